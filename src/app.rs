@@ -1,23 +1,30 @@
 //! Main application state, event handling, and rendering.
 
-use std::collections::BTreeMap;
-use std::path::PathBuf;
-use std::time::Instant;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
-use crossterm::event::{KeyCode, KeyEvent, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 use ratatui::layout::{Alignment, Constraint, Layout, Margin, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{
     Block, Cell, Clear, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table,
-    TableState,
+    TableState, Wrap,
 };
 use ratatui::Frame;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::event::Event;
 use crate::model::{
-    FilterMode, PhaseStatus, ReloadScope, SortMode, Status, Track, TrackCache, TrackId,
+    outstanding_by_priority, FilterMode, PhaseStatus, Pins, PlanFilter, PlanPhase, PlanTask,
+    Priority, ProgressHistory, ReloadScope, SortMode, Status, TagMatch, TaskOrder, Track,
+    TrackCache, TrackId, TrackQuery, TrackSetDiff, TypeFilter,
 };
+use crate::parser::git::GitDates;
+use crate::parser::index::{IndexSource, StatusSource};
+use crate::parser::overview::BoardOverview;
+use crate::parser::retry_read;
 use crate::theme::Theme;
 
 /// Return value from event handling.
@@ -34,6 +41,144 @@ pub enum InputMode {
     Normal,
     Search,
     Help,
+    Palette,
+    ErrorLog,
+    SplitInput,
+    TagPicker,
+    RawPlan,
+    TaskFilter,
+    Query,
+}
+
+/// Named actions shared by key bindings and the command palette.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    CycleFilter,
+    CycleSort,
+    CycleTheme,
+    Search,
+    Query,
+    FilterTasks,
+    ToggleMine,
+    CyclePlanFilter,
+    CycleTypeFilter,
+    PickTags,
+    TogglePin,
+    Refresh,
+    ToggleComplete,
+    CopyOutstanding,
+    CollapsePlan,
+    ToggleDepTree,
+    NextOpenTask,
+    RawPlan,
+    ToggleCompact,
+    ToggleRawProgress,
+    ToggleUtc,
+    TogglePaths,
+    ExportDot,
+    OpenFolder,
+    ToggleChart,
+    ToggleMatrix,
+    ToggleTasksView,
+    ToggleBranchView,
+    ViewBack,
+    ViewForward,
+    MaximiseDetail,
+    MaximiseList,
+    SetSplit,
+    FirstTrack,
+    LastTrack,
+    ErrorLog,
+    Help,
+    Quit,
+}
+
+/// Command palette registry: label, key hint, command.
+const COMMANDS: &[(&str, &str, Command)] = &[
+    ("Cycle filter", "f", Command::CycleFilter),
+    ("Cycle sort", "s", Command::CycleSort),
+    ("Cycle theme", "t", Command::CycleTheme),
+    ("Search tracks", "/", Command::Search),
+    (
+        "Filter with a query (status: tag: prio: type: overdue)",
+        "Q",
+        Command::Query,
+    ),
+    ("Filter plan tasks in detail", "F", Command::FilterTasks),
+    ("Toggle only my tracks", "m", Command::ToggleMine),
+    (
+        "Cycle plan filter (any / has / none)",
+        "P",
+        Command::CyclePlanFilter,
+    ),
+    ("Cycle track type filter", "Y", Command::CycleTypeFilter),
+    ("Filter by tags (AND / OR)", "T", Command::PickTags),
+    ("Pin / unpin track to the top", "p", Command::TogglePin),
+    ("Force refresh", "r", Command::Refresh),
+    ("Toggle track complete", "x", Command::ToggleComplete),
+    ("Copy outstanding tasks", "y", Command::CopyOutstanding),
+    ("Collapse plan to phases", "o", Command::CollapsePlan),
+    ("Toggle dependency tree", "b", Command::ToggleDepTree),
+    (
+        "Jump to next track with open tasks",
+        "n",
+        Command::NextOpenTask,
+    ),
+    ("View raw plan.md", "R", Command::RawPlan),
+    ("Toggle compact detail panel", "v", Command::ToggleCompact),
+    (
+        "Toggle actual plan progress",
+        "a",
+        Command::ToggleRawProgress,
+    ),
+    ("Toggle UTC / local dates", "z", Command::ToggleUtc),
+    (
+        "Toggle relative / absolute paths",
+        "A",
+        Command::TogglePaths,
+    ),
+    ("Export dependency graph (DOT)", "D", Command::ExportDot),
+    (
+        "Open track folder in file manager",
+        "O",
+        Command::OpenFolder,
+    ),
+    ("Toggle progress chart", "c", Command::ToggleChart),
+    ("Toggle tag × status matrix", "g", Command::ToggleMatrix),
+    (
+        "Toggle outstanding tasks view",
+        "W",
+        Command::ToggleTasksView,
+    ),
+    (
+        "Group tracks by branch prefix",
+        "B",
+        Command::ToggleBranchView,
+    ),
+    ("Previous filter / sort / search", "<", Command::ViewBack),
+    ("Next filter / sort / search", ">", Command::ViewForward),
+    ("Maximise detail panel", "Enter", Command::MaximiseDetail),
+    ("Maximise track list", "\\", Command::MaximiseList),
+    ("Set split percentage", "=", Command::SetSplit),
+    ("Jump to first track", "Home", Command::FirstTrack),
+    ("Jump to last track", "End", Command::LastTrack),
+    ("Show error log", "e", Command::ErrorLog),
+    ("Show help", "?", Command::Help),
+    ("Quit", "q", Command::Quit),
+];
+
+/// The list-shaping settings stepped through by `<` / `>` (and Ctrl-O).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewState {
+    pub filter: FilterMode,
+    pub sort: SortMode,
+    pub search_query: String,
+    pub query: String,
+    pub mine_only: bool,
+    pub plan_filter: PlanFilter,
+    pub type_filter: TypeFilter,
+    pub tag_filter: BTreeSet<String>,
+    pub tag_match: TagMatch,
 }
 
 /// Which pane currently has keyboard focus.
@@ -44,11 +189,59 @@ pub enum FocusPane {
     Detail,
 }
 
+/// What fills the main area: the list and detail panes, or one of the
+/// full-screen views.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MainView {
+    #[default]
+    Tracks,
+    /// One progress bar per listed track (`c`).
+    Chart,
+    /// Tag × status counts (`g`).
+    Matrix,
+    /// Outstanding tasks of every listed track (`W`), ordered by `task_order`.
+    Tasks,
+    /// Listed tracks grouped by branch prefix (`B`).
+    Branches,
+}
+
 /// Core application state.
 pub struct App {
     // Core data
     pub tracks: BTreeMap<TrackId, Track>,
     pub conductor_dir: PathBuf,
+    /// Further conductor dirs whose tracks are merged into the same view.
+    pub extra_conductor_dirs: Vec<PathBuf>,
+    pub tracks_subdir: String,
+    pub index_source: IndexSource,
+    pub status_source: StatusSource,
+    /// Extra attempts for a failed plan/metadata read (0 = fail immediately).
+    pub read_retries: u32,
+    /// Git last-commit dates used as the `updated_at` fallback (`--git-dates`).
+    pub git_dates: Option<GitDates>,
+    /// Dates being looked up on a background thread, polled on each tick.
+    git_dates_rx: Option<GitDatesReceiver>,
+    /// Show every task of a Complete track as done (off with
+    /// `--no-autocomplete-normalize`).
+    pub normalize_complete: bool,
+    /// Plan file names tried in order in each track folder (`--plan-file`).
+    pub plan_files: Vec<String>,
+    /// Read each plan only when its track is selected (`--lazy-plans`).
+    pub lazy_plans: bool,
+    /// Nest H3 phases under their H2 phase (`--sub-phases`).
+    pub sub_phases: bool,
+    /// Completed-task samples for the "today" metric, persisted to `history_path`.
+    pub progress_history: ProgressHistory,
+    pub history_path: Option<PathBuf>,
+    /// Per-track `(depends_on, blocks)` counts, recomputed on each reload.
+    pub edge_counts: BTreeMap<TrackId, (usize, usize)>,
+    /// Dependency loops found on the last reload (see `detect_cycles`).
+    pub dependency_cycles: Vec<Vec<TrackId>>,
+    /// First section of the conductor dir's README.md / overview.md, if any.
+    pub overview: Option<BoardOverview>,
+    /// Tracks kept at the top of the list, persisted to `pins_path`.
+    pub pins: Pins,
+    pub pins_path: Option<PathBuf>,
 
     // UI state
     pub table_state: TableState,
@@ -56,12 +249,61 @@ pub struct App {
     pub filter: FilterMode,
     pub sort: SortMode,
     pub search_query: String,
+    /// Raw `Q` filter text and its parsed form (see [`TrackQuery`]).
+    pub query: String,
+    pub track_query: TrackQuery,
+    pub palette_query: String,
+    pub palette_selected: usize,
+    pub mine_pattern: Option<String>,
+    pub mine_only: bool,
+    /// Show only tracks with (or without) a parsed plan.
+    pub plan_filter: PlanFilter,
+    /// Show only tracks of one type.
+    pub type_filter: TypeFilter,
+    /// Applied multi-tag filter; empty shows every track.
+    pub tag_filter: BTreeSet<String>,
+    pub tag_match: TagMatch,
+    /// Tag picker state, applied to `tag_filter`/`tag_match` on Enter.
+    pub tag_picker_draft: BTreeSet<String>,
+    pub tag_picker_match: TagMatch,
+    pub tag_picker_selected: usize,
+    /// Recent list views, oldest first; `view_history_pos` is the one shown.
+    pub view_history: Vec<ViewState>,
+    pub view_history_pos: usize,
     pub mode: InputMode,
+    /// Only plan tasks containing this (case-insensitive) are shown in the
+    /// detail panel; phases without a match are hidden. Kept across tracks.
+    pub task_filter: String,
     pub detail_scroll: u16,
     pub detail_total_lines: u16,
+    /// Scroll the detail panel to the first open task on the next render (`n`).
+    pub scroll_to_open_task: bool,
     pub split_percent: u16,
+    /// Digits typed into the split percentage prompt (`=`).
+    pub split_input: String,
     pub detail_maximised: bool,
+    pub list_maximised: bool,
+    pub plan_collapsed: bool,
+    /// Show dependencies as an indented transitive tree instead of one flat line.
+    pub dep_tree: bool,
+    /// Drop the blank spacer lines in the detail panel to fit more on screen.
+    pub detail_compact: bool,
+    /// Show raw plan completion instead of the normalised 100% for complete tracks.
+    pub show_raw_progress: bool,
+    /// Render dates and the clock in UTC instead of the local timezone.
+    pub utc_dates: bool,
+    /// Show file paths in full rather than relative to the repo root.
+    pub absolute_paths: bool,
+    pub main_view: MainView,
+    /// Scroll offset of the full-screen view; reset when the view changes.
+    pub view_scroll: u16,
+    pub task_order: TaskOrder,
+    /// Title and verbatim lines of the plan.md shown by the raw plan viewer (`R`).
+    pub raw_plan_title: String,
+    pub raw_plan_lines: Vec<String>,
+    pub raw_plan_scroll: u16,
     pub focus: FocusPane,
+    pub debug: bool,
 
     // Theme
     pub theme: Theme,
@@ -69,9 +311,36 @@ pub struct App {
     // Status
     pub watcher_active: bool,
     pub no_watch: bool,
+    /// When the last `Tick` or `FilesChanged` arrived, for the stall watchdog.
+    pub last_event_at: Instant,
+    /// No tick for [`EVENT_STALL_AFTER`]: the tick task (or the channel) has
+    /// died and the screen may be stale.
+    pub event_loop_stalled: bool,
     pub last_refresh: Option<Instant>,
+    /// Status-bar "updated 4s ago", recomputed on tick from `last_refresh`.
+    pub refresh_age: String,
     pub error_message: Option<(String, Instant)>,
+    pub error_history: VecDeque<(chrono::DateTime<chrono::Local>, String)>,
+    pub toast: Option<(String, Instant)>,
+    pub toast_queue: VecDeque<String>,
     pub clock: String,
+    /// Include seconds in the clock; without them an idle dashboard redraws
+    /// once a minute instead of every tick.
+    pub clock_seconds: bool,
+    /// Whether state changed since the last draw.
+    pub dirty: bool,
+    /// Mouse capture is on; off leaves text selection to the terminal.
+    pub mouse_enabled: bool,
+    /// Marker drawn before the selected track (`--highlight-symbol`).
+    pub highlight_symbol: String,
+    /// Show the selected track in reverse video instead of the theme's
+    /// selection colors (`--reverse-selection`).
+    pub reverse_selection: bool,
+    /// Hide complete tracks older than this outside the Complete filter.
+    pub archive_after: Option<chrono::Duration>,
+    /// The Blocked filter also lists tracks waiting on an incomplete
+    /// dependency (`--blocked-includes-deps`).
+    pub blocked_includes_deps: bool,
 
     // Cached filtered list
     pub filtered_track_ids: Vec<TrackId>,
@@ -82,47 +351,211 @@ pub struct App {
 
     // Cache for incremental reloading
     pub track_cache: TrackCache,
+
+    // Tracks incrementally reloaded since startup (or the last forced refresh)
+    pub edited_tracks: HashSet<TrackId>,
+
+    // Watcher changes buffered until the burst settles (flushed on tick)
+    pub pending_changes: Vec<PathBuf>,
+    pub last_change_at: Option<Instant>,
 }
 
+/// How long the watcher must be quiet before buffered changes are reloaded.
+const COALESCE_WINDOW: Duration = Duration::from_millis(250);
+
+/// Results of a background git date lookup: `(track folder, last commit)`.
+type GitDatesReceiver =
+    std::sync::mpsc::Receiver<Vec<(PathBuf, Option<chrono::DateTime<chrono::Utc>>)>>;
+
+/// Ticks arrive every second; this long without one means the event loop stalled.
+const EVENT_STALL_AFTER: Duration = Duration::from_secs(5);
+
+/// Number of past errors kept for the error log (`e`).
+const ERROR_HISTORY_LEN: usize = 20;
+
+/// File (in the working directory) written by the DOT export command.
+const DOT_EXPORT_FILE: &str = "conductor-dependencies.dot";
+
+/// Number of list views kept for stepping back and forward.
+const VIEW_HISTORY_LEN: usize = 30;
+
+/// How long each task-completion toast stays on screen.
+const TOAST_DURATION: Duration = Duration::from_secs(3);
+
+/// Marker before the selected track unless `--highlight-symbol` sets one.
+pub const DEFAULT_HIGHLIGHT_SYMBOL: &str = "▸ ";
+
 impl App {
     pub fn new(
         conductor_dir: PathBuf,
+        index_source: IndexSource,
         no_watch: bool,
         initial_filter: FilterMode,
     ) -> color_eyre::Result<Self> {
+        let history_path = crate::model::history_path(&conductor_dir);
+        let pins_path = crate::model::pins_path(&conductor_dir);
         Ok(Self {
             tracks: BTreeMap::new(),
+            progress_history: history_path
+                .as_deref()
+                .map(ProgressHistory::load)
+                .unwrap_or_default(),
+            history_path,
+            edge_counts: BTreeMap::new(),
+            dependency_cycles: Vec::new(),
+            overview: crate::parser::overview::read_overview(&conductor_dir),
+            pins: pins_path.as_deref().map(Pins::load).unwrap_or_default(),
+            pins_path,
             conductor_dir,
+            extra_conductor_dirs: Vec::new(),
+            tracks_subdir: crate::parser::DEFAULT_TRACKS_SUBDIR.to_string(),
+            index_source,
+            status_source: StatusSource::default(),
+            read_retries: 0,
+            git_dates: None,
+            git_dates_rx: None,
+            normalize_complete: true,
+            plan_files: vec![crate::parser::DEFAULT_PLAN_FILE.to_string()],
+            lazy_plans: false,
+            sub_phases: false,
             table_state: TableState::default(),
             selected_track: None,
             filter: initial_filter,
             sort: SortMode::Updated,
             search_query: String::new(),
+            query: String::new(),
+            track_query: TrackQuery::default(),
+            palette_query: String::new(),
+            palette_selected: 0,
+            mine_pattern: None,
+            mine_only: false,
+            plan_filter: PlanFilter::default(),
+            type_filter: TypeFilter::default(),
+            tag_filter: BTreeSet::new(),
+            tag_match: TagMatch::default(),
+            tag_picker_draft: BTreeSet::new(),
+            tag_picker_match: TagMatch::default(),
+            tag_picker_selected: 0,
+            view_history: Vec::new(),
+            view_history_pos: 0,
             mode: InputMode::Normal,
+            task_filter: String::new(),
             detail_scroll: 0,
             detail_total_lines: 0,
+            scroll_to_open_task: false,
             split_percent: 45,
+            split_input: String::new(),
             detail_maximised: false,
+            list_maximised: false,
+            plan_collapsed: false,
+            dep_tree: false,
+            detail_compact: false,
+            show_raw_progress: false,
+            utc_dates: false,
+            absolute_paths: false,
+            main_view: MainView::default(),
+            view_scroll: 0,
+            task_order: TaskOrder::default(),
+            raw_plan_title: String::new(),
+            raw_plan_lines: Vec::new(),
+            raw_plan_scroll: 0,
             focus: FocusPane::TrackList,
+            debug: false,
             theme: Theme::mako(),
             watcher_active: !no_watch,
             no_watch,
+            last_event_at: Instant::now(),
+            event_loop_stalled: false,
             last_refresh: None,
+            refresh_age: String::new(),
             error_message: None,
+            error_history: VecDeque::new(),
+            toast: None,
+            toast_queue: VecDeque::new(),
             clock: chrono::Local::now().format("%H:%M:%S").to_string(),
+            clock_seconds: true,
+            dirty: true,
+            mouse_enabled: true,
+            highlight_symbol: DEFAULT_HIGHLIGHT_SYMBOL.to_string(),
+            reverse_selection: false,
+            archive_after: None,
+            blocked_includes_deps: false,
             filtered_track_ids: Vec::new(),
             list_area: Rect::default(),
             detail_area: Rect::default(),
             track_cache: TrackCache::new(),
+            edited_tracks: HashSet::new(),
+            pending_changes: Vec::new(),
+            last_change_at: None,
         })
     }
 
+    /// Read track folders from `subdir` (relative to the conductor dir).
+    pub fn set_tracks_subdir(&mut self, subdir: &str) {
+        self.tracks_subdir = subdir.to_string();
+        self.track_cache =
+            TrackCache::with_tracks_subdir(subdir).with_plan_files(self.plan_files.clone());
+    }
+
+    /// Look for each track's plan under these names, first match wins. An
+    /// empty list means the default, `plan.md`.
+    pub fn set_plan_files(&mut self, mut plan_files: Vec<String>) {
+        if plan_files.is_empty() {
+            plan_files.push(crate::parser::DEFAULT_PLAN_FILE.to_string());
+        }
+        self.track_cache =
+            TrackCache::with_tracks_subdir(&self.tracks_subdir).with_plan_files(plan_files.clone());
+        self.plan_files = plan_files;
+    }
+
+    fn tracks_dir(&self) -> PathBuf {
+        self.conductor_dir.join(&self.tracks_subdir)
+    }
+
+    /// Folder holding a track's files, following its source conductor dir.
+    fn track_dir(&self, track_id: &TrackId) -> PathBuf {
+        match self.tracks.get(track_id) {
+            Some(track) => track
+                .source_dir
+                .as_ref()
+                .unwrap_or(&self.conductor_dir)
+                .join(&self.tracks_subdir)
+                .join(track.dir_name()),
+            None => self.tracks_dir().join(track_id.as_str()),
+        }
+    }
+
+    /// `path` for display: relative to the repo root (the conductor dir's
+    /// parent), or in full after `A`.
+    fn display_path(&self, path: &Path) -> String {
+        if self.absolute_paths {
+            std::path::absolute(path)
+                .unwrap_or_else(|_| path.to_path_buf())
+                .display()
+                .to_string()
+        } else {
+            crate::mcp::display_path(path, Some(crate::mcp::repo_root(&self.conductor_dir)))
+        }
+    }
+
     /// Load tracks from disk.
     pub fn load_tracks(&mut self) -> color_eyre::Result<()> {
-        match crate::parser::load_all_tracks(&self.conductor_dir) {
+        match crate::parser::load_tracks_with_extra_dirs(
+            &self.index_source,
+            &self.conductor_dir,
+            &self.extra_conductor_dirs,
+            &self.tracks_subdir,
+            self.status_source,
+            &self.read_options(),
+        ) {
             Ok(tracks) => {
                 self.tracks = tracks;
+                self.fill_git_dates();
+                self.edge_counts = crate::model::edge_counts(&self.tracks);
+                self.check_dependency_cycles();
+                self.record_progress();
                 self.last_refresh = Some(Instant::now());
+                self.refresh_age = self.format_refresh_age();
                 self.recompute_filtered_tracks();
                 if self.selected_track.is_none() {
                     self.select_first();
@@ -130,51 +563,297 @@ impl App {
                 Ok(())
             }
             Err(e) => {
-                self.error_message = Some((e.to_string(), Instant::now()));
+                self.report_error(e.to_string());
                 Ok(())
             }
         }
     }
 
+    /// Fill `updated_at` fallbacks from cached git dates, and look up any
+    /// folders not cached yet on a background thread so git never blocks
+    /// the UI. Those tracks get their dates when `poll_git_dates` sees them.
+    fn fill_git_dates(&mut self) {
+        let Some(git_dates) = &self.git_dates else {
+            return;
+        };
+        let uncached =
+            git_dates.fill_from_cache(&mut self.tracks, &self.conductor_dir, &self.tracks_subdir);
+        // One lookup at a time; whatever it misses is picked up when it lands
+        if uncached.is_empty() || self.git_dates_rx.is_some() {
+            return;
+        }
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let dates = uncached
+                .into_iter()
+                .map(|dir| {
+                    let date = crate::parser::git::git_last_commit(&dir);
+                    (dir, date)
+                })
+                .collect();
+            let _ = tx.send(dates);
+        });
+        self.git_dates_rx = Some(rx);
+    }
+
+    /// Apply git dates from a finished background lookup. Returns whether
+    /// any arrived.
+    fn poll_git_dates(&mut self) -> bool {
+        let Some(rx) = &self.git_dates_rx else {
+            return false;
+        };
+        let dates = match rx.try_recv() {
+            Ok(dates) => dates,
+            Err(std::sync::mpsc::TryRecvError::Empty) => return false,
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.git_dates_rx = None;
+                return false;
+            }
+        };
+        self.git_dates_rx = None;
+        if let Some(git_dates) = &mut self.git_dates {
+            for (dir, date) in dates {
+                git_dates.insert(dir, date);
+            }
+        }
+        self.fill_git_dates();
+        self.recompute_filtered_tracks();
+        true
+    }
+
+    /// How track folders are read, from the CLI settings.
+    fn read_options(&self) -> crate::parser::ReadOptions {
+        crate::parser::ReadOptions {
+            read_retries: self.read_retries,
+            normalize_complete: self.normalize_complete,
+            plan_files: self.plan_files.clone(),
+            lazy_plans: self.lazy_plans,
+            sub_phases: self.sub_phases,
+        }
+    }
+
+    /// Read the plans loading skipped (`--lazy-plans`): the selected
+    /// track's, or every one while [`Self::needs_all_plans`]. Each stays
+    /// loaded until a reload replaces its track.
+    pub fn load_pending_plans(&mut self) {
+        if self.needs_all_plans() {
+            self.load_all_pending_plans();
+        } else if let Some(id) = self.selected_track.clone() {
+            self.load_pending_plan(&id);
+        }
+    }
+
+    /// Whether a filter, sort or view in use reads every track's tasks, so
+    /// pending plans can't stay unread.
+    fn needs_all_plans(&self) -> bool {
+        self.track_query.overdue
+            || self.sort == SortMode::Progress
+            || matches!(
+                self.main_view,
+                MainView::Chart | MainView::Tasks | MainView::Branches
+            )
+    }
+
+    fn load_all_pending_plans(&mut self) {
+        let pending: Vec<TrackId> = self
+            .tracks
+            .values()
+            .filter(|t| t.plan_pending)
+            .map(|t| t.id.clone())
+            .collect();
+        for id in &pending {
+            self.load_pending_plan(id);
+        }
+    }
+
+    fn load_pending_plan(&mut self, id: &TrackId) {
+        if !self.tracks.get(id).is_some_and(|t| t.plan_pending) {
+            return;
+        }
+        let track_dir = self.track_dir(id);
+        let options = self.read_options();
+        if let Some(track) = self.tracks.get_mut(id) {
+            crate::parser::load_pending_plan(track, &track_dir, &options);
+        }
+    }
+
     /// Reload specific tracks or do a full reload.
     pub fn reload_tracks(&mut self, scope: ReloadScope) {
+        let completed_before: HashMap<TrackId, usize> = self
+            .tracks
+            .iter()
+            .map(|(id, t)| (id.clone(), t.tasks_completed))
+            .collect();
+
         match scope {
             ReloadScope::Full => {
+                let previous = self.tracks.clone();
+                self.overview = crate::parser::overview::read_overview(&self.conductor_dir);
                 if let Err(e) = self.load_tracks() {
-                    self.error_message = Some((e.to_string(), Instant::now()));
+                    self.report_error(e.to_string());
+                }
+                let diff = TrackSetDiff::between(&previous, &self.tracks);
+                if !diff.is_empty() {
+                    self.toast_queue
+                        .push_back(format!("Tracks: {}", diff.summary()));
                 }
             }
             ReloadScope::Tracks(track_ids) => {
-                let tracks_dir = self.conductor_dir.join("tracks");
+                let retries = self.read_retries;
                 for id in &track_ids {
-                    let track_dir = tracks_dir.join(id.as_str());
+                    let track_dir = self.track_dir(id);
 
                     // Reload metadata
                     if let Some(track) = self.tracks.get_mut(id) {
-                        if let Ok(Some(meta)) =
+                        match retry_read(retries, || {
                             crate::parser::metadata::parse_metadata(&track_dir, id.as_str())
+                        }) {
+                            Ok(Some(meta)) => track.merge_metadata(meta),
+                            Ok(None) => {}
+                            Err(e) => {
+                                tracing::warn!(track_id = id.as_str(), error = %e, "failed to reload metadata");
+                            }
+                        }
+
+                        // Reload plan; on failure keep the previous plan rather than blanking it
+                        if let Some(plan_path) =
+                            crate::parser::find_plan_file(&track_dir, &self.plan_files)
                         {
-                            track.merge_metadata(meta);
+                            match retry_read(retries, || {
+                                crate::parser::plan::parse_plan_with_sub_phases(
+                                    &plan_path,
+                                    self.sub_phases,
+                                )
+                            }) {
+                                Ok(phases) => track.merge_plan(phases),
+                                Err(e) => {
+                                    tracing::warn!(track_id = id.as_str(), error = %e, "failed to reload plan");
+                                }
+                            }
                         }
 
-                        // Reload plan
-                        let plan_path = track_dir.join("plan.md");
-                        if plan_path.exists() {
-                            if let Ok(phases) = crate::parser::plan::parse_plan(&plan_path) {
-                                track.merge_plan(phases);
+                        // Reload spec, keeping the previous one if it can't be read
+                        let spec_path = track_dir.join("spec.md");
+                        if spec_path.exists() {
+                            match retry_read(retries, || {
+                                crate::parser::spec::parse_spec(&spec_path)
+                            }) {
+                                Ok(spec) => track.merge_spec(spec),
+                                Err(e) => {
+                                    tracing::warn!(track_id = id.as_str(), error = %e, "failed to reload spec");
+                                }
                             }
                         }
 
                         // Auto-complete tasks for tracks marked as done
-                        if track.status == Status::Complete {
+                        if self.normalize_complete && track.status == Status::Complete {
                             track.mark_all_tasks_complete();
                         }
+
+                        self.edited_tracks.insert(id.clone());
                     }
                 }
+                crate::parser::resolve_title_dependencies(&mut self.tracks);
+                self.edge_counts = crate::model::edge_counts(&self.tracks);
+                self.check_dependency_cycles();
+                self.record_progress();
                 self.last_refresh = Some(Instant::now());
+                self.refresh_age = self.format_refresh_age();
                 self.recompute_filtered_tracks();
             }
         }
+
+        for (id, track) in &self.tracks {
+            let Some(&before) = completed_before.get(id) else {
+                continue;
+            };
+            if track.tasks_completed > before {
+                let gained = track.tasks_completed - before;
+                let noun = if gained == 1 { "task" } else { "tasks" };
+                self.toast_queue
+                    .push_back(format!("{id}: +{gained} {noun} done"));
+            }
+        }
+        self.advance_toast();
+    }
+
+    /// Sample total completed tasks into the progress history and persist it.
+    /// A failed write disables persistence for the rest of the session.
+    fn record_progress(&mut self) {
+        // Totals grow as lazily loaded plans are read, which isn't progress
+        if self.lazy_plans {
+            return;
+        }
+        let completed = self.tracks.values().map(|t| t.tasks_completed).sum();
+        if !self
+            .progress_history
+            .record(chrono::Utc::now(), completed, &chrono::Local)
+        {
+            return;
+        }
+        if let Some(path) = &self.history_path {
+            if let Err(e) = self.progress_history.save(path) {
+                tracing::warn!(path = %path.display(), error = %e, "failed to save progress history");
+                self.history_path = None;
+            }
+        }
+    }
+
+    /// Net tasks completed today, or `None` without a sample from today.
+    fn tasks_done_today(&self) -> Option<i64> {
+        if self.lazy_plans {
+            return None;
+        }
+        let completed = self.tracks.values().map(|t| t.tasks_completed).sum();
+        let today = chrono::Local::now().date_naive();
+        self.progress_history
+            .net_change_on(today, completed, &chrono::Local)
+    }
+
+    /// Warn in the error bar when a reload turns up dependency cycles other
+    /// than the ones already reported.
+    fn check_dependency_cycles(&mut self) {
+        let cycles = crate::model::detect_cycles(&self.tracks);
+        if !cycles.is_empty() && cycles != self.dependency_cycles {
+            let first: Vec<&str> = cycles[0]
+                .iter()
+                .chain(&cycles[0][..1])
+                .map(TrackId::as_str)
+                .collect();
+            let more = match cycles.len() {
+                1 => String::new(),
+                n => format!(" (+{} more)", n - 1),
+            };
+            self.report_error(format!("Dependency cycle: {}{more}", first.join(" → ")));
+        }
+        self.dependency_cycles = cycles;
+    }
+
+    /// Show an error in the error bar and record it in the error log.
+    fn report_error(&mut self, message: String) {
+        if self.error_history.len() == ERROR_HISTORY_LEN {
+            self.error_history.pop_front();
+        }
+        self.error_history
+            .push_back((chrono::Local::now(), message.clone()));
+        self.error_message = Some((message, Instant::now()));
+    }
+
+    /// Expire the current toast and show the next queued one, if any.
+    fn advance_toast(&mut self) {
+        if self
+            .toast
+            .as_ref()
+            .is_some_and(|(_, shown)| shown.elapsed() >= TOAST_DURATION)
+        {
+            self.toast = None;
+        }
+        if self.toast.is_none() {
+            self.toast = self
+                .toast_queue
+                .pop_front()
+                .map(|msg| (msg, Instant::now()));
+        }
     }
 
     /// Main event loop.
@@ -183,23 +862,54 @@ impl App {
         self.load_tracks()?;
 
         // Start event handler
-        let mut events =
-            crate::event::EventHandler::new(self.conductor_dir.clone(), !self.no_watch);
+        let index_file = match &self.index_source {
+            IndexSource::File(path) => Some(path.clone()),
+            IndexSource::Content(_) => None,
+        };
+        let mut watch_dirs = vec![self.conductor_dir.clone()];
+        watch_dirs.extend(self.extra_conductor_dirs.iter().cloned());
+        let mut events = crate::event::EventHandler::new(
+            watch_dirs,
+            index_file,
+            self.plan_files.clone(),
+            !self.no_watch,
+            self.mouse_enabled,
+        );
 
         loop {
-            // RENDER
-            terminal.draw(|frame| self.render(frame))?;
+            // RENDER (only when something visible changed)
+            if self.dirty {
+                self.load_pending_plans();
+                terminal.draw(|frame| self.render(frame))?;
+                self.dirty = false;
+            }
 
-            // WAIT FOR EVENT
-            let Some(event) = events.next().await else {
-                break;
+            // WAIT FOR EVENT, waking up anyway so a dead tick task is noticed
+            let event = match tokio::time::timeout(EVENT_STALL_AFTER, events.next()).await {
+                Ok(Some(event)) => Some(event),
+                Ok(None) => break,
+                Err(_) => None,
+            };
+            // Record the event before checking, so a late tick isn't a stall
+            let Some(event) = event else {
+                self.check_event_watchdog();
+                continue;
             };
 
             // UPDATE
-            match self.handle_event(event) {
+            let action = self.handle_event(event);
+            self.check_event_watchdog();
+            match action {
                 Action::Quit => break,
                 Action::ForceRefresh => {
+                    self.edited_tracks.clear();
+                    self.pending_changes.clear();
+                    self.last_change_at = None;
+                    if let Some(git_dates) = &mut self.git_dates {
+                        git_dates.clear();
+                    }
                     let _ = self.load_tracks();
+                    self.dirty = true;
                 }
                 Action::Continue => {}
             }
@@ -208,37 +918,198 @@ impl App {
         Ok(())
     }
 
+    /// Format a stored UTC timestamp in the selected display timezone.
+    ///
+    /// Date-only values (due dates, `2026-01-15` in metadata) are stored as
+    /// midnight UTC and shown as that calendar day, since shifting them to
+    /// local time would show the day before west of UTC.
+    fn format_date(&self, dt: chrono::DateTime<chrono::Utc>, fmt: &str) -> String {
+        if dt.time() == chrono::NaiveTime::MIN {
+            dt.date_naive().format(fmt).to_string()
+        } else if self.utc_dates {
+            dt.format(fmt).to_string()
+        } else {
+            dt.with_timezone(&chrono::Local).format(fmt).to_string()
+        }
+    }
+
+    /// Header clock in the selected display timezone.
+    fn format_clock(&self) -> String {
+        let fmt = if self.clock_seconds {
+            "%H:%M:%S"
+        } else {
+            "%H:%M"
+        };
+        if self.utc_dates {
+            format!("{} UTC", chrono::Utc::now().format(fmt))
+        } else {
+            chrono::Local::now().format(fmt).to_string()
+        }
+    }
+
+    /// Time since the last reload, e.g. "updated 4s ago"; empty before the
+    /// first. Without clock seconds it counts whole minutes only.
+    fn format_refresh_age(&self) -> String {
+        self.last_refresh
+            .map(|at| {
+                let elapsed = at.elapsed();
+                if !self.clock_seconds && elapsed < Duration::from_secs(60) {
+                    "updated just now".to_string()
+                } else {
+                    format!("updated {}", format_age(elapsed))
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    /// Flag the event loop as stalled once no tick or file change has arrived
+    /// for [`EVENT_STALL_AFTER`]; cleared by the next one (see `handle_event`).
+    pub fn check_event_watchdog(&mut self) {
+        let quiet = self.last_event_at.elapsed();
+        if !self.event_loop_stalled && quiet >= EVENT_STALL_AFTER {
+            tracing::warn!(
+                quiet_secs = quiet.as_secs(),
+                "event loop stalled: no tick received"
+            );
+            self.event_loop_stalled = true;
+            self.dirty = true;
+        }
+    }
+
+    /// A tick or file change arrived: the event loop is alive.
+    fn note_loop_event(&mut self) {
+        self.last_event_at = Instant::now();
+        if self.event_loop_stalled {
+            self.event_loop_stalled = false;
+            self.dirty = true;
+        }
+    }
+
     /// Handle a single event.
     pub fn handle_event(&mut self, event: Event) -> Action {
         match event {
-            Event::Key(key) => self.handle_key_event(key),
-            Event::Mouse(mouse) => self.handle_mouse_event(mouse),
+            Event::Key(key) => {
+                self.dirty = true;
+                self.handle_key_event(key)
+            }
+            Event::Mouse(mouse) => {
+                self.dirty = true;
+                self.handle_mouse_event(mouse)
+            }
             Event::Tick => {
-                self.clock = chrono::Local::now().format("%H:%M:%S").to_string();
+                self.note_loop_event();
+                // A tick only redraws if it changes something on screen
+                let clock = self.format_clock();
+                let clock_changed = clock != self.clock;
+                if clock_changed {
+                    self.clock = clock;
+                    self.dirty = true;
+                }
+                // Without seconds the age moves with the clock, once a minute
+                if self.clock_seconds || clock_changed {
+                    let refresh_age = self.format_refresh_age();
+                    if refresh_age != self.refresh_age {
+                        self.refresh_age = refresh_age;
+                        self.dirty = true;
+                    }
+                }
                 // Auto-dismiss errors after 10 seconds
                 if let Some((_, when)) = &self.error_message {
                     if when.elapsed().as_secs() >= 10 {
                         self.error_message = None;
+                        self.dirty = true;
                     }
                 }
+                if self
+                    .last_change_at
+                    .is_some_and(|t| t.elapsed() >= COALESCE_WINDOW)
+                {
+                    self.flush_pending_changes();
+                    self.dirty = true;
+                }
+                if self.poll_git_dates() {
+                    self.dirty = true;
+                }
+                let toast_before = self.toast.clone();
+                self.advance_toast();
+                if self.toast != toast_before {
+                    self.dirty = true;
+                }
                 Action::Continue
             }
             Event::FilesChanged(paths) => {
+                self.note_loop_event();
+                self.dirty = true;
                 self.watcher_active = true;
-                let scope = self.track_cache.classify_changes(&paths);
-                self.reload_tracks(scope);
+                for path in paths {
+                    if !self.pending_changes.contains(&path) {
+                        self.pending_changes.push(path);
+                    }
+                }
+                self.last_change_at = Some(Instant::now());
+                Action::Continue
+            }
+            Event::Resize(width, height) => {
+                self.dirty = true;
+                self.handle_resize(width, height);
                 Action::Continue
             }
-            Event::Resize(_, _) => Action::Continue,
         }
     }
 
+    /// Drop layout caches that the new size invalidates and keep the detail
+    /// scroll within the content that can fit.
+    fn handle_resize(&mut self, width: u16, height: u16) {
+        // Title (1) + stats (2) + status (1) bars, plus the panel border (2)
+        let viewport = height.saturating_sub(6);
+        let max_scroll = self.detail_total_lines.saturating_sub(viewport);
+        self.detail_scroll = self.detail_scroll.min(max_scroll);
+
+        // Stale geometry would mis-route clicks until the next draw
+        self.list_area = Rect::default();
+        self.detail_area = Rect::default();
+        if width < 80 && !self.detail_maximised {
+            self.focus = FocusPane::TrackList;
+        }
+    }
+
+    /// Reload everything touched by the buffered watcher changes in one pass.
+    fn flush_pending_changes(&mut self) {
+        self.last_change_at = None;
+        let paths = std::mem::take(&mut self.pending_changes);
+        if paths.is_empty() {
+            return;
+        }
+        // Folder names are ambiguous across merged dirs, so re-read everything
+        if paths.iter().any(|p| self.is_overview_path(p)) {
+            self.overview = crate::parser::overview::read_overview(&self.conductor_dir);
+        }
+        let scope = if !self.extra_conductor_dirs.is_empty()
+            || paths.iter().any(|p| self.is_index_path(p))
+        {
+            ReloadScope::Full
+        } else {
+            self.track_cache.classify_changes(&paths)
+        };
+        self.reload_tracks(scope);
+    }
+
     /// Handle key events.
     fn handle_key_event(&mut self, key: KeyEvent) -> Action {
         // Global keys
         match key.code {
             KeyCode::Char('q') if self.mode == InputMode::Normal => return Action::Quit,
-            KeyCode::Char('?') if self.mode != InputMode::Search => {
+            KeyCode::Char('?')
+                if !matches!(
+                    self.mode,
+                    InputMode::Search
+                        | InputMode::Query
+                        | InputMode::TaskFilter
+                        | InputMode::Palette
+                        | InputMode::SplitInput
+                        | InputMode::TagPicker
+                ) =>
+            {
                 self.mode = if self.mode == InputMode::Help {
                     InputMode::Normal
                 } else {
@@ -260,12 +1131,45 @@ impl App {
                         self.search_query.clear();
                         self.recompute_filtered_tracks();
                     }
+                    InputMode::Query => {
+                        self.mode = InputMode::Normal;
+                        self.set_query(String::new());
+                    }
+                    InputMode::TaskFilter => {
+                        self.mode = InputMode::Normal;
+                        self.task_filter.clear();
+                        self.detail_scroll = 0;
+                    }
                     InputMode::Help => {
                         self.mode = InputMode::Normal;
                     }
+                    InputMode::Palette => {
+                        self.mode = InputMode::Normal;
+                        self.palette_query.clear();
+                    }
+                    InputMode::ErrorLog => {
+                        self.mode = InputMode::Normal;
+                    }
+                    InputMode::SplitInput => {
+                        self.mode = InputMode::Normal;
+                        self.split_input.clear();
+                    }
+                    InputMode::TagPicker | InputMode::RawPlan => {
+                        self.mode = InputMode::Normal;
+                    }
+                    InputMode::Normal if self.main_view != MainView::Tracks => {
+                        self.main_view = MainView::Tracks;
+                    }
+                    InputMode::Normal if !self.task_filter.is_empty() => {
+                        self.task_filter.clear();
+                        self.detail_scroll = 0;
+                    }
                     InputMode::Normal if self.detail_maximised => {
                         self.detail_maximised = false;
                     }
+                    InputMode::Normal if self.list_maximised => {
+                        self.list_maximised = false;
+                    }
                     InputMode::Normal if self.focus == FocusPane::Detail => {
                         self.focus = FocusPane::TrackList;
                     }
@@ -282,107 +1186,458 @@ impl App {
             return Action::Continue;
         }
 
-        // Search mode: capture all input
-        if self.mode == InputMode::Search {
+        // Error log: c clears it, any other key closes
+        if self.mode == InputMode::ErrorLog {
+            if key.code == KeyCode::Char('c') {
+                self.error_history.clear();
+                self.error_message = None;
+            }
+            self.mode = InputMode::Normal;
+            return Action::Continue;
+        }
+
+        // Command palette: typing filters, Enter runs the highlighted command
+        if self.mode == InputMode::Palette {
             match key.code {
                 KeyCode::Char(c) => {
-                    self.search_query.push(c);
-                    self.recompute_filtered_tracks();
+                    self.palette_query.push(c);
+                    self.palette_selected = 0;
                 }
                 KeyCode::Backspace => {
-                    self.search_query.pop();
-                    self.recompute_filtered_tracks();
+                    self.palette_query.pop();
+                    self.palette_selected = 0;
+                }
+                KeyCode::Down => {
+                    let count = self.palette_matches().len();
+                    self.palette_selected =
+                        (self.palette_selected + 1).min(count.saturating_sub(1));
+                }
+                KeyCode::Up => {
+                    self.palette_selected = self.palette_selected.saturating_sub(1);
                 }
                 KeyCode::Enter => {
+                    let command = self
+                        .palette_matches()
+                        .get(self.palette_selected)
+                        .map(|(_, _, c)| *c);
                     self.mode = InputMode::Normal;
+                    self.palette_query.clear();
+                    if let Some(command) = command {
+                        return self.run_command(command);
+                    }
                 }
                 _ => {}
             }
             return Action::Continue;
         }
 
-        // Normal mode keys
-        match key.code {
-            KeyCode::Down | KeyCode::Char('j') => match self.focus {
-                FocusPane::TrackList => self.select_next(),
-                FocusPane::Detail => {
-                    self.detail_scroll = self
-                        .detail_scroll
-                        .saturating_add(1)
-                        .min(self.detail_total_lines.saturating_sub(5));
+        // Split prompt: digits only, Enter applies the clamped percentage
+        if self.mode == InputMode::SplitInput {
+            match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() && self.split_input.len() < 3 => {
+                    self.split_input.push(c);
                 }
-            },
-            KeyCode::Up | KeyCode::Char('k') => match self.focus {
-                FocusPane::TrackList => self.select_previous(),
-                FocusPane::Detail => {
-                    self.detail_scroll = self.detail_scroll.saturating_sub(1);
+                KeyCode::Backspace => {
+                    self.split_input.pop();
                 }
-            },
-            KeyCode::Home => self.select_first(),
-            KeyCode::End => self.select_last(),
-            KeyCode::Enter => self.detail_maximised = true,
-            KeyCode::Char('f') => {
-                self.filter = self.filter.next();
-                self.recompute_filtered_tracks();
-            }
-            KeyCode::Char('s') => {
-                self.sort = self.sort.next();
-                self.recompute_filtered_tracks();
-            }
-            KeyCode::Char('/') => {
-                self.mode = InputMode::Search;
-            }
-            KeyCode::Char('r') => {
-                return Action::ForceRefresh;
-            }
-            KeyCode::Char('t') => {
-                self.theme = self.theme.next();
-            }
-            KeyCode::Char('[') => {
-                self.split_percent = self.split_percent.saturating_sub(5).max(20);
-            }
-            KeyCode::Char(']') => {
-                self.split_percent = (self.split_percent + 5).min(80);
-            }
-            KeyCode::Char('d') => {
-                self.detail_scroll = self
-                    .detail_scroll
-                    .saturating_add(5)
-                    .min(self.detail_total_lines.saturating_sub(5));
-            }
-            KeyCode::Char('u') => {
-                self.detail_scroll = self.detail_scroll.saturating_sub(5);
-            }
-            KeyCode::Char('x') => {
-                self.toggle_track_complete();
+                KeyCode::Enter => {
+                    match parse_split_percent(&self.split_input) {
+                        Some(pct) => self.split_percent = pct,
+                        None => self.report_error(format!(
+                            "Invalid split percentage '{}'; enter a number from 20 to 80",
+                            self.split_input
+                        )),
+                    }
+                    self.mode = InputMode::Normal;
+                    self.split_input.clear();
+                }
+                _ => {}
             }
-            _ => {}
+            return Action::Continue;
         }
 
-        Action::Continue
-    }
-
-    /// Handle mouse events.
-    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> Action {
-        match mouse.kind {
-            MouseEventKind::Down(crossterm::event::MouseButton::Left) => {
-                // Click in track list area → select that track
-                if !self.detail_maximised
-                    && self.list_area.contains((mouse.column, mouse.row).into())
-                {
-                    // Account for border (1) + header row (1) + header bottom margin (1) = 3 rows offset
-                    let row_offset = mouse.row.saturating_sub(self.list_area.y + 3);
-                    let track_index = (row_offset / 2) as usize; // each row is height 2
-                    if track_index < self.filtered_track_ids.len() {
-                        self.table_state.select(Some(track_index));
-                        self.selected_track = self.filtered_track_ids.get(track_index).cloned();
-                        self.detail_scroll = 0;
+        // Tag picker: Space toggles the highlighted tag, Tab flips AND/OR,
+        // Enter applies the selection
+        if self.mode == InputMode::TagPicker {
+            let tags = self.all_tags();
+            match key.code {
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.tag_picker_selected =
+                        (self.tag_picker_selected + 1).min(tags.len().saturating_sub(1));
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.tag_picker_selected = self.tag_picker_selected.saturating_sub(1);
+                }
+                KeyCode::Char(' ') => {
+                    if let Some(tag) = tags.get(self.tag_picker_selected) {
+                        if !self.tag_picker_draft.remove(tag) {
+                            self.tag_picker_draft.insert(tag.clone());
+                        }
                     }
                 }
-            }
-            MouseEventKind::ScrollDown => {
-                if self.detail_maximised
-                    || self.detail_area.contains((mouse.column, mouse.row).into())
+                KeyCode::Tab => {
+                    self.tag_picker_match = self.tag_picker_match.toggle();
+                }
+                KeyCode::Char('c') => self.tag_picker_draft.clear(),
+                KeyCode::Enter => {
+                    self.tag_filter = std::mem::take(&mut self.tag_picker_draft);
+                    self.tag_match = self.tag_picker_match;
+                    self.mode = InputMode::Normal;
+                    self.recompute_filtered_tracks();
+                }
+                _ => {}
+            }
+            return Action::Continue;
+        }
+
+        // Raw plan viewer: scroll keys move, q or R closes
+        if self.mode == InputMode::RawPlan {
+            let max_scroll = self.raw_plan_lines.len().saturating_sub(1) as u16;
+            match key.code {
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.raw_plan_scroll = self.raw_plan_scroll.saturating_add(1).min(max_scroll);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.raw_plan_scroll = self.raw_plan_scroll.saturating_sub(1);
+                }
+                KeyCode::PageDown | KeyCode::Char('d') => {
+                    self.raw_plan_scroll = self.raw_plan_scroll.saturating_add(10).min(max_scroll);
+                }
+                KeyCode::PageUp | KeyCode::Char('u') => {
+                    self.raw_plan_scroll = self.raw_plan_scroll.saturating_sub(10);
+                }
+                KeyCode::Home | KeyCode::Char('g') => self.raw_plan_scroll = 0,
+                KeyCode::End | KeyCode::Char('G') => self.raw_plan_scroll = max_scroll,
+                KeyCode::Char('q') | KeyCode::Char('R') => self.mode = InputMode::Normal,
+                _ => {}
+            }
+            return Action::Continue;
+        }
+
+        // Query prompt: the list follows the query as it is typed
+        if self.mode == InputMode::Query {
+            match key.code {
+                KeyCode::Char(c) => {
+                    let mut query = std::mem::take(&mut self.query);
+                    query.push(c);
+                    self.set_query(query);
+                }
+                KeyCode::Backspace => {
+                    let mut query = std::mem::take(&mut self.query);
+                    query.pop();
+                    self.set_query(query);
+                }
+                KeyCode::Enter => {
+                    self.mode = InputMode::Normal;
+                    self.record_view_state();
+                }
+                _ => {}
+            }
+            return Action::Continue;
+        }
+
+        // Task filter prompt: typing narrows the plan, Enter keeps the filter
+        if self.mode == InputMode::TaskFilter {
+            match key.code {
+                KeyCode::Char(c) => {
+                    self.task_filter.push(c);
+                    self.detail_scroll = 0;
+                }
+                KeyCode::Backspace => {
+                    self.task_filter.pop();
+                    self.detail_scroll = 0;
+                }
+                KeyCode::Enter => {
+                    self.mode = InputMode::Normal;
+                }
+                _ => {}
+            }
+            return Action::Continue;
+        }
+
+        // Search mode: capture all input
+        if self.mode == InputMode::Search {
+            match key.code {
+                KeyCode::Char(c) => {
+                    self.search_query.push(c);
+                    self.recompute_filtered_tracks();
+                }
+                KeyCode::Backspace => {
+                    self.search_query.pop();
+                    self.recompute_filtered_tracks();
+                }
+                KeyCode::Enter => {
+                    self.mode = InputMode::Normal;
+                    self.record_view_state();
+                }
+                _ => {}
+            }
+            return Action::Continue;
+        }
+
+        // Full-screen views: arrows scroll the view instead of moving the
+        // selection, and `s` cycles the tasks view's order
+        if self.main_view != MainView::Tracks {
+            match key.code {
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.view_scroll = self.view_scroll.saturating_add(1);
+                    return Action::Continue;
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.view_scroll = self.view_scroll.saturating_sub(1);
+                    return Action::Continue;
+                }
+                KeyCode::Char('s') if self.main_view == MainView::Tasks => {
+                    self.task_order = self.task_order.next();
+                    self.view_scroll = 0;
+                    return Action::Continue;
+                }
+                _ => {}
+            }
+        }
+
+        if key.code == KeyCode::Char('p') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.open_palette();
+            return Action::Continue;
+        }
+        // Ctrl-O steps back like `<` (Ctrl-I arrives as Tab, so `>` goes forward)
+        if key.code == KeyCode::Char('o') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return self.run_command(Command::ViewBack);
+        }
+
+        // Normal mode keys
+        match key.code {
+            KeyCode::Down | KeyCode::Char('j') => match self.focus {
+                FocusPane::TrackList => self.select_next(),
+                FocusPane::Detail => {
+                    self.detail_scroll = self
+                        .detail_scroll
+                        .saturating_add(1)
+                        .min(self.detail_total_lines.saturating_sub(5));
+                }
+            },
+            KeyCode::Up | KeyCode::Char('k') => match self.focus {
+                FocusPane::TrackList => self.select_previous(),
+                FocusPane::Detail => {
+                    self.detail_scroll = self.detail_scroll.saturating_sub(1);
+                }
+            },
+            KeyCode::Home => return self.run_command(Command::FirstTrack),
+            KeyCode::End => return self.run_command(Command::LastTrack),
+            KeyCode::Enter => return self.run_command(Command::MaximiseDetail),
+            KeyCode::Char('\\') => return self.run_command(Command::MaximiseList),
+            KeyCode::Char('f') => return self.run_command(Command::CycleFilter),
+            KeyCode::Char('s') => return self.run_command(Command::CycleSort),
+            KeyCode::Char('/') => return self.run_command(Command::Search),
+            KeyCode::Char('F') => return self.run_command(Command::FilterTasks),
+            KeyCode::Char('Q') => return self.run_command(Command::Query),
+            KeyCode::Char('m') => return self.run_command(Command::ToggleMine),
+            KeyCode::Char('P') => return self.run_command(Command::CyclePlanFilter),
+            KeyCode::Char('Y') => return self.run_command(Command::CycleTypeFilter),
+            KeyCode::Char('T') => return self.run_command(Command::PickTags),
+            KeyCode::Char('r') => return self.run_command(Command::Refresh),
+            KeyCode::Char('t') => return self.run_command(Command::CycleTheme),
+            KeyCode::Char(':') => self.open_palette(),
+            KeyCode::Char('[') => {
+                self.split_percent = self.split_percent.saturating_sub(5).max(20);
+            }
+            KeyCode::Char(']') => {
+                self.split_percent = (self.split_percent + 5).min(80);
+            }
+            KeyCode::Char('=') => return self.run_command(Command::SetSplit),
+            KeyCode::Char('d') => {
+                self.detail_scroll = self
+                    .detail_scroll
+                    .saturating_add(5)
+                    .min(self.detail_total_lines.saturating_sub(5));
+            }
+            KeyCode::Char('u') => {
+                self.detail_scroll = self.detail_scroll.saturating_sub(5);
+            }
+            KeyCode::Char('p') => return self.run_command(Command::TogglePin),
+            KeyCode::Char('x') => return self.run_command(Command::ToggleComplete),
+            KeyCode::Char('y') => return self.run_command(Command::CopyOutstanding),
+            KeyCode::Char('o') => return self.run_command(Command::CollapsePlan),
+            KeyCode::Char('b') => return self.run_command(Command::ToggleDepTree),
+            KeyCode::Char('n') => return self.run_command(Command::NextOpenTask),
+            KeyCode::Char('R') => return self.run_command(Command::RawPlan),
+            KeyCode::Char('v') => return self.run_command(Command::ToggleCompact),
+            KeyCode::Char('a') => return self.run_command(Command::ToggleRawProgress),
+            KeyCode::Char('z') => return self.run_command(Command::ToggleUtc),
+            KeyCode::Char('A') => return self.run_command(Command::TogglePaths),
+            KeyCode::Char('D') => return self.run_command(Command::ExportDot),
+            KeyCode::Char('O') => return self.run_command(Command::OpenFolder),
+            KeyCode::Char('c') => return self.run_command(Command::ToggleChart),
+            KeyCode::Char('g') => return self.run_command(Command::ToggleMatrix),
+            KeyCode::Char('W') => return self.run_command(Command::ToggleTasksView),
+            KeyCode::Char('B') => return self.run_command(Command::ToggleBranchView),
+            KeyCode::Char('<') => return self.run_command(Command::ViewBack),
+            KeyCode::Char('>') => return self.run_command(Command::ViewForward),
+            KeyCode::Char('e') => return self.run_command(Command::ErrorLog),
+            _ => {}
+        }
+
+        Action::Continue
+    }
+
+    /// Execute a named command (from its key binding or the palette).
+    fn run_command(&mut self, command: Command) -> Action {
+        match command {
+            Command::CycleFilter => {
+                self.filter = self.filter.next();
+                self.recompute_filtered_tracks();
+            }
+            Command::CycleSort => {
+                self.sort = self.sort.next();
+                self.recompute_filtered_tracks();
+            }
+            Command::CycleTheme => {
+                self.theme = self.theme.next();
+            }
+            Command::Search => {
+                self.mode = InputMode::Search;
+            }
+            Command::Query => {
+                self.mode = InputMode::Query;
+            }
+            Command::FilterTasks => {
+                self.mode = InputMode::TaskFilter;
+            }
+            Command::ToggleMine => {
+                if self.mine_pattern.is_some() {
+                    self.mine_only = !self.mine_only;
+                    self.recompute_filtered_tracks();
+                } else {
+                    self.report_error(
+                        "No --mine pattern set; restart with --mine <PATTERN>".to_string(),
+                    );
+                }
+            }
+            Command::PickTags => self.open_tag_picker(),
+            Command::CyclePlanFilter => {
+                self.plan_filter = self.plan_filter.next();
+                self.recompute_filtered_tracks();
+            }
+            Command::CycleTypeFilter => {
+                self.type_filter = self.type_filter.next();
+                self.recompute_filtered_tracks();
+            }
+            Command::TogglePin => self.toggle_pin(),
+            Command::Refresh => return Action::ForceRefresh,
+            Command::ToggleComplete => self.toggle_track_complete(),
+            Command::CopyOutstanding => self.copy_outstanding_tasks(),
+            Command::ToggleDepTree => {
+                self.dep_tree = !self.dep_tree;
+            }
+            Command::ToggleCompact => {
+                self.detail_compact = !self.detail_compact;
+                self.detail_scroll = 0;
+            }
+            Command::CollapsePlan => {
+                self.plan_collapsed = !self.plan_collapsed;
+                self.detail_scroll = 0;
+            }
+            Command::ToggleRawProgress => {
+                self.show_raw_progress = !self.show_raw_progress;
+            }
+            Command::ToggleUtc => {
+                self.utc_dates = !self.utc_dates;
+                self.clock = self.format_clock();
+            }
+            Command::TogglePaths => self.absolute_paths = !self.absolute_paths,
+            Command::ViewBack => self.step_view_history(false),
+            Command::ViewForward => self.step_view_history(true),
+            Command::ExportDot => self.export_dot(),
+            Command::OpenFolder => self.open_folder(),
+            Command::RawPlan => self.open_raw_plan(),
+            Command::NextOpenTask => self.select_next_open_task(),
+            Command::ToggleChart => self.toggle_main_view(MainView::Chart),
+            Command::ToggleMatrix => self.toggle_main_view(MainView::Matrix),
+            Command::ToggleTasksView => self.toggle_main_view(MainView::Tasks),
+            Command::ToggleBranchView => self.toggle_main_view(MainView::Branches),
+            Command::MaximiseDetail => {
+                self.detail_maximised = true;
+                self.list_maximised = false;
+            }
+            Command::MaximiseList => {
+                self.list_maximised = !self.list_maximised;
+                self.detail_maximised = false;
+            }
+            Command::SetSplit => {
+                self.mode = InputMode::SplitInput;
+                self.split_input.clear();
+            }
+            Command::FirstTrack => self.select_first(),
+            Command::LastTrack => self.select_last(),
+            Command::ErrorLog => {
+                self.mode = InputMode::ErrorLog;
+            }
+            Command::Help => {
+                self.mode = InputMode::Help;
+            }
+            Command::Quit => return Action::Quit,
+        }
+        Action::Continue
+    }
+
+    /// Open the tag picker, starting from the applied selection.
+    fn open_tag_picker(&mut self) {
+        if self.all_tags().is_empty() {
+            self.report_error("No tracks have tags to filter by".to_string());
+            return;
+        }
+        self.mode = InputMode::TagPicker;
+        self.tag_picker_draft = self.tag_filter.clone();
+        self.tag_picker_match = self.tag_match;
+        self.tag_picker_selected = 0;
+    }
+
+    /// Every tag used by a track, sorted and listed once regardless of
+    /// case (as first spelled), since tag filters ignore case.
+    fn all_tags(&self) -> Vec<String> {
+        let mut tags: BTreeMap<String, &String> = BTreeMap::new();
+        for tag in self.tracks.values().flat_map(|t| &t.tags) {
+            tags.entry(tag.to_lowercase()).or_insert(tag);
+        }
+        tags.into_values().cloned().collect()
+    }
+
+    fn open_palette(&mut self) {
+        self.mode = InputMode::Palette;
+        self.palette_query.clear();
+        self.palette_selected = 0;
+    }
+
+    /// Palette commands matching the current query, best match first.
+    fn palette_matches(&self) -> Vec<&'static (&'static str, &'static str, Command)> {
+        let mut scored: Vec<(usize, &(&str, &str, Command))> = COMMANDS
+            .iter()
+            .filter_map(|entry| fuzzy_score(&self.palette_query, entry.0).map(|s| (s, entry)))
+            .collect();
+        scored.sort_by_key(|(score, _)| *score);
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    /// Handle mouse events.
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> Action {
+        match mouse.kind {
+            // Click in track list area → select that track
+            MouseEventKind::Down(crossterm::event::MouseButton::Left)
+                if !self.detail_maximised
+                    && self.list_area.contains((mouse.column, mouse.row).into()) =>
+            {
+                // Account for border (1) + header row (1) + header bottom margin (1) = 3 rows offset
+                let row_offset = mouse.row.saturating_sub(self.list_area.y + 3);
+                let track_index = (row_offset / 2) as usize; // each row is height 2
+                if track_index < self.filtered_track_ids.len() {
+                    self.table_state.select(Some(track_index));
+                    self.selected_track = self.filtered_track_ids.get(track_index).cloned();
+                    self.detail_scroll = 0;
+                }
+            }
+            MouseEventKind::ScrollDown => {
+                if self.detail_maximised
+                    || self.detail_area.contains((mouse.column, mouse.row).into())
                 {
                     // Scroll detail panel down
                     self.detail_scroll = self
@@ -408,6 +1663,28 @@ impl App {
         Action::Continue
     }
 
+    /// Whether a changed path is the index file (which may not be named tracks.md).
+    fn is_index_path(&self, path: &Path) -> bool {
+        match &self.index_source {
+            IndexSource::File(index) => index.file_name() == path.file_name(),
+            IndexSource::Content(_) => false,
+        }
+    }
+
+    /// Whether `path` is the board's README.md / overview.md (not a track's).
+    fn is_overview_path(&self, path: &Path) -> bool {
+        let in_conductor_dir = path.parent().is_some_and(|dir| {
+            dir == self.conductor_dir
+                || std::fs::canonicalize(dir).ok()
+                    == std::fs::canonicalize(&self.conductor_dir).ok()
+        });
+        in_conductor_dir
+            && path
+                .file_name()
+                .and_then(|f| f.to_str())
+                .is_some_and(|name| crate::parser::overview::OVERVIEW_FILE_NAMES.contains(&name))
+    }
+
     // ─────────────────────────────────────────────────────────
     // Selection helpers
     // ─────────────────────────────────────────────────────────
@@ -461,12 +1738,150 @@ impl App {
         self.detail_scroll = 0;
     }
 
+    /// Listed tracks with open tasks that can be worked on now: not
+    /// complete, blocked, or in review, no external blockers, and every
+    /// dependency complete. Highest priority first, then list order.
+    fn actionable_track_ids(&self) -> Vec<TrackId> {
+        let mut actionable: Vec<(Priority, usize, &TrackId)> = self
+            .filtered_track_ids
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, id)| {
+                let track = self.tracks.get(id)?;
+                let ready = track.tasks_completed < track.tasks_total
+                    && !matches!(
+                        track.status,
+                        Status::Complete | Status::Blocked | Status::Review
+                    )
+                    && track.external_blockers.is_empty()
+                    && crate::model::unmet_dependencies(&self.tracks, track).is_empty();
+                ready.then_some((track.priority, pos, id))
+            })
+            .collect();
+        actionable.sort();
+        actionable
+            .into_iter()
+            .map(|(_, _, id)| id.clone())
+            .collect()
+    }
+
+    /// Walk the actionable tracks (see [`Self::actionable_track_ids`]): select
+    /// the one after the current selection and scroll to its first open
+    /// task, wrapping to the first at the end.
+    fn select_next_open_task(&mut self) {
+        self.load_all_pending_plans();
+        let actionable = self.actionable_track_ids();
+        let Some(first) = actionable.first() else {
+            self.toast_queue
+                .push_back("No outstanding tasks".to_string());
+            self.advance_toast();
+            return;
+        };
+        let current = self
+            .selected_track
+            .as_ref()
+            .and_then(|selected| actionable.iter().position(|id| id == selected));
+        let next = match current {
+            Some(i) if i + 1 < actionable.len() => &actionable[i + 1],
+            Some(_) => {
+                self.toast_queue
+                    .push_back("No more outstanding tasks; back to the first".to_string());
+                self.advance_toast();
+                first
+            }
+            None => first,
+        };
+        let pos = self.filtered_track_ids.iter().position(|id| id == next);
+        self.table_state.select(pos);
+        self.selected_track = Some(next.clone());
+        self.detail_scroll = 0;
+        self.scroll_to_open_task = true;
+    }
+
     // ─────────────────────────────────────────────────────────
     // Filter / Sort
     // ─────────────────────────────────────────────────────────
 
+    /// Replace the `Q` query and re-filter the list.
+    fn set_query(&mut self, query: String) {
+        self.track_query = TrackQuery::parse(&query);
+        self.query = query;
+        self.recompute_filtered_tracks();
+    }
+
+    fn view_state(&self) -> ViewState {
+        ViewState {
+            filter: self.filter,
+            sort: self.sort,
+            search_query: self.search_query.clone(),
+            query: self.query.clone(),
+            mine_only: self.mine_only,
+            plan_filter: self.plan_filter,
+            type_filter: self.type_filter,
+            tag_filter: self.tag_filter.clone(),
+            tag_match: self.tag_match,
+        }
+    }
+
+    /// Push the current view onto the history unless it is the one shown,
+    /// dropping any forward entries and the oldest beyond the cap. Views
+    /// typed into the search and query prompts are recorded once on Enter.
+    fn record_view_state(&mut self) {
+        if matches!(self.mode, InputMode::Search | InputMode::Query) {
+            return;
+        }
+        let state = self.view_state();
+        if self.view_history.get(self.view_history_pos) == Some(&state) {
+            return;
+        }
+        self.view_history
+            .truncate((self.view_history_pos + 1).min(self.view_history.len()));
+        self.view_history.push(state);
+        if self.view_history.len() > VIEW_HISTORY_LEN {
+            self.view_history.remove(0);
+        }
+        self.view_history_pos = self.view_history.len() - 1;
+    }
+
+    /// Restore the previous (or next) recorded view.
+    fn step_view_history(&mut self, forward: bool) {
+        let pos = if forward {
+            self.view_history_pos + 1
+        } else {
+            match self.view_history_pos.checked_sub(1) {
+                Some(pos) => pos,
+                None => return,
+            }
+        };
+        let Some(state) = self.view_history.get(pos).cloned() else {
+            return;
+        };
+        self.view_history_pos = pos;
+        self.filter = state.filter;
+        self.sort = state.sort;
+        self.search_query = state.search_query;
+        self.track_query = TrackQuery::parse(&state.query);
+        self.query = state.query;
+        self.mine_only = state.mine_only;
+        self.plan_filter = state.plan_filter;
+        self.type_filter = state.type_filter;
+        self.tag_filter = state.tag_filter;
+        self.tag_match = state.tag_match;
+        self.recompute_filtered_tracks();
+        self.toast_queue.push_back(format!(
+            "View {}/{}",
+            self.view_history_pos + 1,
+            self.view_history.len()
+        ));
+        self.advance_toast();
+    }
+
     fn recompute_filtered_tracks(&mut self) {
+        if self.needs_all_plans() {
+            self.load_all_pending_plans();
+        }
         let search_lower = self.search_query.to_ascii_lowercase();
+        let now = chrono::Utc::now();
 
         let mut tracks: Vec<(TrackId, &Track)> = self
             .tracks
@@ -474,10 +1889,33 @@ impl App {
             .filter(|(_, track)| match self.filter {
                 FilterMode::All => true,
                 FilterMode::Active => track.status == Status::InProgress,
-                FilterMode::Blocked => track.status == Status::Blocked,
+                FilterMode::Review => track.status == Status::Review,
+                FilterMode::Blocked => {
+                    track.status == Status::Blocked
+                        || (self.blocked_includes_deps
+                            && !track.is_complete()
+                            && !track.is_blocked_by_incomplete(&self.tracks).is_empty())
+                }
                 FilterMode::Complete => track.status == Status::Complete,
                 FilterMode::New => track.status == Status::New,
             })
+            .filter(|(_, track)| match self.archive_after {
+                Some(max_age) if self.filter != FilterMode::Complete => {
+                    !track.is_archived(now, max_age)
+                }
+                _ => true,
+            })
+            .filter(|(_, track)| match (&self.mine_pattern, self.mine_only) {
+                (Some(pattern), true) => track.is_owned_by(pattern),
+                _ => true,
+            })
+            .filter(|(_, track)| {
+                self.plan_filter
+                    .matches(track.plan_pending || !track.plan_phases.is_empty())
+            })
+            .filter(|(_, track)| self.type_filter.matches(&track.track_type))
+            .filter(|(_, track)| self.tag_match.matches(&self.tag_filter, &track.tags))
+            .filter(|(_, track)| self.track_query.matches(track, now))
             .filter(|(id, track)| {
                 if search_lower.is_empty() {
                     return true;
@@ -488,24 +1926,12 @@ impl App {
             .map(|(id, track)| (id.clone(), track))
             .collect();
 
-        match self.sort {
-            SortMode::Updated => {
-                tracks.sort_by(|(_, a), (_, b)| {
-                    let a_time = a.updated_at.or(a.created_at);
-                    let b_time = b.updated_at.or(b.created_at);
-                    b_time.cmp(&a_time)
-                });
-            }
-            SortMode::Progress => {
-                tracks.sort_by(|(_, a), (_, b)| {
-                    b.progress_percent()
-                        .partial_cmp(&a.progress_percent())
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-            }
-        }
+        tracks.sort_by(|(_, a), (_, b)| crate::model::compare_tracks(a, b, self.sort));
+        // Pinned tracks first, in pin order; the stable sort keeps the rest sorted
+        tracks.sort_by_key(|(id, _)| self.pins.position(id).unwrap_or(usize::MAX));
 
         self.filtered_track_ids = tracks.into_iter().map(|(id, _)| id).collect();
+        self.record_view_state();
 
         // Ensure selection is still visible
         if let Some(ref selected) = self.selected_track {
@@ -528,59 +1954,207 @@ impl App {
     // Toggle track complete
     // ─────────────────────────────────────────────────────────
 
-    fn toggle_track_complete(&mut self) {
-        let Some(track_id) = self.selected_track.clone() else {
+    /// Pin or unpin the selected track and persist the pins. A failed write
+    /// disables persistence for the rest of the session.
+    fn toggle_pin(&mut self) {
+        let Some(id) = self.selected_track.clone() else {
             return;
         };
-        let Some(track) = self.tracks.get(&track_id) else {
+        let pinned = self.pins.toggle(&id);
+        if let Some(path) = &self.pins_path {
+            if let Err(e) = self.pins.save(path) {
+                self.report_error(format!("Failed to save pins: {e}"));
+                self.pins_path = None;
+            }
+        }
+        self.recompute_filtered_tracks();
+        let verb = if pinned { "pinned" } else { "unpinned" };
+        self.toast_queue.push_back(format!("{id}: {verb}"));
+        self.advance_toast();
+    }
+
+    /// Copy the selected track's outstanding tasks to the clipboard as a
+    /// markdown checklist.
+    fn copy_outstanding_tasks(&mut self) {
+        let Some(track) = self
+            .selected_track
+            .as_ref()
+            .and_then(|id| self.tracks.get(id))
+        else {
             return;
         };
-
-        let completing = track.status != Status::Complete;
-        let title = track.title.clone();
-
-        // Write to tracks.md
-        if let Err(e) = self.write_tracks_md_status(&track_id, completing) {
-            self.error_message = Some((format!("Failed to update tracks.md: {e}"), Instant::now()));
+        let checklist: Vec<String> = track
+            .outstanding_tasks()
+            .map(|(_, task)| format!("- [ ] {}", task.text))
+            .collect();
+        if checklist.is_empty() {
+            self.toast_queue
+                .push_back(format!("{}: no outstanding tasks", track.id));
+            self.advance_toast();
             return;
         }
 
-        // Write to metadata.json (if it exists)
-        if let Err(e) = self.write_metadata_status(&track_id, completing) {
-            self.error_message = Some((
-                format!("Failed to update metadata.json: {e}"),
-                Instant::now(),
-            ));
-            return;
+        let count = checklist.len();
+        let noun = if count == 1 { "task" } else { "tasks" };
+        let message = format!("{}: copied {count} outstanding {noun}", track.id);
+        match copy_to_clipboard(&(checklist.join("\n") + "\n")) {
+            Ok(()) => {
+                self.toast_queue.push_back(message);
+                self.advance_toast();
+            }
+            Err(e) => {
+                self.report_error(format!("Failed to copy: {e}"));
+            }
         }
+    }
 
-        // Update in-memory state
-        let track = self.tracks.get_mut(&track_id).expect("track exists");
-        if completing {
-            track.status = Status::Complete;
-            track.checkbox_status = crate::model::CheckboxStatus::Checked;
-            track.mark_all_tasks_complete();
-        } else {
-            track.status = Status::New;
-            track.checkbox_status = crate::model::CheckboxStatus::Unchecked;
+    /// Write the dependency graph as Graphviz DOT to the working directory.
+    fn export_dot(&mut self) {
+        match std::fs::write(DOT_EXPORT_FILE, crate::model::to_dot(&self.tracks)) {
+            Ok(()) => {
+                self.toast_queue
+                    .push_back(format!("Dependency graph written to {DOT_EXPORT_FILE}"));
+                self.advance_toast();
+            }
+            Err(e) => self.report_error(format!("Failed to write {DOT_EXPORT_FILE}: {e}")),
         }
-
-        let verb = if completing { "complete" } else { "new" };
-        self.error_message = Some((format!("✓ Marked '{}' as {}", title, verb), Instant::now()));
-        self.recompute_filtered_tracks();
     }
 
-    fn write_tracks_md_status(
-        &self,
+    /// Show the selected track's plan.md exactly as it is on disk, read fresh
+    /// so it reflects what the parser would see now.
+    fn open_raw_plan(&mut self) {
+        let Some(track_id) = self.selected_track.clone() else {
+            return;
+        };
+        let track_dir = self.track_dir(&track_id);
+        let path =
+            crate::parser::find_plan_file(&track_dir, &self.plan_files).unwrap_or_else(|| {
+                track_dir.join(
+                    self.plan_files
+                        .first()
+                        .map_or(crate::parser::DEFAULT_PLAN_FILE, String::as_str),
+                )
+            });
+        match std::fs::read(&path) {
+            Ok(bytes) => {
+                let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+                self.raw_plan_title = format!("{}/{file_name}", track_id.as_str());
+                self.raw_plan_lines = String::from_utf8_lossy(&bytes)
+                    .lines()
+                    .map(|line| line.replace('\t', "    "))
+                    .collect();
+                self.raw_plan_scroll = 0;
+                self.mode = InputMode::RawPlan;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                self.toast_queue
+                    .push_back(format!("No plan.md for {}", track_id.as_str()));
+                self.advance_toast();
+            }
+            Err(e) => self.report_error(format!("Failed to read {}: {e}", path.display())),
+        }
+    }
+
+    /// Open the selected track's folder (or the conductor dir) in the system
+    /// file manager, copying the path instead when no launcher is available.
+    fn open_folder(&mut self) {
+        let dir = self
+            .selected_track
+            .as_ref()
+            .map(|id| self.track_dir(id))
+            .filter(|dir| dir.is_dir())
+            .unwrap_or_else(|| self.conductor_dir.clone());
+        match spawn_file_manager(&dir) {
+            Ok(()) => {
+                self.toast_queue
+                    .push_back(format!("Opened {}", dir.display()));
+                self.advance_toast();
+            }
+            Err(e) => match copy_to_clipboard(&dir.display().to_string()) {
+                Ok(()) => {
+                    self.toast_queue.push_back(format!(
+                        "Couldn't open folder ({e}); copied {}",
+                        dir.display()
+                    ));
+                    self.advance_toast();
+                }
+                Err(copy_err) => {
+                    self.report_error(format!("Failed to open {}: {e}; {copy_err}", dir.display()))
+                }
+            },
+        }
+    }
+
+    fn toggle_track_complete(&mut self) {
+        let Some(track_id) = self.selected_track.clone() else {
+            return;
+        };
+        let Some(track) = self.tracks.get(&track_id) else {
+            return;
+        };
+
+        let completing = track.status != Status::Complete;
+        let title = track.title.clone();
+
+        // Write to tracks.md
+        if let Err(e) = self.write_tracks_md_status(&track_id, completing) {
+            self.report_error(format!("Failed to update tracks.md: {e}"));
+            return;
+        }
+
+        // Write to the metadata file (if there is one)
+        if let Err(e) = self.write_metadata_status(&track_id, completing) {
+            self.report_error(format!("Failed to update metadata: {e}"));
+            return;
+        }
+
+        // Update in-memory state
+        let track = self.tracks.get_mut(&track_id).expect("track exists");
+        if completing {
+            track.status = Status::Complete;
+            track.checkbox_status = crate::model::CheckboxStatus::Checked;
+            if self.normalize_complete {
+                track.mark_all_tasks_complete();
+            }
+        } else {
+            track.status = Status::New;
+            track.checkbox_status = crate::model::CheckboxStatus::Unchecked;
+        }
+
+        let verb = if completing { "complete" } else { "new" };
+        self.error_message = Some((format!("✓ Marked '{}' as {}", title, verb), Instant::now()));
+        self.recompute_filtered_tracks();
+    }
+
+    fn write_tracks_md_status(
+        &self,
         track_id: &TrackId,
         completing: bool,
     ) -> Result<(), std::io::Error> {
-        let tracks_path = self.conductor_dir.join("tracks.md");
-        let content = std::fs::read_to_string(&tracks_path)?;
+        let source_dir = self
+            .tracks
+            .get(track_id)
+            .and_then(|t| t.source_dir.clone())
+            .filter(|dir| *dir != self.conductor_dir);
+        let extra_index;
+        let tracks_path = match (&source_dir, &self.index_source) {
+            (Some(dir), _) => {
+                extra_index = dir.join("tracks.md");
+                &extra_index
+            }
+            (None, IndexSource::File(path)) => path,
+            (None, IndexSource::Content(_)) => {
+                return Err(std::io::Error::other("tracks.md was read from stdin"));
+            }
+        };
+        let content = std::fs::read_to_string(tracks_path)?;
         let lines: Vec<&str> = content.lines().collect();
         let mut result: Vec<String> = Vec::with_capacity(lines.len());
 
-        let track_id_str = track_id.as_str();
+        let track_id_str = self
+            .tracks
+            .get(track_id)
+            .map_or(track_id.as_str(), |t| t.dir_name());
         let mut i = 0;
         while i < lines.len() {
             let line = lines[i];
@@ -634,34 +2208,31 @@ impl App {
         if content.ends_with('\n') {
             output.push('\n');
         }
-        std::fs::write(&tracks_path, output)
+        std::fs::write(tracks_path, output)
     }
 
+    /// Set the status (and updated date) in the track's metadata file, if
+    /// it has one.
     fn write_metadata_status(
         &self,
         track_id: &TrackId,
         completing: bool,
-    ) -> Result<(), std::io::Error> {
-        let meta_path = self
-            .conductor_dir
-            .join("tracks")
-            .join(track_id.as_str())
-            .join("metadata.json");
-
-        if !meta_path.exists() {
+    ) -> Result<(), crate::parser::error::ParseError> {
+        let track_dir = self.track_dir(track_id);
+        let has_metadata = crate::parser::metadata::METADATA_FILES
+            .iter()
+            .any(|name| track_dir.join(name).exists());
+        if !has_metadata {
             return Ok(());
         }
-
-        let content = std::fs::read_to_string(&meta_path)?;
-        let mut value: serde_json::Value = serde_json::from_str(&content)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))?;
-
         let new_status = if completing { "completed" } else { "new" };
-        value["status"] = serde_json::Value::String(new_status.to_string());
-
-        let output = serde_json::to_string_pretty(&value)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))?;
-        std::fs::write(&meta_path, format!("{}\n", output))
+        crate::parser::metadata::write_metadata_status(
+            &track_dir,
+            track_id.as_str(),
+            new_status,
+            chrono::Utc::now(),
+        )?;
+        Ok(())
     }
 
     // ─────────────────────────────────────────────────────────
@@ -716,7 +2287,17 @@ impl App {
         self.render_status_bar(frame, status_area);
 
         // Main content area
-        if area.width < 80 || self.detail_maximised {
+        if self.main_view != MainView::Tracks {
+            self.list_area = Rect::default();
+            self.detail_area = Rect::default();
+            match self.main_view {
+                MainView::Chart => self.render_progress_chart(frame, main_area),
+                MainView::Matrix => self.render_tag_matrix(frame, main_area),
+                MainView::Tasks => self.render_tasks_view(frame, main_area),
+                MainView::Branches => self.render_branch_groups(frame, main_area),
+                MainView::Tracks => {}
+            }
+        } else if area.width < 80 || self.detail_maximised || self.list_maximised {
             // Narrow terminal or maximised: show only one pane
             if self.detail_maximised && self.selected_track.is_some() {
                 self.detail_area = main_area;
@@ -742,16 +2323,43 @@ impl App {
         }
 
         // Overlays
+        if let Some((ref msg, _)) = self.toast {
+            self.render_toast(frame, main_area, msg);
+        }
         if self.mode == InputMode::Search {
-            self.render_search_overlay(frame, area);
+            self.render_input_line(frame, area, " / ", &self.search_query, "");
+        }
+        if self.mode == InputMode::TaskFilter {
+            self.render_input_line(frame, area, " Tasks: ", &self.task_filter, "");
+        }
+        if self.mode == InputMode::Query {
+            let hint = self.query_hint();
+            self.render_input_line(frame, area, " Query: ", &self.query, &hint);
         }
         if self.mode == InputMode::Help {
             self.render_help_overlay(frame, area);
         }
+        if self.mode == InputMode::Palette {
+            self.render_palette_overlay(frame, area);
+        }
+        if self.mode == InputMode::ErrorLog {
+            self.render_error_log(frame, area);
+        }
+        if self.mode == InputMode::SplitInput {
+            self.render_split_input_overlay(frame, area);
+        }
+        if self.mode == InputMode::TagPicker {
+            self.render_tag_picker_overlay(frame, area);
+        }
+        if self.mode == InputMode::RawPlan {
+            self.render_raw_plan(frame, area);
+        }
     }
 
     fn render_title_bar(&self, frame: &mut Frame, area: Rect) {
-        let watcher_indicator = if self.no_watch {
+        let watcher_indicator = if self.event_loop_stalled {
+            Span::styled("⚠ STALLED", Style::default().fg(self.theme.error))
+        } else if self.no_watch {
             Span::styled("○ STATIC", Style::default().fg(self.theme.text_secondary))
         } else if self.watcher_active {
             Span::styled("● WATCHING", Style::default().fg(self.theme.success))
@@ -759,14 +2367,35 @@ impl App {
             Span::styled("● WATCHER ERROR", Style::default().fg(self.theme.error))
         };
 
-        let padding = area.width.saturating_sub(24 + self.clock.len() as u16 + 12) as usize;
+        let today = match self.tasks_done_today() {
+            Some(n) => format!("{n:+} tasks today"),
+            None => "— tasks today".to_string(),
+        };
+        let right_width = today.chars().count() as u16 + 3 + self.clock.len() as u16 + 12;
+        // Board headline from the overview file, when there's room for it
+        let headline = self
+            .overview
+            .as_ref()
+            .map(|o| {
+                let room = area.width.saturating_sub(24 + 3 + right_width) as usize;
+                truncate(o.headline(), room)
+            })
+            .filter(|h| h.width() >= 4)
+            .map(|h| format!(" · {h}"))
+            .unwrap_or_default();
+        let padding =
+            area.width
+                .saturating_sub(24 + headline.width() as u16 + right_width) as usize;
 
         let title = Line::from(vec![
             Span::styled(
                 " ◇ Conductor Dashboard",
                 Style::default().add_modifier(Modifier::BOLD),
             ),
+            Span::raw(headline),
             Span::raw(" ".repeat(padding)),
+            Span::raw(today),
+            Span::raw(" · "),
             Span::raw(&self.clock),
             Span::raw("  "),
             watcher_indicator,
@@ -793,6 +2422,11 @@ impl App {
             .values()
             .filter(|t| t.status == Status::InProgress)
             .count();
+        let review = self
+            .tracks
+            .values()
+            .filter(|t| t.status == Status::Review)
+            .count();
         let blocked = self
             .tracks
             .values()
@@ -804,7 +2438,7 @@ impl App {
             .filter(|t| t.status == Status::Complete)
             .count();
 
-        let counts = Line::from(vec![
+        let mut counts = vec![
             Span::styled(
                 format!(" {} Total", total),
                 Style::default().add_modifier(Modifier::BOLD),
@@ -815,6 +2449,11 @@ impl App {
                 Style::default().fg(self.theme.accent),
             ),
             Span::raw(" │ "),
+            Span::styled(
+                format!("{} Review", review),
+                Style::default().fg(self.theme.review),
+            ),
+            Span::raw(" │ "),
             Span::styled(
                 format!("{} Blocked", blocked),
                 Style::default().fg(self.theme.warning),
@@ -824,22 +2463,38 @@ impl App {
                 format!("{} Complete", complete),
                 Style::default().fg(self.theme.success),
             ),
-        ]);
-        frame.render_widget(Paragraph::new(counts), counts_area);
+        ];
+
+        let open_tasks = outstanding_by_priority(self.tracks.values());
+        if open_tasks.iter().any(|&n| n > 0) {
+            counts.push(Span::raw(" │ Open tasks: "));
+            for (i, (priority, n)) in Priority::ALL.into_iter().zip(open_tasks).enumerate() {
+                if i > 0 {
+                    counts.push(Span::raw(" · "));
+                }
+                counts.push(Span::styled(
+                    format!("{n} {}", priority.label().to_lowercase()),
+                    Style::default().fg(priority_color(priority, &self.theme)),
+                ));
+            }
+        }
+        frame.render_widget(Paragraph::new(Line::from(counts)), counts_area);
 
         let filter_label = match self.filter {
-            FilterMode::All => "[All]  Active  Blocked  Done  New",
-            FilterMode::Active => " All  [Active] Blocked  Done  New",
-            FilterMode::Blocked => " All   Active [Blocked] Done  New",
-            FilterMode::Complete => " All   Active  Blocked [Done] New",
-            FilterMode::New => " All   Active  Blocked  Done [New]",
+            FilterMode::All => "[All]  Active  Review  Blocked  Done  New",
+            FilterMode::Active => " All  [Active] Review  Blocked  Done  New",
+            FilterMode::Review => " All   Active [Review] Blocked  Done  New",
+            FilterMode::Blocked => " All   Active  Review [Blocked] Done  New",
+            FilterMode::Complete => " All   Active  Review  Blocked [Done] New",
+            FilterMode::New => " All   Active  Review  Blocked  Done [New]",
         };
         let sort_label = match self.sort {
-            SortMode::Updated => "[Recent] Progress",
-            SortMode::Progress => " Recent [Progress]",
+            SortMode::Updated => "[Recent] Progress  Type",
+            SortMode::Progress => " Recent [Progress] Type",
+            SortMode::Type => " Recent  Progress [Type]",
         };
 
-        let controls = Line::from(vec![
+        let mut controls = vec![
             Span::styled(
                 format!(" Filter: {filter_label}"),
                 Style::default().fg(self.theme.text_secondary),
@@ -849,7 +2504,51 @@ impl App {
                 format!("Sort: {sort_label}"),
                 Style::default().fg(self.theme.text_secondary),
             ),
-        ]);
+        ];
+        if let (Some(pattern), true) = (&self.mine_pattern, self.mine_only) {
+            controls.push(Span::raw("  │  "));
+            controls.push(Span::styled(
+                format!("Mine: {pattern}"),
+                Style::default().fg(self.theme.accent),
+            ));
+        }
+        if self.plan_filter != PlanFilter::Any {
+            controls.push(Span::raw("  │  "));
+            controls.push(Span::styled(
+                format!("Plan: {}", self.plan_filter.label()),
+                Style::default().fg(self.theme.accent),
+            ));
+        }
+        if self.type_filter != TypeFilter::All {
+            controls.push(Span::raw("  │  "));
+            controls.push(Span::styled(
+                format!("Type: {}", self.type_filter.label()),
+                Style::default().fg(self.theme.accent),
+            ));
+        }
+        if !self.tag_filter.is_empty() {
+            let separator = format!(" {} ", self.tag_match.label());
+            let tags: Vec<&str> = self.tag_filter.iter().map(String::as_str).collect();
+            controls.push(Span::raw("  │  "));
+            controls.push(Span::styled(
+                format!("Tags: {}", tags.join(&separator)),
+                Style::default().fg(self.theme.accent),
+            ));
+        }
+        if !self.query.trim().is_empty() {
+            controls.push(Span::raw("  │  "));
+            controls.push(Span::styled(
+                format!("Query: {}", self.query.trim()),
+                Style::default().fg(self.theme.accent),
+            ));
+            if !self.track_query.ignored.is_empty() {
+                controls.push(Span::styled(
+                    format!(" (ignored: {})", self.track_query.ignored.join(" ")),
+                    Style::default().fg(self.theme.warning),
+                ));
+            }
+        }
+        let controls = Line::from(controls);
         frame.render_widget(Paragraph::new(controls), controls_area);
     }
 
@@ -868,10 +2567,30 @@ impl App {
         }
     }
 
+    /// Small bottom-right popup announcing newly completed tasks.
+    fn render_toast(&self, frame: &mut Frame, area: Rect, msg: &str) {
+        let width = (msg.chars().count() as u16 + 6).min(area.width);
+        let toast_area = Rect {
+            x: area.x + area.width - width,
+            y: area.y + area.height.saturating_sub(3),
+            width,
+            height: 3.min(area.height),
+        };
+        let block = Block::bordered()
+            .border_style(Style::default().fg(self.theme.success))
+            .style(Style::default().bg(self.theme.surface));
+        let line = Line::from(vec![
+            Span::styled(" ✓ ", Style::default().fg(self.theme.success)),
+            Span::styled(msg, Style::default().fg(self.theme.text_primary)),
+        ]);
+        frame.render_widget(Clear, toast_area);
+        frame.render_widget(Paragraph::new(line).block(block), toast_area);
+    }
+
     fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
         let theme_name = self.theme.name;
 
-        let shortcuts = Line::from(vec![
+        let mut shortcuts = Line::from(vec![
             Span::styled(" ↑↓", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(" Navigate  "),
             Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
@@ -893,6 +2612,9 @@ impl App {
             Span::styled("q", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(format!(" Quit  │ {theme_name}")),
         ]);
+        if !self.refresh_age.is_empty() {
+            shortcuts.push_span(Span::raw(format!(" │ {}", self.refresh_age)));
+        }
 
         frame.render_widget(
             Paragraph::new(shortcuts).style(
@@ -906,8 +2628,13 @@ impl App {
 
     fn render_track_list(&mut self, frame: &mut Frame, area: Rect) {
         let theme = self.theme;
+        let (bar_width, show_tasks) = list_columns(area.width);
 
-        let header = Row::new(vec!["Track", "Status", "Progress", "Tasks"])
+        let mut header_cells = vec!["Track", "Status", "Progress"];
+        if show_tasks {
+            header_cells.push("Tasks");
+        }
+        let header = Row::new(header_cells)
             .style(
                 Style::default()
                     .fg(theme.text_secondary)
@@ -915,52 +2642,119 @@ impl App {
             )
             .bottom_margin(1);
 
+        let task_count = |t: &Track| {
+            format!(
+                "{}/{}",
+                format_count(t.tasks_completed),
+                format_count(t.tasks_total)
+            )
+        };
+        let tasks_width = self
+            .filtered_track_ids
+            .iter()
+            .filter_map(|id| self.tracks.get(id))
+            .map(|t| task_count(t).chars().count() as u16)
+            .max()
+            .unwrap_or(0)
+            .max(6);
+        // Bar plus " 100%"
+        let progress_width = bar_width as u16 + 5;
+
+        // Borders, highlight symbol, the fixed columns, and the column gaps
+        let fixed = 5 + progress_width + if show_tasks { tasks_width + 3 } else { 2 };
+        let title_width = area.width.saturating_sub(2 + 2 + fixed) as usize;
+
         let rows: Vec<Row> = self
             .filtered_track_ids
             .iter()
             .filter_map(|id| self.tracks.get(id).map(|t| (id, t)))
-            .map(|(_id, track)| {
-                let title = Line::from(vec![Span::styled(
-                    &track.title,
+            .map(|(id, track)| {
+                let edited = self.edited_tracks.contains(id);
+                let pinned = self.pins.contains(id);
+                let edges = match self.edge_counts.get(id) {
+                    Some(&(deps, blocks)) if deps + blocks > 0 => {
+                        format!(" ⇢{deps} ⇠{blocks}")
+                    }
+                    _ => String::new(),
+                };
+                let title_room = title_width
+                    .saturating_sub(if edited { 2 } else { 0 })
+                    .saturating_sub(if pinned { 3 } else { 0 })
+                    .saturating_sub(edges.chars().count());
+                let mut title_spans = Vec::new();
+                if pinned {
+                    title_spans.push(Span::raw("📌 "));
+                }
+                title_spans.push(Span::styled(
+                    truncate(&track.title, title_room),
                     Style::default().add_modifier(Modifier::BOLD),
-                )]);
+                ));
+                if !edges.is_empty() {
+                    title_spans.push(Span::styled(
+                        edges,
+                        Style::default().fg(theme.text_secondary),
+                    ));
+                }
+                if edited {
+                    title_spans.push(Span::styled(" •", Style::default().fg(theme.accent)));
+                }
+                let title = Line::from(title_spans);
                 let date_str = track
                     .created_at
-                    .map(|d| d.format("%b %d").to_string())
+                    .map(|d| self.format_date(d, "%b %d"))
                     .unwrap_or_default();
-                let subtitle = Line::from(vec![Span::styled(
-                    format!(
-                        "{}{}",
-                        if track.phase.is_empty() {
-                            String::new()
-                        } else {
-                            format!("{} · ", track.phase)
-                        },
-                        date_str
-                    ),
-                    Style::default().fg(theme.text_secondary),
-                )]);
+                // An active track's current phase stands out while scanning
+                let subtitle = if track.phase.is_empty() {
+                    Line::styled(date_str, Style::default().fg(theme.text_secondary))
+                } else if track.status == Status::InProgress {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("▸ {}", track.phase),
+                            Style::default().fg(theme.accent),
+                        ),
+                        Span::styled(
+                            format!(" · {date_str}"),
+                            Style::default().fg(theme.text_secondary),
+                        ),
+                    ])
+                } else {
+                    Line::styled(
+                        format!("{} · {date_str}", track.phase),
+                        Style::default().fg(theme.text_secondary),
+                    )
+                };
 
-                Row::new(vec![
+                let mut cells = vec![
                     Cell::from(Text::from(vec![title, subtitle])),
                     Cell::from(status_span(&track.status, &theme)),
                     Cell::from(progress_bar_text(
                         track.progress_percent(),
                         &track.status,
+                        bar_width,
                         &theme,
                     )),
-                    Cell::from(format!("{}/{}", track.tasks_completed, track.tasks_total)),
-                ])
-                .height(2)
+                ];
+                if show_tasks {
+                    cells.push(Cell::from(Text::from(vec![
+                        Line::raw(task_count(track)),
+                        remaining_sparkbar(
+                            track.tasks_total.saturating_sub(track.tasks_completed),
+                            &theme,
+                        ),
+                    ])));
+                }
+                Row::new(cells).height(2)
             })
             .collect();
 
-        let widths = [
+        let mut widths = vec![
             Constraint::Fill(1),
             Constraint::Length(5),
-            Constraint::Length(12),
-            Constraint::Length(6),
+            Constraint::Length(progress_width),
         ];
+        if show_tasks {
+            widths.push(Constraint::Length(tasks_width));
+        }
 
         let list_border_color = if self.focus == FocusPane::TrackList {
             theme.accent
@@ -968,6 +2762,15 @@ impl App {
             theme.border
         };
 
+        let highlight_style = if self.reverse_selection {
+            Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
+        } else {
+            Style::default()
+                .bg(theme.selection_bg)
+                .fg(theme.selection_fg)
+                .add_modifier(Modifier::BOLD)
+        };
+
         let table = Table::new(rows, widths)
             .header(header)
             .block(
@@ -975,13 +2778,8 @@ impl App {
                     .border_style(Style::default().fg(list_border_color))
                     .title(" Tracks "),
             )
-            .row_highlight_style(
-                Style::default()
-                    .bg(theme.accent)
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .highlight_symbol("▸ ");
+            .row_highlight_style(highlight_style)
+            .highlight_symbol(self.highlight_symbol.as_str());
 
         frame.render_stateful_widget(table, area, &mut self.table_state);
     }
@@ -1002,9 +2800,29 @@ impl App {
         frame.render_widget(block, area);
 
         let Some(track_id) = &self.selected_track else {
-            let msg = Paragraph::new("Select a track to view details")
+            let mut lines = Vec::new();
+            if let Some(overview) = &self.overview {
+                if !overview.title.is_empty() {
+                    lines.push(Line::styled(
+                        overview.title.clone(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ));
+                }
+                lines.extend(
+                    overview
+                        .description
+                        .lines()
+                        .map(|l| Line::raw(l.to_string())),
+                );
+                lines.push(Line::raw(""));
+            }
+            lines.push(Line::styled(
+                "Select a track to view details",
+                Style::default().fg(theme.text_secondary),
+            ));
+            let msg = Paragraph::new(lines)
                 .alignment(Alignment::Center)
-                .style(Style::default().fg(theme.text_secondary));
+                .wrap(Wrap { trim: true });
             frame.render_widget(msg, inner);
             return;
         };
@@ -1014,6 +2832,15 @@ impl App {
         };
 
         let mut lines: Vec<Line> = Vec::new();
+        // Line of the first open task, for `n` to scroll to
+        let mut first_open_line: Option<usize> = None;
+        // Blank separators between sections and phases, dropped in compact mode
+        let compact = self.detail_compact;
+        let spacer = |lines: &mut Vec<Line>| {
+            if !compact {
+                lines.push(Line::raw(""));
+            }
+        };
 
         // Type label + track ID
         lines.push(Line::from(vec![
@@ -1027,94 +2854,306 @@ impl App {
             Span::styled(track.id.as_str(), Style::default().fg(theme.text_secondary)),
         ]));
 
-        // Title
-        lines.push(Line::from(Span::styled(
-            &track.title,
-            Style::default().add_modifier(Modifier::BOLD),
-        )));
+        // Title, wrapped so long titles stay fully readable
+        for title_line in wrap_text(&track.title, inner.width as usize) {
+            lines.push(Line::styled(
+                title_line,
+                Style::default().add_modifier(Modifier::BOLD),
+            ));
+        }
 
         // Status + created date
         let date_str = track
             .created_at
-            .map(|d| d.format("%b %d, %Y").to_string())
+            .map(|d| self.format_date(d, "%b %d, %Y"))
             .unwrap_or_else(|| "Unknown".to_string());
         lines.push(Line::from(format!(
-            "{} {}  Created: {}",
-            match track.status {
-                Status::InProgress => "⚙ Active",
-                Status::Blocked => "⚠ Blocked",
-                Status::Complete => "✓ Complete",
-                Status::New => "○ New",
-            },
-            "",
+            "{} {}   Created: {}",
+            track.status.glyph(),
+            track.status.label(),
             date_str
         )));
 
-        lines.push(Line::raw(""));
+        // Track folder, which also shows the origin when several conductor
+        // dirs are merged
+        lines.push(Line::styled(
+            format!("Folder: {}", self.display_path(&self.track_dir(track_id))),
+            Style::default().fg(theme.text_secondary),
+        ));
 
-        // Progress bar (full width)
-        let pct = track.progress_percent();
-        let bar_width = inner.width.saturating_sub(14) as usize;
-        let filled = ((pct / 100.0) * bar_width as f32).round() as usize;
-        let empty = bar_width.saturating_sub(filled);
-        let bar_color = if pct >= 100.0 {
-            theme.progress_done
-        } else if pct > 0.0 {
-            theme.progress_active
+        // Raw checkbox vs resolved status (--debug)
+        if self.debug {
+            lines.push(Line::styled(
+                format!(
+                    "debug: checkbox {} → {}",
+                    track.checkbox_status.marker(),
+                    track.status
+                ),
+                Style::default()
+                    .fg(theme.text_secondary)
+                    .add_modifier(Modifier::ITALIC),
+            ));
+        }
+
+        spacer(&mut lines);
+
+        // Progress bar (full width); `a` swaps in the raw plan counts
+        let (done, total, pct) = if self.show_raw_progress {
+            (
+                track.raw_tasks_completed,
+                track.raw_tasks_total,
+                track.raw_progress_percent(),
+            )
         } else {
-            theme.progress_new
+            (
+                track.tasks_completed,
+                track.tasks_total,
+                track.progress_percent(),
+            )
         };
-        lines.push(Line::from(vec![
-            Span::styled(
-                format!("{}/{} ", track.tasks_completed, track.tasks_total),
-                Style::default().add_modifier(Modifier::BOLD),
-            ),
-            Span::styled("█".repeat(filled), Style::default().fg(bar_color)),
-            Span::styled("░".repeat(empty), Style::default().fg(theme.border)),
-            Span::raw(format!(" {:.0}%", pct)),
-        ]));
+        let bar_width = inner.width.saturating_sub(14) as usize;
+        let mut bar_line = vec![Span::styled(
+            format!("{}/{} ", done, total),
+            Style::default().add_modifier(Modifier::BOLD),
+        )];
+        bar_line.extend(wide_bar_spans(
+            pct,
+            bar_width,
+            progress_color(Some(track.status), pct, &theme),
+            &theme,
+        ));
+        bar_line.push(Span::raw(format!(" {:.0}%", pct)));
+        lines.push(Line::from(bar_line));
+        if let Some(warning) = track.task_count_warning() {
+            lines.push(Line::styled(
+                format!("⚠ {warning}"),
+                Style::default()
+                    .fg(theme.warning)
+                    .add_modifier(Modifier::DIM),
+            ));
+        }
+        if self.show_raw_progress && track.raw_tasks_completed != track.tasks_completed {
+            lines.push(Line::styled(
+                "(actual plan ticks; shown as 100% because the track is complete)",
+                Style::default()
+                    .fg(theme.text_secondary)
+                    .add_modifier(Modifier::ITALIC),
+            ));
+        }
 
-        lines.push(Line::raw(""));
+        spacer(&mut lines);
 
-        // Dependencies
-        if !track.dependencies.is_empty() {
-            let dep_str: Vec<&str> = track.dependencies.iter().map(|d| d.as_str()).collect();
+        // Dependencies: one flat line, or the full upstream tree (`b`). Only
+        // a warning while some dependency, direct or upstream, is unmet.
+        let unmet = track.is_blocked_by_incomplete(&self.tracks);
+        let (deps_label, deps_color) = if unmet.is_empty() {
+            ("Depends on:", theme.text_secondary)
+        } else {
+            ("⚠ Blocked by:", theme.warning)
+        };
+        if !track.dependencies.is_empty() && self.dep_tree {
+            lines.push(Line::styled(deps_label, Style::default().fg(deps_color)));
+            for (depth, dep) in crate::model::dependency_tree(&self.tracks, &track.id) {
+                let (icon, color) = match self.tracks.get(&dep).map(|t| t.status) {
+                    Some(status) => (status.glyph(), status_color(status, &theme)),
+                    None => ("?", theme.error),
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("  {}└ ", "  ".repeat(depth)),
+                        Style::default().fg(theme.border),
+                    ),
+                    Span::styled(format!("{icon} "), Style::default().fg(color)),
+                    Span::raw(dep.as_str().to_string()),
+                ]));
+            }
+            spacer(&mut lines);
+        } else if !track.dependencies.is_empty() {
+            // Unmet dependencies in the warning color, satisfied ones in green,
+            // then anything further upstream still holding those up
+            let mut spans = vec![Span::styled(
+                format!("{deps_label} "),
+                Style::default().fg(deps_color),
+            )];
+            for (i, dep) in track.dependencies.iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::raw(", "));
+                }
+                let color = if unmet.contains(dep) {
+                    theme.warning
+                } else {
+                    theme.success
+                };
+                spans.push(Span::styled(dep.as_str(), Style::default().fg(color)));
+            }
+            let upstream: Vec<&str> = unmet
+                .iter()
+                .filter(|dep| !track.dependencies.contains(dep))
+                .map(TrackId::as_str)
+                .collect();
+            if !upstream.is_empty() {
+                spans.push(Span::styled(
+                    format!(" (upstream: {})", upstream.join(", ")),
+                    Style::default().fg(theme.warning),
+                ));
+            }
+            lines.push(Line::from(spans));
+            spacer(&mut lines);
+        }
+        if !track.external_blockers.is_empty() {
             lines.push(Line::styled(
-                format!("⚠ Blocked by: {}", dep_str.join(", ")),
+                "⛔ Waiting on (external):",
                 Style::default().fg(theme.warning),
             ));
-            lines.push(Line::raw(""));
+            for blocker in &track.external_blockers {
+                lines.push(Line::from(vec![
+                    Span::styled("  • ", Style::default().fg(theme.border)),
+                    Span::raw(blocker.clone()),
+                ]));
+            }
+            spacer(&mut lines);
+        }
+        let impact = crate::model::downstream_dependents(
+            &crate::model::dependents_map(&self.tracks),
+            &track.id,
+        )
+        .len();
+        if impact > 0 {
+            let noun = if impact == 1 { "track" } else { "tracks" };
+            lines.push(Line::styled(
+                format!("⛓ Impact: {impact} {noun} waiting on this (directly or transitively)"),
+                Style::default().fg(theme.accent),
+            ));
+            spacer(&mut lines);
         }
 
-        // Implementation Plan heading
-        if !track.plan_phases.is_empty() {
+        // Spec title and acceptance criteria from spec.md
+        if track.spec_summary.is_some() || !track.acceptance_criteria.is_empty() {
             lines.push(Line::from(vec![
                 Span::styled("━━ ", Style::default().fg(theme.accent)),
                 Span::styled(
-                    "IMPLEMENTATION PLAN",
+                    "SPEC",
                     Style::default()
                         .fg(theme.accent)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" ━━", Style::default().fg(theme.accent)),
             ]));
-            lines.push(Line::raw(""));
+            if let Some(summary) = &track.spec_summary {
+                lines.push(Line::raw(summary.clone()));
+            }
+            if !track.acceptance_criteria.is_empty() {
+                lines.push(Line::styled(
+                    "Acceptance criteria:",
+                    Style::default().fg(theme.text_secondary),
+                ));
+                for criterion in &track.acceptance_criteria {
+                    lines.push(Line::from(vec![
+                        Span::styled("  • ", Style::default().fg(theme.border)),
+                        Span::raw(criterion.clone()),
+                    ]));
+                }
+            }
+            spacer(&mut lines);
+        }
 
-            for phase in &track.plan_phases {
-                let phase_icon = match phase.status {
-                    PhaseStatus::Complete => "●",
-                    PhaseStatus::Active => "◐",
-                    PhaseStatus::Pending => "○",
-                    PhaseStatus::Blocked => "⊘",
+        // Phase completion order for finished tracks with dated phase headings
+        let mut dated: Vec<(chrono::DateTime<chrono::Utc>, &PlanPhase)> = track
+            .plan_phases
+            .iter()
+            .filter_map(|p| p.completed_at.map(|at| (at, p)))
+            .collect();
+        if track.is_complete() && !dated.is_empty() {
+            dated.sort_by_key(|(at, _)| *at);
+            lines.push(Line::from(vec![
+                Span::styled("━━ ", Style::default().fg(theme.accent)),
+                Span::styled(
+                    "TIMELINE",
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(" ━━", Style::default().fg(theme.accent)),
+            ]));
+            let mut prev: Option<chrono::DateTime<chrono::Utc>> = None;
+            for (i, (at, phase)) in dated.iter().enumerate() {
+                let connector = if i + 1 == dated.len() {
+                    "└─"
+                } else {
+                    "├─"
                 };
-                let icon_color = match phase.status {
-                    PhaseStatus::Complete => theme.success,
-                    PhaseStatus::Active => theme.accent,
-                    PhaseStatus::Pending => theme.text_secondary,
-                    PhaseStatus::Blocked => theme.warning,
+                let mut spans = vec![
+                    Span::styled(
+                        format!("{} ", self.format_date(*at, "%Y-%m-%d")),
+                        Style::default().fg(theme.text_secondary),
+                    ),
+                    Span::styled(connector, Style::default().fg(theme.border)),
+                    Span::styled("● ", Style::default().fg(theme.success)),
+                    Span::raw(phase.name.as_str()),
+                ];
+                if let Some(prev) = prev {
+                    spans.push(Span::styled(
+                        format!("  +{}d", (*at - prev).num_days()),
+                        Style::default().fg(theme.text_secondary),
+                    ));
+                }
+                lines.push(Line::from(spans));
+                prev = Some(*at);
+            }
+            spacer(&mut lines);
+        }
+
+        // Implementation Plan heading
+        if !track.plan_phases.is_empty() {
+            let task_filter = self.task_filter.to_lowercase();
+            let task_matches = |task: &PlanTask| task.text.to_lowercase().contains(&task_filter);
+            let mut heading = vec![
+                Span::styled("━━ ", Style::default().fg(theme.accent)),
+                Span::styled(
+                    "IMPLEMENTATION PLAN",
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(" ━━", Style::default().fg(theme.accent)),
+            ];
+            if !task_filter.is_empty() {
+                heading.push(Span::styled(
+                    format!("  tasks matching \"{}\" (Esc clears)", self.task_filter),
+                    Style::default().fg(theme.text_secondary),
+                ));
+            }
+            lines.push(Line::from(heading));
+            spacer(&mut lines);
+            if !task_filter.is_empty()
+                && !track
+                    .plan_phases
+                    .iter()
+                    .any(|p| p.tasks.iter().any(task_matches))
+            {
+                lines.push(Line::styled(
+                    "No tasks match",
+                    Style::default().fg(theme.text_secondary),
+                ));
+            }
+
+            let now = chrono::Utc::now();
+            for (i, phase) in track.plan_phases.iter().enumerate() {
+                let indent = "  ".repeat(phase.depth);
+                let phase_icon = phase.status.glyph();
+                let icon_color = phase_status_color(phase.status, &theme);
+                // A parent phase without direct tasks reports its sub-phases' totals
+                let counted = match crate::model::sub_phases_of(&track.plan_phases, i) {
+                    [] => std::slice::from_ref(phase),
+                    subs => subs,
                 };
-                let done = phase.tasks.iter().filter(|t| t.done).count();
-                let total = phase.tasks.len();
+                if !task_filter.is_empty()
+                    && !counted.iter().any(|p| p.tasks.iter().any(task_matches))
+                {
+                    continue;
+                }
+                let done: usize = counted.iter().map(|p| p.tasks_completed()).sum();
+                let total: usize = counted.iter().map(|p| p.tasks.len()).sum();
 
                 // Phase header with background highlight for active phases
                 let phase_name_style = match phase.status {
@@ -1133,37 +3172,91 @@ impl App {
                     _ => Style::default().fg(theme.text_secondary),
                 };
 
-                lines.push(Line::from(vec![
+                let mut header = vec![
+                    Span::raw(indent.clone()),
                     Span::styled(phase_icon, Style::default().fg(icon_color)),
                     Span::styled(format!(" {} ", phase.name), phase_name_style),
                     Span::styled(format!("({}/{})", done, total), count_style),
-                ]));
+                ];
+
+                // Collapsed: one line per phase with its progress bar, no tasks
+                if self.plan_collapsed {
+                    let pct = if total > 0 {
+                        done as f32 / total as f32 * 100.0
+                    } else {
+                        0.0
+                    };
+                    header.push(Span::raw("  "));
+                    header.extend(wide_bar_spans(
+                        pct,
+                        12,
+                        progress_color(None, pct, &theme),
+                        &theme,
+                    ));
+                    header.push(Span::styled(format!(" {pct:>3.0}%"), count_style));
+                    if first_open_line.is_none() && phase.tasks.iter().any(|t| !t.done) {
+                        first_open_line = Some(lines.len());
+                    }
+                    lines.push(Line::from(header));
+                    continue;
+                }
+                lines.push(Line::from(header));
 
-                for task in &phase.tasks {
+                for task in phase.tasks.iter().filter(|t| task_matches(t)) {
+                    if !task.done && first_open_line.is_none() {
+                        first_open_line = Some(lines.len());
+                    }
+                    // Subtasks sit under their parent task
+                    let indent = format!("{indent}{}", "  ".repeat(task.depth.into()));
                     if task.done {
                         lines.push(Line::from(vec![
-                            Span::styled("  ✓ ", Style::default().fg(theme.success)),
+                            Span::styled(
+                                format!("{indent}  ✓ "),
+                                Style::default().fg(theme.success),
+                            ),
                             Span::styled(&task.text, Style::default().fg(theme.text_secondary)),
                         ]));
                     } else {
-                        lines.push(Line::from(vec![
-                            Span::styled("  ○ ", Style::default().fg(theme.warning)),
+                        let overdue = task.is_overdue(now);
+                        let text_color = if overdue { theme.error } else { Color::White };
+                        let mut spans = vec![
+                            Span::styled(
+                                format!("{indent}  ○ "),
+                                Style::default().fg(if overdue {
+                                    theme.error
+                                } else {
+                                    theme.warning
+                                }),
+                            ),
                             Span::styled(
                                 &task.text,
-                                Style::default()
-                                    .fg(Color::White)
-                                    .add_modifier(Modifier::BOLD),
+                                Style::default().fg(text_color).add_modifier(Modifier::BOLD),
                             ),
-                        ]));
+                        ];
+                        if let Some(due) = task.due {
+                            spans.push(Span::styled(
+                                format!("  due {}", self.format_date(due, "%Y-%m-%d")),
+                                Style::default().fg(if overdue {
+                                    theme.error
+                                } else {
+                                    theme.text_secondary
+                                }),
+                            ));
+                        }
+                        lines.push(Line::from(spans));
                     }
                 }
 
-                lines.push(Line::raw(""));
+                spacer(&mut lines);
             }
         }
 
         let total_lines = lines.len() as u16;
         self.detail_total_lines = total_lines;
+        if std::mem::take(&mut self.scroll_to_open_task) {
+            // Keep the phase header just above the task in view
+            self.detail_scroll = first_open_line.map_or(0, |line| line.saturating_sub(2)) as u16;
+        }
 
         let paragraph = Paragraph::new(lines).scroll((self.detail_scroll, 0));
         frame.render_widget(paragraph, inner);
@@ -1184,7 +3277,499 @@ impl App {
         }
     }
 
-    fn render_search_overlay(&self, frame: &mut Frame, area: Rect) {
+    /// Show `which` full-screen, or go back to the track list if it is showing.
+    fn toggle_main_view(&mut self, which: MainView) {
+        self.main_view = if self.main_view == which {
+            MainView::Tracks
+        } else {
+            which
+        };
+        self.view_scroll = 0;
+        self.load_pending_plans();
+    }
+
+    /// Lines of a full-screen view at `view_scroll`, with a scrollbar when
+    /// they overflow `area`.
+    fn render_view_lines(&mut self, frame: &mut Frame, lines: Vec<Line>, area: Rect) {
+        let total_lines = lines.len() as u16;
+        self.view_scroll = self.view_scroll.min(total_lines.saturating_sub(1));
+        frame.render_widget(Paragraph::new(lines).scroll((self.view_scroll, 0)), area);
+
+        if total_lines > area.height {
+            let mut scrollbar_state =
+                ScrollbarState::new(total_lines as usize).position(self.view_scroll as usize);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight),
+                area,
+                &mut scrollbar_state,
+            );
+        }
+    }
+
+    /// One horizontal progress bar per visible track, in the current sort order.
+    fn render_progress_chart(&mut self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme;
+
+        let block = Block::bordered()
+            .border_style(Style::default().fg(theme.accent))
+            .title(" Progress ");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let label_width = (inner.width / 3).clamp(10, 40) as usize;
+        // label + space + bar + " 100%" + scrollbar column
+        let bar_width = (inner.width as usize).saturating_sub(label_width + 7);
+
+        let lines: Vec<Line> = self
+            .filtered_track_ids
+            .iter()
+            .filter_map(|id| self.tracks.get(id))
+            .map(|track| {
+                let pct = track.progress_percent();
+                let mut spans = vec![Span::raw(format!(
+                    "{} ",
+                    fit_width(&track.title, label_width)
+                ))];
+                spans.extend(wide_bar_spans(
+                    pct,
+                    bar_width,
+                    progress_color(Some(track.status), pct, &theme),
+                    &theme,
+                ));
+                spans.push(Span::raw(format!(" {:>3.0}%", pct)));
+                Line::from(spans)
+            })
+            .collect();
+
+        self.render_view_lines(frame, lines, inner);
+    }
+
+    /// Pivot table: one row per tag, one column per status, cells are track
+    /// counts over the currently filtered tracks.
+    fn render_tag_matrix(&mut self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme;
+
+        let block = Block::bordered()
+            .border_style(Style::default().fg(theme.accent))
+            .title(" Tags × Status ");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let tracks = self
+            .filtered_track_ids
+            .iter()
+            .filter_map(|id| self.tracks.get(id));
+        let matrix = tag_status_matrix(tracks);
+
+        let label_width = (inner.width / 3).clamp(10, 30) as usize;
+        let cell = |text: &str| format!("{text:>10}");
+        let mut header = vec![Span::raw(fit_width("Tag", label_width))];
+        for status in MATRIX_STATUSES {
+            header.push(Span::raw(cell(status.label())));
+        }
+        header.push(Span::raw(cell("Total")));
+        let header = Line::from(header).style(
+            Style::default()
+                .fg(theme.text_secondary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let rows: Vec<Line> = matrix
+            .iter()
+            .map(|(tag, counts)| {
+                let mut spans = vec![Span::raw(fit_width(tag, label_width))];
+                for (status, count) in MATRIX_STATUSES.iter().zip(counts) {
+                    let color = match (status, count) {
+                        (_, 0) => theme.border,
+                        (Status::Complete, _) => theme.success,
+                        (Status::Blocked, _) => theme.warning,
+                        (Status::InProgress, _) => theme.accent,
+                        (Status::Review, _) => theme.review,
+                        _ => theme.text_primary,
+                    };
+                    spans.push(Span::styled(
+                        cell(&count.to_string()),
+                        Style::default().fg(color),
+                    ));
+                }
+                let total: usize = counts.iter().sum();
+                spans.push(Span::styled(
+                    cell(&total.to_string()),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ));
+                Line::from(spans)
+            })
+            .collect();
+
+        let [header_area, body_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(inner);
+        frame.render_widget(Paragraph::new(header), header_area);
+
+        self.render_view_lines(frame, rows, body_area);
+    }
+
+    fn render_branch_groups(&mut self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme;
+
+        let block = Block::bordered()
+            .border_style(Style::default().fg(theme.accent))
+            .title(" Tracks by branch prefix ");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let groups = branch_groups(
+            self.filtered_track_ids
+                .iter()
+                .filter_map(|id| self.tracks.get(id)),
+        );
+        let title_width = (inner.width / 2).clamp(10, 50) as usize;
+        let mut lines: Vec<Line> = Vec::new();
+        for (prefix, tracks) in &groups {
+            if !lines.is_empty() {
+                lines.push(Line::raw(""));
+            }
+            lines.push(Line::styled(
+                format!(
+                    "{} ({})",
+                    prefix.as_deref().unwrap_or(NO_BRANCH),
+                    tracks.len()
+                ),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            for track in tracks {
+                lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(
+                        format!("{} ", track.status.glyph()),
+                        Style::default().fg(status_color(track.status, &theme)),
+                    ),
+                    Span::raw(fit_width(&track.title, title_width)),
+                    Span::styled(
+                        format!("{:>4.0}%  ", track.progress_percent()),
+                        Style::default().fg(theme.text_secondary),
+                    ),
+                    Span::styled(
+                        track.branch.clone().unwrap_or_default(),
+                        Style::default().fg(theme.text_secondary),
+                    ),
+                ]));
+            }
+        }
+        if lines.is_empty() {
+            lines.push(Line::styled(
+                "No tracks",
+                Style::default().fg(theme.text_secondary),
+            ));
+        }
+
+        self.render_view_lines(frame, lines, inner);
+    }
+
+    /// Outstanding tasks of the listed tracks in `task_order`, as
+    /// `(track, phase index, phase, task)`. Ties keep list then plan order.
+    fn ordered_outstanding_tasks(&self) -> Vec<(&Track, usize, &PlanPhase, &PlanTask)> {
+        let mut rows: Vec<(usize, &Track, usize, &PlanPhase, &PlanTask)> = Vec::new();
+        for (position, track) in self
+            .filtered_track_ids
+            .iter()
+            .filter_map(|id| self.tracks.get(id))
+            .enumerate()
+        {
+            for (phase_index, phase) in track.plan_phases.iter().enumerate() {
+                for task in phase.tasks.iter().filter(|t| !t.done) {
+                    rows.push((position, track, phase_index, phase, task));
+                }
+            }
+        }
+        // Stable sorts, so equal keys stay in list then plan order
+        match self.task_order {
+            TaskOrder::Priority => rows.sort_by_key(|(_, track, ..)| track.priority),
+            TaskOrder::Track => rows.sort_by(|a, b| {
+                let title = |t: &Track| t.title.to_lowercase();
+                title(a.1).cmp(&title(b.1)).then(a.0.cmp(&b.0))
+            }),
+            TaskOrder::Phase => rows.sort_by_key(|(_, _, phase_index, ..)| *phase_index),
+        }
+        rows.into_iter()
+            .map(|(_, track, phase_index, phase, task)| (track, phase_index, phase, task))
+            .collect()
+    }
+
+    fn render_tasks_view(&mut self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme;
+        let rows = self.ordered_outstanding_tasks();
+
+        let block = Block::bordered()
+            .border_style(Style::default().fg(theme.accent))
+            .title(format!(" Outstanding tasks ({}) ", rows.len()))
+            .title_bottom(format!(" Order: {} · s cycles ", self.task_order.label()));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let track_width = (inner.width / 4).clamp(10, 30) as usize;
+        let phase_width = (inner.width / 5).clamp(8, 24) as usize;
+        let lines: Vec<Line> = if rows.is_empty() {
+            vec![Line::styled(
+                "No outstanding tasks",
+                Style::default().fg(theme.text_secondary),
+            )]
+        } else {
+            rows.iter()
+                .map(|(track, _, phase, task)| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{:<9}", track.priority.label()),
+                            Style::default().fg(priority_color(track.priority, &theme)),
+                        ),
+                        Span::raw(fit_width(&track.title, track_width)),
+                        Span::styled(
+                            fit_width(&phase.name, phase_width),
+                            Style::default().fg(theme.text_secondary),
+                        ),
+                        Span::raw(format!("○ {}", task.text)),
+                    ])
+                })
+                .collect()
+        };
+
+        self.render_view_lines(frame, lines, inner);
+    }
+
+    /// Full text of the last error plus recent history, newest first.
+    fn render_error_log(&self, frame: &mut Frame, area: Rect) {
+        let mut lines = Vec::new();
+        match self.error_history.back() {
+            Some((_, last)) => {
+                lines.push(Line::styled(
+                    "Last error",
+                    Style::default().add_modifier(Modifier::BOLD),
+                ));
+                lines.push(Line::styled(
+                    last.clone(),
+                    Style::default().fg(self.theme.error),
+                ));
+            }
+            None => lines.push(Line::styled(
+                "No errors recorded",
+                Style::default().fg(self.theme.text_secondary),
+            )),
+        }
+        if self.error_history.len() > 1 {
+            lines.push(Line::raw(""));
+            lines.push(Line::styled(
+                "Recent",
+                Style::default().add_modifier(Modifier::BOLD),
+            ));
+            for (when, msg) in self.error_history.iter().rev() {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("{}  ", when.format("%H:%M:%S")),
+                        Style::default().fg(self.theme.text_secondary),
+                    ),
+                    Span::raw(msg.clone()),
+                ]));
+            }
+        }
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            "c clear · any other key close",
+            Style::default().fg(self.theme.text_secondary),
+        ));
+
+        let popup = centered_rect(80, area.height.saturating_sub(4), area);
+        let block = Block::bordered()
+            .title(" Error Log ")
+            .border_style(Style::default().fg(self.theme.error))
+            .style(Style::default().bg(self.theme.surface));
+        frame.render_widget(Clear, popup);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false }),
+            popup,
+        );
+    }
+
+    /// Verbatim plan.md with a line-number gutter, unwrapped so line numbers
+    /// match the file.
+    fn render_raw_plan(&self, frame: &mut Frame, area: Rect) {
+        let gutter = self.raw_plan_lines.len().max(1).to_string().len();
+        let lines: Vec<Line> = if self.raw_plan_lines.is_empty() {
+            vec![Line::styled(
+                "plan.md is empty",
+                Style::default().fg(self.theme.text_secondary),
+            )]
+        } else {
+            self.raw_plan_lines
+                .iter()
+                .enumerate()
+                .map(|(i, text)| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{:>gutter$} │ ", i + 1),
+                            Style::default().fg(self.theme.text_secondary),
+                        ),
+                        Span::raw(text.clone()),
+                    ])
+                })
+                .collect()
+        };
+
+        let popup = centered_rect(90, area.height.saturating_sub(4), area);
+        let block = Block::bordered()
+            .title(format!(" {} ", self.raw_plan_title))
+            .title_bottom(" j/k scroll · d/u page · g/G top/bottom · Esc close ")
+            .border_style(Style::default().fg(self.theme.accent))
+            .style(Style::default().bg(self.theme.surface));
+        frame.render_widget(Clear, popup);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .scroll((self.raw_plan_scroll, 0)),
+            popup,
+        );
+    }
+
+    fn render_palette_overlay(&self, frame: &mut Frame, area: Rect) {
+        const MAX_ROWS: usize = 12;
+        let matches = self.palette_matches();
+        let height = (matches.len().clamp(1, MAX_ROWS) + 4) as u16;
+        let popup = centered_rect(50, height, area);
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(
+                    " : ",
+                    Style::default()
+                        .fg(self.theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(&self.palette_query),
+                Span::styled("█", Style::default().fg(self.theme.accent)),
+            ]),
+            Line::raw(""),
+        ];
+        if matches.is_empty() {
+            lines.push(Line::styled(
+                "  No matching commands",
+                Style::default().fg(self.theme.text_secondary),
+            ));
+        }
+        let key_width = 6;
+        let label_width = (popup.width as usize).saturating_sub(key_width + 6);
+        let first = self.palette_selected.saturating_sub(MAX_ROWS - 1);
+        for (i, (label, key, _)) in matches.iter().enumerate().skip(first).take(MAX_ROWS) {
+            let style = if i == self.palette_selected {
+                Style::default()
+                    .fg(self.theme.text_on_bar)
+                    .bg(self.theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(self.theme.text_primary)
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {} ", fit_width(label, label_width)), style),
+                Span::styled(
+                    format!("{key:>key_width$} "),
+                    style.fg(if i == self.palette_selected {
+                        self.theme.text_on_bar
+                    } else {
+                        self.theme.text_secondary
+                    }),
+                ),
+            ]));
+        }
+
+        let block = Block::bordered()
+            .title(" Commands ")
+            .border_style(Style::default().fg(self.theme.accent))
+            .style(Style::default().bg(self.theme.surface));
+        frame.render_widget(Clear, popup);
+        frame.render_widget(Paragraph::new(lines).block(block), popup);
+    }
+
+    fn render_tag_picker_overlay(&self, frame: &mut Frame, area: Rect) {
+        const MAX_ROWS: usize = 12;
+        let tags = self.all_tags();
+        let height = (tags.len().clamp(1, MAX_ROWS) + 6) as u16;
+        let popup = centered_rect(40, height, area);
+
+        let mode_style = |mode: TagMatch| {
+            if mode == self.tag_picker_match {
+                Style::default()
+                    .fg(self.theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(self.theme.text_secondary)
+            }
+        };
+        let mut lines = vec![
+            Line::from(vec![
+                Span::raw(" Match: "),
+                Span::styled("any (OR)", mode_style(TagMatch::Any)),
+                Span::raw(" / "),
+                Span::styled("all (AND)", mode_style(TagMatch::All)),
+            ]),
+            Line::raw(""),
+        ];
+        let label_width = (popup.width as usize).saturating_sub(10);
+        let first = self.tag_picker_selected.saturating_sub(MAX_ROWS - 1);
+        for (i, tag) in tags.iter().enumerate().skip(first).take(MAX_ROWS) {
+            let style = if i == self.tag_picker_selected {
+                Style::default()
+                    .fg(self.theme.text_on_bar)
+                    .bg(self.theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(self.theme.text_primary)
+            };
+            let mark = if self.tag_picker_draft.contains(tag) {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            lines.push(Line::styled(
+                format!("  {mark} {}", fit_width(tag, label_width)),
+                style,
+            ));
+        }
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            " Space toggle · Tab AND/OR · c clear · Enter apply",
+            Style::default().fg(self.theme.text_secondary),
+        ));
+
+        let block = Block::bordered()
+            .title(" Tags ")
+            .border_style(Style::default().fg(self.theme.accent))
+            .style(Style::default().bg(self.theme.surface));
+        frame.render_widget(Clear, popup);
+        frame.render_widget(Paragraph::new(lines).block(block), popup);
+    }
+
+    /// Note on query tokens that were skipped, or a reminder of the syntax
+    /// while the query is empty.
+    fn query_hint(&self) -> String {
+        if self.query.trim().is_empty() {
+            "  status:blocked tag:backend prio:high type:bug overdue <words>".to_string()
+        } else if self.track_query.ignored.is_empty() {
+            String::new()
+        } else {
+            format!("  ignored: {}", self.track_query.ignored.join(" "))
+        }
+    }
+
+    /// One-line text prompt (search, task filter) across the top of the list.
+    fn render_input_line(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        prompt: &str,
+        text: &str,
+        hint: &str,
+    ) {
         let search_area = Rect {
             x: area.x + 1,
             y: area.y + 3,
@@ -1196,13 +3781,17 @@ impl App {
 
         let search_line = Line::from(vec![
             Span::styled(
-                " / ",
+                prompt.to_string(),
                 Style::default()
                     .fg(self.theme.accent)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw(&self.search_query),
+            Span::raw(text),
             Span::styled("█", Style::default().fg(self.theme.accent)),
+            Span::styled(
+                hint.to_string(),
+                Style::default().fg(self.theme.text_secondary),
+            ),
         ]);
 
         frame.render_widget(
@@ -1215,10 +3804,42 @@ impl App {
         );
     }
 
-    fn render_help_overlay(&self, frame: &mut Frame, area: Rect) {
-        let popup_area = centered_rect(60, 20, area);
-        frame.render_widget(Clear, popup_area);
+    fn render_split_input_overlay(&self, frame: &mut Frame, area: Rect) {
+        let input_area = Rect {
+            x: area.x + 1,
+            y: area.y + 3,
+            width: area.width.saturating_sub(2),
+            height: 1,
+        };
 
+        frame.render_widget(Clear, input_area);
+
+        let input_line = Line::from(vec![
+            Span::styled(
+                " Split % ",
+                Style::default()
+                    .fg(self.theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(&self.split_input),
+            Span::styled("█", Style::default().fg(self.theme.accent)),
+            Span::styled(
+                format!("  20–80, currently {}", self.split_percent),
+                Style::default().fg(self.theme.text_secondary),
+            ),
+        ]);
+
+        frame.render_widget(
+            Paragraph::new(input_line).style(
+                Style::default()
+                    .bg(self.theme.surface)
+                    .fg(self.theme.text_primary),
+            ),
+            input_area,
+        );
+    }
+
+    fn render_help_overlay(&self, frame: &mut Frame, area: Rect) {
         let help_text = vec![
             Line::styled(
                 "Keyboard Shortcuts",
@@ -1230,24 +3851,65 @@ impl App {
             Line::raw("  Tab       Switch pane focus"),
             Line::raw("  Home/End  First/last track"),
             Line::raw("  Enter     Maximise detail panel"),
+            Line::raw("  \\         Maximise track list"),
             Line::raw("  Esc       Return to split view / reset focus"),
-            Line::raw("  f         Cycle filter (All → Active → Blocked → Done → New)"),
-            Line::raw("  s         Cycle sort (Recent ↔ Progress)"),
+            Line::raw("  f         Cycle filter (All → Active → Review → Blocked → Done → New)"),
+            Line::raw("  s         Cycle sort (Recent → Progress → Type)"),
             Line::raw("  /         Open search"),
-            Line::raw("  r         Force refresh"),
+            Line::raw("  F         Filter plan tasks in detail (Esc clears)"),
+            Line::raw("  Q         Query filter: status: tag: prio: type: overdue"),
+            Line::raw("  : / C-p   Open command palette"),
+            Line::raw("  m         Toggle only my tracks (--mine)"),
+            Line::raw("  P         Cycle plan filter (any → has plan → no plan)"),
+            Line::raw("  Y         Cycle type filter (all → feature → bug → … → other)"),
+            Line::raw("  T         Filter by several tags (AND / OR)"),
+            Line::raw("  p         Pin / unpin track to the top"),
+            Line::raw("  r         Force refresh (clears • edited markers)"),
             Line::raw("  t         Cycle theme"),
             Line::raw("  x         Toggle track complete"),
+            Line::raw("  y         Copy outstanding tasks as a checklist"),
+            Line::raw("  c         Toggle progress chart view"),
+            Line::raw("  e         Show error log (c inside clears it)"),
+            Line::raw("  g         Toggle tag × status matrix"),
+            Line::raw("  W         Outstanding tasks of listed tracks (s: order)"),
+            Line::raw("  B         Group listed tracks by branch prefix"),
+            Line::raw("  o         Collapse plan to phase headers"),
+            Line::raw("  b         Toggle flat / tree dependency view"),
+            Line::raw("  n         Next track with open tasks (by priority)"),
+            Line::raw("  R         View the raw plan.md as on disk"),
+            Line::raw("  v         Toggle compact / comfortable detail panel"),
+            Line::raw("  a         Toggle actual plan progress (raw ticks)"),
+            Line::raw("  z         Toggle UTC / local time for dates"),
+            Line::raw("  A         Toggle relative / absolute file paths"),
+            Line::raw("  < / >     Previous / next filter, sort and search (also Ctrl-O)"),
+            Line::raw("  D         Export dependency graph as Graphviz DOT"),
+            Line::raw("  O         Open track folder in file manager"),
             Line::raw("  d/u       Scroll detail down/up"),
             Line::raw("  [/]       Resize split (left/right)"),
+            Line::raw("  =         Set split percentage (20–80)"),
             Line::raw("  ?         Toggle this help"),
             Line::raw("  q         Quit"),
             Line::raw(""),
+            Line::styled("Glyphs", Style::default().add_modifier(Modifier::BOLD)),
+            legend_line(
+                "Tracks",
+                Status::ALL.map(|s| (s.glyph(), s.label(), status_color(s, &self.theme))),
+            ),
+            legend_line(
+                "Phases",
+                PhaseStatus::ALL
+                    .map(|p| (p.glyph(), p.label(), phase_status_color(p, &self.theme))),
+            ),
+            Line::raw(""),
             Line::styled(
                 "Press any key to close",
                 Style::default().fg(self.theme.text_secondary),
             ),
         ];
 
+        let popup_area = centered_rect(60, help_text.len() as u16 + 2, area);
+        frame.render_widget(Clear, popup_area);
+
         let help = Paragraph::new(help_text).block(
             Block::bordered()
                 .title(" Help ")
@@ -1263,59 +3925,1475 @@ impl App {
 // Standalone helper functions
 // ─────────────────────────────────────────────────────────
 
+/// Parse a typed split percentage, clamped to the 20–80 range `[`/`]` allow.
+fn parse_split_percent(input: &str) -> Option<u16> {
+    input
+        .trim()
+        .parse::<u16>()
+        .ok()
+        .map(|pct| pct.clamp(20, 80))
+}
+
 fn status_span(status: &Status, theme: &Theme) -> Text<'static> {
-    let (label, style) = match status {
-        Status::InProgress => (
-            "⚙ ACT",
-            Style::default()
-                .fg(theme.accent)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Status::Blocked => (
-            "⚠ BLK",
-            Style::default()
-                .fg(theme.warning)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Status::Complete => ("✓ DON", Style::default().fg(theme.success)),
-        Status::New => ("○ NEW", Style::default().fg(theme.text_secondary)),
+    let abbrev = match status {
+        Status::InProgress => "ACT",
+        Status::Review => "REV",
+        Status::Blocked => "BLK",
+        Status::Complete => "DON",
+        Status::New => "NEW",
     };
-    Text::from(Span::styled(label, style))
+    let mut style = Style::default().fg(status_color(*status, theme));
+    if matches!(
+        status,
+        Status::InProgress | Status::Review | Status::Blocked
+    ) {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    Text::from(Span::styled(format!("{} {abbrev}", status.glyph()), style))
+}
+
+/// Color of a track status glyph.
+fn status_color(status: Status, theme: &Theme) -> Color {
+    match status {
+        Status::InProgress => theme.accent,
+        Status::Review => theme.review,
+        Status::Blocked => theme.warning,
+        Status::Complete => theme.success,
+        Status::New => theme.text_secondary,
+    }
+}
+
+/// Color of a priority label: urgent ones stand out, the rest recede.
+fn priority_color(priority: Priority, theme: &Theme) -> Color {
+    match priority {
+        Priority::Critical => theme.error,
+        Priority::High => theme.warning,
+        Priority::Medium => theme.text_primary,
+        Priority::Low => theme.text_secondary,
+    }
+}
+
+/// Color of a plan phase glyph.
+fn phase_status_color(status: PhaseStatus, theme: &Theme) -> Color {
+    match status {
+        PhaseStatus::Complete => theme.success,
+        PhaseStatus::Active => theme.accent,
+        PhaseStatus::Pending => theme.text_secondary,
+        PhaseStatus::Blocked => theme.warning,
+    }
 }
 
-fn progress_bar_text(percent: f32, status: &Status, theme: &Theme) -> Text<'static> {
-    let width: usize = 8;
-    let filled = ((percent / 100.0) * width as f32).round() as usize;
-    let empty = width.saturating_sub(filled);
+/// One help-legend row: a heading followed by colored `glyph label` pairs.
+fn legend_line(
+    heading: &'static str,
+    entries: impl IntoIterator<Item = (&'static str, &'static str, Color)>,
+) -> Line<'static> {
+    let mut spans = vec![Span::raw(format!("  {heading:<10}"))];
+    for (glyph, label, color) in entries {
+        spans.push(Span::styled(glyph, Style::default().fg(color)));
+        spans.push(Span::raw(format!(" {label}  ")));
+    }
+    Line::from(spans)
+}
 
-    let color = match status {
-        Status::Complete => theme.progress_done,
-        Status::Blocked => theme.progress_blocked,
+/// Progress bar color, shared by every bar so the list, detail, and chart
+/// never disagree. `status` is `None` for bars without a track (plan phases).
+///
+/// - Complete → done, Review → review, and Blocked → blocked, whatever the
+///   percent.
+/// - Otherwise (New, Active, or no status) the percent decides: 100% → done,
+///   any progress → active, none → new. A New track with ticked tasks thus
+///   reads as under way, and an Active one with nothing ticked as not started.
+fn progress_color(status: Option<Status>, percent: f32, theme: &Theme) -> Color {
+    match status {
+        Some(Status::Complete) => theme.progress_done,
+        Some(Status::Review) => theme.review,
+        Some(Status::Blocked) => theme.progress_blocked,
+        _ if percent >= 100.0 => theme.progress_done,
         _ if percent > 0.0 => theme.progress_active,
         _ => theme.progress_new,
-    };
+    }
+}
 
-    let bar = format!(
-        "{}{} {:>3.0}%",
-        "█".repeat(filled),
-        "░".repeat(empty),
-        percent
-    );
-    Text::from(Span::styled(bar, Style::default().fg(color)))
+/// Track list column sizing for a list `width` cells wide: the progress bar
+/// width and whether the Tasks column is shown. Narrow lists drop Tasks and
+/// shrink the bar so titles stay readable; wide ones get a longer bar.
+fn list_columns(width: u16) -> (usize, bool) {
+    match width {
+        0..=49 => (4, false),
+        50..=69 => (6, true),
+        70..=119 => (8, true),
+        _ => (16, true),
+    }
 }
 
-fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::vertical([
-        Constraint::Fill(1),
-        Constraint::Length(height),
-        Constraint::Fill(1),
-    ])
-    .split(r);
+/// Left-aligned partial blocks for 1/8 … 7/8 of a cell.
+const EIGHTHS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
 
-    Layout::horizontal([
-        Constraint::Percentage((100 - percent_x) / 2),
-        Constraint::Percentage(percent_x),
-        Constraint::Percentage((100 - percent_x) / 2),
-    ])
-    .split(popup_layout[1])[1]
+/// Fill of a `width`-cell bar in eighths of a cell: full blocks plus at most
+/// one partial block, and the number of empty cells left over.
+fn bar_fill(percent: f32, width: usize) -> (String, usize) {
+    let eighths = ((percent.clamp(0.0, 100.0) / 100.0) * (width * 8) as f32).round() as usize;
+    let mut fill = "█".repeat(eighths / 8);
+    if let Some(&partial) = (eighths % 8).checked_sub(1).and_then(|i| EIGHTHS.get(i)) {
+        fill.push(partial);
+    }
+    let cells = eighths.div_ceil(8);
+    (fill, width.saturating_sub(cells))
+}
+
+fn progress_bar_text(percent: f32, status: &Status, width: usize, theme: &Theme) -> Text<'static> {
+    let (fill, empty) = bar_fill(percent, width);
+
+    let color = progress_color(Some(*status), percent, theme);
+
+    let bar = format!("{fill}{} {:>3.0}%", "░".repeat(empty), percent);
+    Text::from(Span::styled(bar, Style::default().fg(color)))
+}
+
+/// Human-readable age: "just now", "42s ago", "5m ago", "3h ago", "2d ago".
+fn format_age(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    match secs {
+        0 => "just now".to_string(),
+        1..=59 => format!("{secs}s ago"),
+        60..=3599 => format!("{}m ago", secs / 60),
+        3600..=86399 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86400),
+    }
+}
+
+/// Compact count for narrow columns: exact below 1000, then "1.2k", "45k",
+/// "1.3M". One decimal is kept while the scaled value is under 10.
+fn format_count(n: usize) -> String {
+    if n < 1000 {
+        return n.to_string();
+    }
+    // Integer arithmetic so halves always round up
+    for (scale, unit) in [(1_000, "k"), (1_000_000, "M")] {
+        let tenths = (n + scale / 20) / (scale / 10);
+        if tenths < 100 {
+            return format!("{}.{}{unit}", tenths / 10, tenths % 10);
+        }
+        let whole = (n + scale / 2) / scale;
+        if whole < 1000 || unit == "M" {
+            return format!("{whole}{unit}");
+        }
+    }
+    unreachable!("the M scale always returns")
+}
+
+/// Tiny "tasks remaining" indicator: a shade block that darkens with the
+/// absolute backlog size, followed by the count. Empty when nothing is left.
+fn remaining_sparkbar(remaining: usize, theme: &Theme) -> Line<'static> {
+    let (block, color) = match remaining {
+        0 => return Line::default(),
+        1..=5 => ("░", theme.text_secondary),
+        6..=20 => ("▒", theme.progress_active),
+        21..=50 => ("▓", theme.warning),
+        _ => ("█", theme.error),
+    };
+    Line::from(vec![
+        Span::styled(block, Style::default().fg(color)),
+        Span::styled(
+            format!(" {}", format_count(remaining)),
+            Style::default().fg(theme.text_secondary),
+        ),
+    ])
+}
+
+/// Full-width progress bar spans, coloured by completion.
+fn wide_bar_spans(pct: f32, width: usize, bar_color: Color, theme: &Theme) -> Vec<Span<'static>> {
+    let (fill, empty) = bar_fill(pct, width);
+    vec![
+        Span::styled(fill, Style::default().fg(bar_color)),
+        Span::styled("░".repeat(empty), Style::default().fg(theme.border)),
+    ]
+}
+
+/// Case-insensitive subsequence match. Returns a score (lower is better:
+/// fewer skipped characters, earlier start), or `None` if `query` doesn't match.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<usize> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut pos = 0;
+    let mut score = 0;
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = candidate[pos..].iter().position(|&c| c == q)?;
+        score += found;
+        pos += found + 1;
+    }
+    Some(score)
+}
+
+/// Status columns of the tag matrix, in display order.
+const MATRIX_STATUSES: [Status; 5] = [
+    Status::New,
+    Status::InProgress,
+    Status::Review,
+    Status::Blocked,
+    Status::Complete,
+];
+
+/// Heading of the branch-view group for tracks without a branch.
+const NO_BRANCH: &str = "(no branch)";
+
+/// Tracks grouped by [`Track::branch_prefix`], prefixes compared
+/// case-insensitively and sorted by name with the branchless group last.
+/// Each group keeps the order of `tracks`.
+fn branch_groups<'a>(
+    tracks: impl Iterator<Item = &'a Track>,
+) -> Vec<(Option<String>, Vec<&'a Track>)> {
+    let mut groups: Vec<(Option<String>, Vec<&Track>)> = Vec::new();
+    for track in tracks {
+        let prefix = track.branch_prefix();
+        let same = |key: &Option<String>| match (key, prefix) {
+            (Some(key), Some(prefix)) => key.eq_ignore_ascii_case(prefix),
+            (None, None) => true,
+            _ => false,
+        };
+        match groups.iter_mut().find(|(key, _)| same(key)) {
+            Some((_, members)) => members.push(track),
+            None => groups.push((prefix.map(str::to_string), vec![track])),
+        }
+    }
+    groups.sort_by_key(|(key, _)| (key.is_none(), key.as_deref().map(str::to_lowercase)));
+    groups
+}
+
+/// Count tracks per tag and status (columns ordered as `MATRIX_STATUSES`).
+/// Tracks without tags are bucketed under "(untagged)".
+fn tag_status_matrix<'a>(tracks: impl Iterator<Item = &'a Track>) -> BTreeMap<String, [usize; 5]> {
+    let mut matrix: BTreeMap<String, [usize; 5]> = BTreeMap::new();
+    for track in tracks {
+        let col = MATRIX_STATUSES
+            .iter()
+            .position(|s| *s == track.status)
+            .unwrap_or(0);
+        if track.tags.is_empty() {
+            matrix.entry("(untagged)".to_string()).or_default()[col] += 1;
+        }
+        for tag in &track.tags {
+            matrix.entry(tag.clone()).or_default()[col] += 1;
+        }
+    }
+    matrix
+}
+
+/// Truncate to `width` display columns, ending in `…` when cut. Wide
+/// characters (CJK, emoji) count as two columns.
+fn truncate(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > width.saturating_sub(1) {
+            break;
+        }
+        used += w;
+        out.push(c);
+    }
+    out.push('…');
+    out
+}
+
+/// Truncate to `width` display columns and pad with spaces.
+fn fit_width(text: &str, width: usize) -> String {
+    let out = truncate(text, width);
+    let used = out.width();
+    format!("{out}{}", " ".repeat(width.saturating_sub(used)))
+}
+
+/// Word-wrap `text` into lines of at most `width` display columns,
+/// splitting words longer than a whole line.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let current_width = current.width();
+        if current_width > 0 && current_width + 1 + word.width() <= width {
+            current.push(' ');
+            current.push_str(word);
+            continue;
+        }
+        if current_width > 0 {
+            lines.push(std::mem::take(&mut current));
+        }
+        let mut used = 0;
+        for c in word.chars() {
+            let w = c.width().unwrap_or(0);
+            if used + w > width && used > 0 {
+                lines.push(std::mem::take(&mut current));
+                used = 0;
+            }
+            used += w;
+            current.push(c);
+        }
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Copy text to the system clipboard via the OSC 52 escape sequence, which
+/// most modern terminals (and tmux with `set-clipboard on`) honour.
+fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    use base64::Engine;
+    use std::io::Write;
+
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{encoded}\x07")?;
+    stdout.flush()
+}
+
+/// How long to wait for the file manager launcher to report a failure.
+const LAUNCHER_GRACE: Duration = Duration::from_millis(500);
+
+/// Launch the platform file manager on `dir` (see [`spawn_launcher`]).
+fn spawn_file_manager(dir: &Path) -> std::io::Result<()> {
+    let launcher = if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(windows) {
+        "explorer"
+    } else {
+        "xdg-open"
+    };
+    spawn_launcher(launcher, &[dir.as_os_str()])
+}
+
+/// Run a launcher such as `open` / `xdg-open`, which hands off and exits:
+/// a non-zero exit within [`LAUNCHER_GRACE`] is an error. One still running
+/// after that is reaped on a background thread so the TUI keeps running.
+fn spawn_launcher(launcher: &str, args: &[&std::ffi::OsStr]) -> std::io::Result<()> {
+    use std::process::Stdio;
+
+    let mut child = std::process::Command::new(launcher)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    let deadline = Instant::now() + LAUNCHER_GRACE;
+    while Instant::now() < deadline {
+        match child.try_wait()? {
+            // explorer exits with 1 even when the window opened
+            Some(status) if status.success() || cfg!(windows) => return Ok(()),
+            Some(status) => {
+                return Err(std::io::Error::other(format!("{launcher} {status}")));
+            }
+            None => std::thread::sleep(Duration::from_millis(20)),
+        }
+    }
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length(height),
+        Constraint::Fill(1),
+    ])
+    .split(r);
+
+    Layout::horizontal([
+        Constraint::Percentage((100 - percent_x) / 2),
+        Constraint::Percentage(percent_x),
+        Constraint::Percentage((100 - percent_x) / 2),
+    ])
+    .split(popup_layout[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::TrackType;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    /// An app over a synthetic track set, with nothing read from or written to disk.
+    fn render_app(tracks: Vec<Track>) -> App {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/render");
+        let mut app = App::new(
+            dir.clone(),
+            IndexSource::File(dir.join("tracks.md")),
+            true,
+            FilterMode::All,
+        )
+        .unwrap();
+        app.history_path = None;
+        app.pins_path = None;
+        app.tracks = tracks.into_iter().map(|t| (t.id.clone(), t)).collect();
+        app.edge_counts = crate::model::edge_counts(&app.tracks);
+        app.recompute_filtered_tracks();
+        app.select_first();
+        app
+    }
+
+    /// Render into a `width` × `height` buffer and return it as text rows.
+    fn render_rows(app: &mut App, width: u16, height: u16) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .map(|y| (0..width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    fn half_done_track() -> Track {
+        let tasks = ["Wire exporter", "Add config"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, text)| PlanTask {
+                text: text.to_string(),
+                done: i == 0,
+                due: None,
+                depth: 0,
+            })
+            .collect();
+        let mut track = Track {
+            id: TrackId::new("otel_20260301"),
+            title: "OTel Tracing".to_string(),
+            status: Status::InProgress,
+            ..Track::default()
+        };
+        track.merge_plan(vec![PlanPhase {
+            name: "Phase 1: Export".to_string(),
+            status: PhaseStatus::Active,
+            tasks,
+            depth: 0,
+            completed_at: None,
+        }]);
+        track
+    }
+
+    #[test]
+    fn test_stats_bar_counts_open_tasks_by_priority() {
+        let mut urgent = half_done_track();
+        urgent.id = TrackId::new("urgent_20260303");
+        urgent.priority = Priority::Critical;
+        let mut app = render_app(vec![half_done_track(), urgent]);
+        let rows = render_rows(&mut app, 140, 30);
+
+        let stats = &rows[1];
+        assert!(
+            stats.contains("Open tasks: 1 critical · 0 high · 1 medium · 0 low"),
+            "{stats}"
+        );
+    }
+
+    #[test]
+    fn test_custom_highlight_symbol_and_reverse_selection() {
+        let mut app = render_app(vec![half_done_track()]);
+        app.highlight_symbol = "> ".to_string();
+        app.reverse_selection = true;
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+
+        let (x, y) = (0..30)
+            .find_map(|y| {
+                let row: String = (0..120).map(|x| buffer[(x, y)].symbol()).collect();
+                row.find("> OTel Tracing")
+                    .map(|at| (row[..at].chars().count() as u16, y))
+            })
+            .expect("selected row with the custom marker");
+        let cell = &buffer[(x, y)];
+        assert!(cell.modifier.contains(Modifier::REVERSED));
+        assert_eq!(cell.bg, Color::Reset);
+    }
+
+    #[test]
+    fn test_active_track_phase_highlighted_in_subtitle() {
+        let mut queued = half_done_track();
+        queued.id = TrackId::new("queued_20260303");
+        queued.title = "Queued Work".to_string();
+        queued.status = Status::New;
+        let mut second = half_done_track();
+        second.id = TrackId::new("second_20260304");
+        let mut app = render_app(vec![half_done_track(), queued, second]);
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..30)
+            .map(|y| (0..120).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+
+        let marked: Vec<(usize, usize)> = rows
+            .iter()
+            .enumerate()
+            .filter_map(|(y, r)| r.find("▸ Phase 1: Export").map(|at| (y, at)))
+            .collect();
+        // Both active tracks, but not the new one with the same phase
+        assert_eq!(marked.len(), 2, "{rows:#?}");
+        assert!(rows
+            .iter()
+            .any(|r| r.contains(" Phase 1: Export · ") && !r.contains("▸ Phase 1: Export")));
+
+        // The unselected active row shows the phase in the accent color
+        assert!(marked.iter().any(|&(y, at)| {
+            let x = rows[y][..at].chars().count() as u16 + 2;
+            buffer[(x, y as u16)].fg == app.theme.accent
+        }));
+    }
+
+    #[test]
+    fn test_render_list_row_and_stats() {
+        let blocked = Track {
+            id: TrackId::new("vendor_20260302"),
+            title: "Vendor Sync".to_string(),
+            status: Status::Blocked,
+            ..Track::default()
+        };
+        let mut app = render_app(vec![half_done_track(), blocked]);
+        let rows = render_rows(&mut app, 120, 30);
+
+        let stats = &rows[1];
+        assert!(stats.contains("2 Total"), "{stats}");
+        assert!(
+            stats.contains("1 Active") && stats.contains("1 Blocked"),
+            "{stats}"
+        );
+
+        // Title, status glyph, and percent share the selected track's row
+        let row = rows
+            .iter()
+            .find(|r| r.contains("▸ OTel Tracing"))
+            .expect("selected track row");
+        let title_at = row.find("OTel Tracing").unwrap();
+        let status_at = row.find("⚙ ACT").expect("status glyph");
+        let percent_at = row.find("50%").expect("progress percent");
+        assert!(title_at < status_at && status_at < percent_at, "{row}");
+        assert!(rows
+            .iter()
+            .any(|r| r.contains("Vendor Sync") && r.contains("⚠ BLK")));
+    }
+
+    #[test]
+    fn test_render_detail_panel_for_selection() {
+        let mut app = render_app(vec![half_done_track()]);
+        let rows = render_rows(&mut app, 120, 30);
+        // Only look right of where the detail pane was laid out
+        assert!(app.detail_area.x > 0);
+        let detail: Vec<String> = rows
+            .iter()
+            .map(|r| r.chars().skip(app.detail_area.x as usize).collect())
+            .collect();
+        assert!(detail.iter().any(|r| r.contains("otel_20260301")));
+        assert!(detail.iter().any(|r| r.contains("Phase 1: Export")));
+        assert!(detail.iter().any(|r| r.contains("○ Add config")));
+    }
+
+    #[test]
+    fn test_help_lists_status_and_phase_glyphs() {
+        let mut app = render_app(vec![half_done_track()]);
+        app.mode = InputMode::Help;
+        let rows = render_rows(&mut app, 120, 70);
+        let row = |heading: &str| {
+            rows.iter()
+                .find(|r| r.contains(heading))
+                .unwrap_or_else(|| panic!("no {heading} legend"))
+                .clone()
+        };
+        let tracks = row("Tracks    ");
+        for status in Status::ALL {
+            assert!(tracks.contains(&format!("{} {}", status.glyph(), status.label())));
+        }
+        assert!(tracks.contains("⚙ Active"));
+        assert!(row("Phases    ").contains("⊘ Blocked"));
+    }
+
+    #[test]
+    fn test_detail_folder_path_toggles_relative_and_absolute() {
+        let mut app = render_app(vec![half_done_track()]);
+        let folder_line = |app: &mut App| {
+            render_rows(app, 200, 30)
+                .into_iter()
+                .find_map(|r| {
+                    r.split_once("Folder: ")
+                        .map(|(_, path)| path.split_whitespace().next().unwrap_or("").to_string())
+                })
+                .expect("folder line")
+        };
+        assert_eq!(folder_line(&mut app), "render/tracks/otel_20260301");
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('A'), KeyModifiers::NONE));
+        let absolute = folder_line(&mut app);
+        assert!(Path::new(&absolute).is_absolute());
+        assert!(absolute.ends_with("render/tracks/otel_20260301"));
+    }
+
+    #[test]
+    fn test_git_dates_are_looked_up_off_the_ui_thread() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("tracks/otel_20260301")).unwrap();
+        let mut app = render_app(vec![half_done_track()]);
+        app.conductor_dir = dir.path().to_path_buf();
+        app.git_dates = Some(GitDates::default());
+        let updated_at = |app: &App| app.tracks[&TrackId::new("otel_20260301")].updated_at;
+
+        // Nothing cached yet: the track waits for the background lookup
+        app.fill_git_dates();
+        assert_eq!(updated_at(&app), None);
+        let started = Instant::now();
+        while !app.poll_git_dates() {
+            assert!(
+                started.elapsed() < Duration::from_secs(10),
+                "lookup never finished"
+            );
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        // Not a git repo, so the folder's mtime stands in
+        assert!(updated_at(&app).is_some());
+        assert!(app.git_dates_rx.is_none());
+    }
+
+    #[test]
+    fn test_watchdog_flags_missing_ticks_until_one_arrives() {
+        let mut app = render_app(vec![half_done_track()]);
+        app.check_event_watchdog();
+        assert!(!app.event_loop_stalled);
+
+        app.last_event_at = Instant::now() - EVENT_STALL_AFTER;
+        app.check_event_watchdog();
+        assert!(app.event_loop_stalled);
+        assert!(render_rows(&mut app, 120, 30)[0].contains("⚠ STALLED"));
+
+        app.handle_event(Event::Tick);
+        assert!(!app.event_loop_stalled);
+        assert!(!render_rows(&mut app, 120, 30)[0].contains("STALLED"));
+
+        // A tick that arrives late is recorded before the watchdog looks
+        app.last_event_at = Instant::now() - EVENT_STALL_AFTER;
+        app.handle_event(Event::Tick);
+        app.check_event_watchdog();
+        assert!(!app.event_loop_stalled);
+    }
+
+    #[test]
+    fn test_refresh_age_without_seconds_waits_for_the_minute() {
+        let mut app = render_app(vec![half_done_track()]);
+        app.clock_seconds = false;
+        app.clock = app.format_clock();
+        app.last_refresh = Some(Instant::now() - Duration::from_secs(30));
+        app.refresh_age = app.format_refresh_age();
+        assert_eq!(app.refresh_age, "updated just now");
+
+        // Two minutes on, but the clock still shows the same minute
+        app.last_refresh = Some(Instant::now() - Duration::from_secs(120));
+        app.dirty = false;
+        let clock = app.clock.clone();
+        app.handle_event(Event::Tick);
+        if app.clock == clock {
+            assert_eq!(app.refresh_age, "updated just now");
+            assert!(!app.dirty);
+        }
+
+        // The minute turns over
+        app.clock = String::new();
+        app.handle_event(Event::Tick);
+        assert_eq!(app.refresh_age, "updated 2m ago");
+        assert!(app.dirty);
+    }
+
+    #[test]
+    fn test_empty_plan_file_list_falls_back_to_plan_md() {
+        let mut app = render_app(vec![half_done_track()]);
+        app.set_plan_files(Vec::new());
+        assert_eq!(app.plan_files, [crate::parser::DEFAULT_PLAN_FILE]);
+        // Opening the raw plan of a track without one no longer indexes an empty list
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('R'), KeyModifiers::NONE));
+        assert_eq!(app.mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_raw_plan_viewer_reads_plan_verbatim() {
+        // A fresh dir each run, so no plan.md is left over from an earlier one
+        let dir = tempfile::tempdir().unwrap();
+        let track_dir = dir.path().join("tracks").join("otel_20260301");
+        std::fs::create_dir_all(&track_dir).unwrap();
+        let mut track = half_done_track();
+        track.source_dir = Some(dir.path().to_path_buf());
+        let mut app = render_app(vec![track]);
+        let press = |app: &mut App, code| {
+            app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+        };
+
+        press(&mut app, KeyCode::Char('R'));
+        assert_eq!(app.mode, InputMode::Normal);
+        assert_eq!(
+            app.toast.as_ref().map(|(msg, _)| msg.as_str()),
+            Some("No plan.md for otel_20260301")
+        );
+
+        std::fs::write(
+            track_dir.join("plan.md"),
+            "# Plan\n\n- [x]  Wire exporter\n\t* [ ] Add config\n",
+        )
+        .unwrap();
+        press(&mut app, KeyCode::Char('R'));
+        assert_eq!(app.mode, InputMode::RawPlan);
+        assert_eq!(app.raw_plan_lines.len(), 4);
+        let rows = render_rows(&mut app, 100, 30).join("\n");
+        assert!(rows.contains("otel_20260301/plan.md"));
+        assert!(rows.contains("3 │ - [x]  Wire exporter"));
+        assert!(rows.contains("4 │     * [ ] Add config"));
+
+        press(&mut app, KeyCode::Char('G'));
+        assert_eq!(app.raw_plan_scroll, 3);
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.raw_plan_scroll, 3);
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_task_filter_hides_unmatched_tasks_and_phases() {
+        let mut track = half_done_track();
+        track.merge_plan(vec![
+            PlanPhase {
+                name: "Phase 1: Export".to_string(),
+                status: PhaseStatus::Active,
+                tasks: ["Wire exporter", "Test exporter"]
+                    .into_iter()
+                    .map(|text| PlanTask {
+                        text: text.to_string(),
+                        done: false,
+                        due: None,
+                        depth: 0,
+                    })
+                    .collect(),
+                depth: 0,
+                completed_at: None,
+            },
+            PlanPhase {
+                name: "Phase 2: Rollout".to_string(),
+                status: PhaseStatus::Pending,
+                tasks: vec![PlanTask {
+                    text: "Enable in prod".to_string(),
+                    done: false,
+                    due: None,
+                    depth: 0,
+                }],
+                depth: 0,
+                completed_at: None,
+            },
+        ]);
+        let mut app = render_app(vec![track]);
+        let press = |app: &mut App, code| {
+            app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+        };
+
+        press(&mut app, KeyCode::Char('F'));
+        for c in "TEST".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.mode, InputMode::Normal);
+        assert_eq!(app.task_filter, "TEST");
+
+        let detail = |app: &mut App| {
+            let rows = render_rows(app, 120, 40);
+            let x = app.detail_area.x as usize;
+            rows.iter()
+                .map(|row| row.chars().skip(x).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let rows = detail(&mut app);
+        assert!(rows.contains("Test exporter"));
+        assert!(!rows.contains("Wire exporter"));
+        assert!(!rows.contains("Phase 2: Rollout"));
+
+        // Esc in normal mode drops the filter before anything else
+        press(&mut app, KeyCode::Esc);
+        assert!(app.task_filter.is_empty());
+        let rows = detail(&mut app);
+        assert!(rows.contains("Wire exporter"));
+        assert!(rows.contains("Phase 2: Rollout"));
+    }
+
+    #[test]
+    fn test_query_filters_list_live() {
+        let track = |id: &str, status| Track {
+            id: TrackId::new(id),
+            status,
+            ..Track::default()
+        };
+        let mut app = render_app(vec![
+            track("a", Status::Blocked),
+            track("b", Status::Review),
+            track("c", Status::New),
+        ]);
+        let press = |app: &mut App, code| {
+            app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+        };
+
+        press(&mut app, KeyCode::Char('Q'));
+        for c in "status:blocked,review foo:bar".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        assert_eq!(app.filtered_track_ids.len(), 2);
+        assert_eq!(app.query_hint(), "  ignored: foo:bar");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.mode, InputMode::Normal);
+        assert_eq!(app.filtered_track_ids.len(), 2);
+
+        press(&mut app, KeyCode::Char('Q'));
+        press(&mut app, KeyCode::Esc);
+        assert!(app.query.is_empty());
+        assert_eq!(app.filtered_track_ids.len(), 3);
+    }
+
+    #[test]
+    fn test_type_filter_composes_with_status_filter_and_search() {
+        let track = |id: &str, status, track_type| Track {
+            id: TrackId::new(id),
+            title: id.to_string(),
+            status,
+            track_type,
+            ..Track::default()
+        };
+        let mut app = render_app(vec![
+            track("bug_login", Status::InProgress, TrackType::Bug),
+            track("bug_export", Status::Blocked, TrackType::Bug),
+            track("feat_login", Status::InProgress, TrackType::Feature),
+        ]);
+        let press = |app: &mut App, code| {
+            app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+        };
+
+        press(&mut app, KeyCode::Char('Y'));
+        assert_eq!(app.type_filter, TypeFilter::Feature);
+        press(&mut app, KeyCode::Char('Y'));
+        assert_eq!(app.type_filter, TypeFilter::Bug);
+        assert_eq!(app.filtered_track_ids.len(), 2);
+
+        app.filter = FilterMode::Active;
+        app.recompute_filtered_tracks();
+        assert_eq!(app.filtered_track_ids, vec![TrackId::new("bug_login")]);
+
+        app.filter = FilterMode::All;
+        app.search_query = "export".to_string();
+        app.recompute_filtered_tracks();
+        assert_eq!(app.filtered_track_ids, vec![TrackId::new("bug_export")]);
+
+        let rows = render_rows(&mut app, 120, 30);
+        assert!(rows.iter().any(|r| r.contains("Type: Bug")));
+    }
+
+    #[test]
+    fn test_blocked_filter_can_include_dependency_blocked_tracks() {
+        let track = |id: &str, status, deps: &[&str]| Track {
+            id: TrackId::new(id),
+            status,
+            dependencies: deps.iter().map(|d| TrackId::new(*d)).collect(),
+            ..Track::default()
+        };
+        let mut app = render_app(vec![
+            track("api", Status::InProgress, &[]),
+            track("ui", Status::New, &["api"]),
+            track("legal", Status::Blocked, &[]),
+        ]);
+        app.filter = FilterMode::Blocked;
+        app.recompute_filtered_tracks();
+        assert_eq!(app.filtered_track_ids, vec![TrackId::new("legal")]);
+
+        app.blocked_includes_deps = true;
+        app.recompute_filtered_tracks();
+        assert_eq!(
+            app.filtered_track_ids,
+            vec![TrackId::new("legal"), TrackId::new("ui")]
+        );
+    }
+
+    #[test]
+    fn test_detail_warns_only_while_a_dependency_is_unmet() {
+        let track = |id: &str, status, deps: &[&str]| Track {
+            id: TrackId::new(id),
+            status,
+            dependencies: deps.iter().map(|d| TrackId::new(*d)).collect(),
+            ..Track::default()
+        };
+        let mut app = render_app(vec![
+            track("done", Status::Complete, &[]),
+            track("ready", Status::New, &["done"]),
+            track("wip", Status::InProgress, &[]),
+            track("api", Status::New, &["wip"]),
+            track("ui", Status::New, &["done", "api"]),
+        ]);
+        let mut detail_of = |id: &str| {
+            app.selected_track = Some(TrackId::new(id));
+            render_rows(&mut app, 120, 30)
+        };
+
+        let rows = detail_of("ready");
+        assert!(rows.iter().any(|r| r.contains("Depends on: done")));
+        assert!(!rows.iter().any(|r| r.contains("Blocked by")));
+
+        let rows = detail_of("ui");
+        assert!(
+            rows.iter()
+                .any(|r| r.contains("⚠ Blocked by: done, api (upstream: wip)")),
+            "{rows:#?}"
+        );
+    }
+
+    #[test]
+    fn test_dependency_cycle_reported_once() {
+        let track = |id: &str, deps: &[&str]| Track {
+            id: TrackId::new(id),
+            dependencies: deps.iter().map(|d| TrackId::new(*d)).collect(),
+            ..Track::default()
+        };
+        let mut app = render_app(vec![track("api", &["ui"]), track("ui", &["api"])]);
+        app.check_dependency_cycles();
+        let (message, _) = app.error_message.take().expect("cycle warning");
+        assert_eq!(message, "Dependency cycle: api → ui → api");
+
+        // The same cycle on the next reload isn't reported again
+        app.check_dependency_cycles();
+        assert!(app.error_message.is_none());
+    }
+
+    #[test]
+    fn test_date_only_values_keep_their_calendar_day() {
+        use chrono::TimeZone;
+
+        let app = conductor_app(false);
+        let due = chrono::Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+        assert_eq!(app.format_date(due, "%Y-%m-%d"), "2026-03-01");
+        let stamp = chrono::Utc.with_ymd_and_hms(2026, 3, 1, 12, 30, 0).unwrap();
+        assert_eq!(
+            app.format_date(stamp, "%Y-%m-%d %H:%M"),
+            stamp
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        );
+    }
+
+    /// The repo's own conductor data, plans read up front or (`lazy`) on demand.
+    fn conductor_app(lazy: bool) -> App {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("conductor");
+        let mut app = App::new(
+            dir.clone(),
+            IndexSource::File(dir.join("tracks.md")),
+            true,
+            FilterMode::All,
+        )
+        .unwrap();
+        app.history_path = None;
+        app.pins_path = None;
+        app.pins = Pins::default();
+        app.lazy_plans = lazy;
+        app.load_tracks().unwrap();
+        app
+    }
+
+    #[test]
+    fn test_mcp_list_tracks_order_matches_dashboard() {
+        use conductor_dashboard::mcp::types::{ListTracksParams, TrackSummaryResponse};
+        use rmcp::handler::server::wrapper::Parameters;
+
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("conductor");
+        let svc = conductor_dashboard::mcp::ConductorService::new(&dir).unwrap();
+        let mut app = conductor_app(false);
+
+        for (param, mode) in [
+            ("updated", SortMode::Updated),
+            ("progress", SortMode::Progress),
+            ("type", SortMode::Type),
+        ] {
+            let result = svc.list_tracks(Parameters(ListTracksParams {
+                status: None,
+                sort: Some(param.into()),
+            }));
+            let listed: Vec<TrackSummaryResponse> = serde_json::from_str(&result).unwrap();
+            let listed: Vec<&str> = listed.iter().map(|t| t.id.as_str()).collect();
+
+            app.sort = mode;
+            app.recompute_filtered_tracks();
+            let shown: Vec<&str> = app
+                .filtered_track_ids
+                .iter()
+                .map(|id| id.as_str())
+                .collect();
+
+            assert_eq!(listed, shown, "ordering differs for sort={param}");
+        }
+    }
+
+    fn pending_plans(app: &App) -> usize {
+        app.tracks.values().filter(|t| t.plan_pending).count()
+    }
+
+    #[test]
+    fn test_lazy_plans_count_as_plans_for_plan_filter() {
+        let (mut eager, mut lazy) = (conductor_app(false), conductor_app(true));
+        assert!(pending_plans(&lazy) > 0);
+        for app in [&mut eager, &mut lazy] {
+            app.plan_filter = PlanFilter::WithPlan;
+            app.recompute_filtered_tracks();
+        }
+        assert_eq!(lazy.filtered_track_ids, eager.filtered_track_ids);
+    }
+
+    #[test]
+    fn test_lazy_plans_loaded_for_next_open_task() {
+        let (mut eager, mut lazy) = (conductor_app(false), conductor_app(true));
+        for app in [&mut eager, &mut lazy] {
+            app.handle_key_event(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE));
+        }
+        assert!(eager.selected_track.is_some());
+        assert_eq!(lazy.selected_track, eager.selected_track);
+    }
+
+    #[test]
+    fn test_lazy_plans_loaded_for_tasks_view_and_chart() {
+        let eager = conductor_app(false);
+        for key in ['W', 'c', 'B'] {
+            let mut lazy = conductor_app(true);
+            lazy.handle_key_event(KeyEvent::new(KeyCode::Char(key), KeyModifiers::NONE));
+            assert_eq!(pending_plans(&lazy), 0, "{key}");
+            assert_eq!(
+                lazy.ordered_outstanding_tasks().len(),
+                eager.ordered_outstanding_tasks().len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_lazy_plans_loaded_for_overdue_query() {
+        let (mut eager, mut lazy) = (conductor_app(false), conductor_app(true));
+        for app in [&mut eager, &mut lazy] {
+            app.query = "overdue".to_string();
+            app.track_query = TrackQuery::parse("overdue");
+            app.recompute_filtered_tracks();
+        }
+        assert_eq!(pending_plans(&lazy), 0);
+        assert_eq!(lazy.filtered_track_ids, eager.filtered_track_ids);
+    }
+
+    #[test]
+    fn test_lazy_plans_loaded_for_progress_sort() {
+        let (mut eager, mut lazy) = (conductor_app(false), conductor_app(true));
+        for app in [&mut eager, &mut lazy] {
+            app.sort = SortMode::Progress;
+            app.recompute_filtered_tracks();
+        }
+        assert_eq!(pending_plans(&lazy), 0);
+        assert_eq!(lazy.filtered_track_ids, eager.filtered_track_ids);
+    }
+
+    #[test]
+    fn test_tasks_view_cycles_order_with_s() {
+        let track = |id: &str, title: &str, priority| {
+            let mut track = Track {
+                id: TrackId::new(id),
+                title: title.to_string(),
+                priority,
+                ..Track::default()
+            };
+            let phase = |n: usize| PlanPhase {
+                name: format!("Phase {n}"),
+                status: PhaseStatus::Pending,
+                tasks: vec![PlanTask {
+                    text: format!("{id} task {n}"),
+                    done: false,
+                    due: None,
+                    depth: 0,
+                }],
+                depth: 0,
+                completed_at: None,
+            };
+            track.merge_plan(vec![phase(1), phase(2)]);
+            track
+        };
+        let mut app = render_app(vec![
+            track("alpha", "Alpha", Priority::Low),
+            track("zeta", "Zeta", Priority::High),
+        ]);
+        let order = |app: &App| -> Vec<String> {
+            app.ordered_outstanding_tasks()
+                .iter()
+                .map(|(_, _, _, task)| task.text.clone())
+                .collect()
+        };
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('W'), KeyModifiers::NONE));
+        assert_eq!(app.main_view, MainView::Tasks);
+        let rows = render_rows(&mut app, 120, 30).join("\n");
+        assert!(rows.contains("Outstanding tasks (4)"), "{rows}");
+        assert!(rows.contains("Order: Priority"), "{rows}");
+        assert_eq!(
+            order(&app),
+            ["zeta task 1", "zeta task 2", "alpha task 1", "alpha task 2"]
+        );
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE));
+        assert_eq!(app.task_order, TaskOrder::Track);
+        assert_eq!(
+            order(&app),
+            ["alpha task 1", "alpha task 2", "zeta task 1", "zeta task 2"]
+        );
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE));
+        assert_eq!(app.task_order, TaskOrder::Phase);
+        let phased = order(&app);
+        assert!(
+            phased[..2].iter().all(|t| t.ends_with("task 1")),
+            "{phased:?}"
+        );
+        assert!(
+            phased[2..].iter().all(|t| t.ends_with("task 2")),
+            "{phased:?}"
+        );
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(app.main_view, MainView::Tracks);
+    }
+
+    #[test]
+    fn test_branch_view_groups_by_prefix_with_counts() {
+        let track = |id: &str, branch: Option<&str>| Track {
+            id: TrackId::new(id),
+            title: id.to_string(),
+            branch: branch.map(str::to_string),
+            ..Track::default()
+        };
+        let mut app = render_app(vec![
+            track("login", Some("feat/login")),
+            track("search", Some("Feat/search")),
+            track("ticket", Some("DSS-1234")),
+            track("loose", None),
+        ]);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('B'), KeyModifiers::NONE));
+        assert_eq!(app.main_view, MainView::Branches);
+        let rows = render_rows(&mut app, 120, 30);
+        let position = |text: &str| {
+            rows.iter()
+                .position(|r| r.contains(text))
+                .unwrap_or_else(|| panic!("{text} missing: {rows:#?}"))
+        };
+        assert!(position("DSS (1)") < position("feat (2)"));
+        assert!(position("feat (2)") < position("(no branch) (1)"));
+        assert!(position("feat (2)") < position("Feat/search"));
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(app.main_view, MainView::Tracks);
+    }
+
+    #[test]
+    fn test_view_history_steps_back_and_forward() {
+        let mut app = render_app(vec![half_done_track()]);
+        let press = |app: &mut App, code| {
+            app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+        };
+        let initial = app.view_state();
+
+        press(&mut app, KeyCode::Char('f'));
+        let filtered = app.view_state();
+        press(&mut app, KeyCode::Char('/'));
+        for c in "otel".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        // The typed search is one step, not one per keystroke
+        assert_eq!(app.view_history.len(), 3);
+
+        press(&mut app, KeyCode::Char('<'));
+        assert_eq!(app.view_state(), filtered);
+        assert!(app.search_query.is_empty());
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL));
+        assert_eq!(app.view_state(), initial);
+        press(&mut app, KeyCode::Char('<'));
+        assert_eq!(app.view_state(), initial);
+
+        press(&mut app, KeyCode::Char('>'));
+        assert_eq!(app.view_state(), filtered);
+        // A new change drops the forward entries
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.view_history.len(), 3);
+        press(&mut app, KeyCode::Char('>'));
+        assert_eq!(app.sort, SortMode::Progress);
+
+        for _ in 0..VIEW_HISTORY_LEN * 2 {
+            press(&mut app, KeyCode::Char('s'));
+        }
+        assert_eq!(app.view_history.len(), VIEW_HISTORY_LEN);
+        assert_eq!(app.view_history_pos, VIEW_HISTORY_LEN - 1);
+    }
+
+    #[test]
+    fn test_detail_indents_subtasks_by_depth() {
+        let mut track = half_done_track();
+        let mut phases = track.plan_phases.clone();
+        phases[0].tasks.push(PlanTask {
+            text: "Sample spans".to_string(),
+            done: false,
+            due: None,
+            depth: 1,
+        });
+        track.merge_plan(phases);
+        let mut app = render_app(vec![track]);
+        let rows = render_rows(&mut app, 120, 40);
+
+        let column = |text: &str| {
+            rows.iter()
+                .find_map(|r| r.find(text))
+                .unwrap_or_else(|| panic!("{text} missing: {rows:#?}"))
+        };
+        assert_eq!(column("Sample spans"), column("Add config") + 2);
+    }
+
+    #[test]
+    fn test_next_open_task_walks_actionable_tracks_by_priority() {
+        let track = |id: &str, priority, status| {
+            let mut track = half_done_track();
+            track.id = TrackId::new(id);
+            track.priority = priority;
+            track.status = status;
+            track
+        };
+        let mut waiting = track("waiting", Priority::Critical, Status::InProgress);
+        waiting.dependencies = vec![TrackId::new("low")];
+        let mut app = render_app(vec![
+            track("low", Priority::Low, Status::InProgress),
+            track("high", Priority::High, Status::New),
+            track("blocked", Priority::Critical, Status::Blocked),
+            waiting,
+        ]);
+        let press_n = |app: &mut App| {
+            app.handle_key_event(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE));
+            render_rows(app, 120, 40);
+            app.selected_track.as_ref().unwrap().as_str().to_string()
+        };
+
+        assert_eq!(press_n(&mut app), "high");
+        // The first open task (last but one line) sits two lines from the top
+        assert_eq!(
+            app.detail_scroll as usize,
+            app.detail_total_lines as usize - 4
+        );
+        assert_eq!(press_n(&mut app), "low");
+        assert!(app.toast.is_none());
+        assert_eq!(press_n(&mut app), "high");
+        assert_eq!(
+            app.toast.as_ref().map(|(msg, _)| msg.as_str()),
+            Some("No more outstanding tasks; back to the first")
+        );
+    }
+
+    #[test]
+    fn test_tag_picker_applies_on_enter() {
+        let tagged = |id: &str, tags: &[&str]| Track {
+            id: TrackId::new(id),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..Track::default()
+        };
+        let mut app = render_app(vec![
+            tagged("a", &["backend", "security"]),
+            tagged("b", &["backend"]),
+            tagged("c", &["ui"]),
+        ]);
+        let press = |app: &mut App, code| {
+            app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+        };
+
+        // Tags sort as backend, security, ui: pick the first two
+        press(&mut app, KeyCode::Char('T'));
+        press(&mut app, KeyCode::Char(' '));
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Char(' '));
+        assert_eq!(
+            app.filtered_track_ids.len(),
+            3,
+            "nothing applied before Enter"
+        );
+        press(&mut app, KeyCode::Enter);
+        let ids = |app: &App| -> Vec<String> {
+            app.filtered_track_ids
+                .iter()
+                .map(|id| id.as_str().to_string())
+                .collect()
+        };
+        assert_eq!(ids(&app), vec!["a", "b"]);
+
+        press(&mut app, KeyCode::Char('T'));
+        press(&mut app, KeyCode::Tab);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.tag_match, TagMatch::All);
+        assert_eq!(ids(&app), vec!["a"]);
+    }
+
+    #[test]
+    fn test_tag_picker_lists_tags_once_ignoring_case() {
+        let tagged = |id: &str, tags: &[&str]| Track {
+            id: TrackId::new(id),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..Track::default()
+        };
+        let mut app = render_app(vec![
+            tagged("a", &["Backend"]),
+            tagged("b", &["backend", "ui"]),
+            tagged("c", &["UI"]),
+        ]);
+        assert_eq!(app.all_tags(), vec!["Backend", "ui"]);
+
+        // Picking the one entry matches every spelling
+        for code in [KeyCode::Char('T'), KeyCode::Char(' '), KeyCode::Enter] {
+            app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+        }
+        assert_eq!(
+            app.filtered_track_ids,
+            vec![TrackId::new("a"), TrackId::new("b")]
+        );
+    }
+
+    #[test]
+    fn test_render_too_small_terminal() {
+        let mut app = render_app(vec![half_done_track()]);
+        let rows = render_rows(&mut app, 30, 8);
+        assert!(rows.iter().any(|r| r.contains("Terminal too small")));
+        assert!(!rows.iter().any(|r| r.contains("OTel Tracing")));
+    }
+
+    #[test]
+    fn test_format_count_thresholds() {
+        assert_eq!(format_count(0), "0");
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1_000), "1.0k");
+        assert_eq!(format_count(1_249), "1.2k");
+        assert_eq!(format_count(1_250), "1.3k");
+        assert_eq!(format_count(9_949), "9.9k");
+        assert_eq!(format_count(9_950), "10k");
+        assert_eq!(format_count(45_400), "45k");
+        assert_eq!(format_count(999_499), "999k");
+        assert_eq!(format_count(999_500), "1.0M");
+        assert_eq!(format_count(12_600_000), "13M");
+    }
+
+    #[test]
+    fn test_list_columns_adapt_to_width() {
+        assert_eq!(list_columns(40), (4, false));
+        assert_eq!(list_columns(60), (6, true));
+        assert_eq!(list_columns(80), (8, true));
+        assert_eq!(list_columns(160), (16, true));
+    }
+
+    #[test]
+    fn test_bar_fill_uses_eighth_blocks() {
+        let bar = |pct| {
+            let (fill, empty) = bar_fill(pct, 8);
+            format!("{fill}{}", "░".repeat(empty))
+        };
+        assert_eq!(bar(0.0), "░░░░░░░░");
+        assert_eq!(bar(1.0), "▏░░░░░░░");
+        assert_eq!(bar(10.0), "▊░░░░░░░");
+        assert_eq!(bar(20.0), "█▋░░░░░░");
+        assert_eq!(bar(50.0), "████░░░░");
+        assert_eq!(bar(99.0), "███████▉");
+        assert_eq!(bar(100.0), "████████");
+        assert_eq!(bar(150.0), "████████");
+    }
+
+    #[test]
+    fn test_format_age_units() {
+        let age = |secs| format_age(Duration::from_secs(secs));
+        assert_eq!(format_age(Duration::from_millis(400)), "just now");
+        assert_eq!(age(4), "4s ago");
+        assert_eq!(age(59), "59s ago");
+        assert_eq!(age(60), "1m ago");
+        assert_eq!(age(3_599), "59m ago");
+        assert_eq!(age(7_200), "2h ago");
+        assert_eq!(age(200_000), "2d ago");
+    }
+
+    #[test]
+    fn test_truncate_and_wrap_titles() {
+        assert_eq!(truncate("Short", 10), "Short");
+        assert_eq!(truncate("Sécurité hardening", 8), "Sécurit…");
+        assert_eq!(fit_width("ab", 4), "ab  ");
+
+        assert_eq!(
+            wrap_text("Security hardening (auth, secrets, and audit logging)", 20),
+            vec![
+                "Security hardening",
+                "(auth, secrets, and",
+                "audit logging)"
+            ]
+        );
+        assert_eq!(wrap_text("abcdefgh ij", 3), vec!["abc", "def", "gh", "ij"]);
+        assert_eq!(wrap_text("", 10), vec![""]);
+
+        // Wide characters take two columns each
+        assert_eq!(truncate("安全加固计划", 7), "安全加…");
+        assert_eq!(fit_width("安全", 6), "安全  ");
+        assert_eq!(wrap_text("安全加固 计划", 5), vec!["安全", "加固", "计划"]);
+    }
+
+    #[test]
+    fn test_progress_color_by_status_and_percent() {
+        let theme = Theme::mako();
+        let color = |status, pct| progress_color(status, pct, &theme);
+        for pct in [0.0, 40.0, 100.0] {
+            assert_eq!(color(Some(Status::Complete), pct), theme.progress_done);
+            assert_eq!(color(Some(Status::Review), pct), theme.review);
+            assert_eq!(color(Some(Status::Blocked), pct), theme.progress_blocked);
+        }
+        for status in [Some(Status::New), Some(Status::InProgress), None] {
+            assert_eq!(color(status, 0.0), theme.progress_new);
+            assert_eq!(color(status, 40.0), theme.progress_active);
+            assert_eq!(color(status, 100.0), theme.progress_done);
+        }
+    }
+
+    #[test]
+    fn test_parse_split_percent_clamps() {
+        assert_eq!(parse_split_percent("45"), Some(45));
+        assert_eq!(parse_split_percent("5"), Some(20));
+        assert_eq!(parse_split_percent("95"), Some(80));
+        assert_eq!(parse_split_percent(""), None);
+        assert_eq!(parse_split_percent("abc"), None);
+    }
+
+    #[test]
+    fn test_overview_is_reread_when_readme_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("tracks.md"), "# Project Tracks\n").unwrap();
+        let readme = dir.path().join("README.md");
+        std::fs::write(&readme, "# Q1 Roadmap\n").unwrap();
+        let mut app = App::new(
+            dir.path().to_path_buf(),
+            IndexSource::File(dir.path().join("tracks.md")),
+            true,
+            FilterMode::All,
+        )
+        .unwrap();
+        app.history_path = None;
+        app.pins_path = None;
+        assert_eq!(app.overview.as_ref().unwrap().title, "Q1 Roadmap");
+
+        std::fs::write(&readme, "# Q2 Roadmap\n").unwrap();
+        app.pending_changes.push(readme);
+        app.flush_pending_changes();
+        assert_eq!(app.overview.as_ref().unwrap().title, "Q2 Roadmap");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_launcher_failure_is_an_error() {
+        assert!(spawn_launcher("true", &[]).is_ok());
+        let err = spawn_launcher("false", &[]).unwrap_err();
+        assert!(err.to_string().starts_with("false exit status"), "{err}");
+        // Still running after the grace period: assumed to have launched
+        assert!(spawn_launcher("sleep", &["2".as_ref()]).is_ok());
+    }
 }
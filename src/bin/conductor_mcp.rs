@@ -4,15 +4,44 @@ use clap::Parser;
 use rmcp::{transport::stdio, ServiceExt};
 use tracing_subscriber::EnvFilter;
 
-use conductor_dashboard::mcp::ConductorService;
+use conductor_dashboard::mcp::{http, ConductorService};
+use conductor_dashboard::parser::ReadOptions;
 
-/// Conductor MCP Server — read-only access to track data via Model Context Protocol.
+/// Conductor MCP Server — access to track data via Model Context Protocol
+/// (read-only unless --allow-write).
 #[derive(Parser, Debug)]
 #[command(name = "conductor-mcp", version, about)]
 struct Cli {
     /// Path to the conductor directory
     #[arg(long, default_value = "./conductor")]
     conductor_dir: PathBuf,
+
+    /// Directory under the conductor dir holding track folders ("." for the conductor dir itself)
+    #[arg(long, value_name = "NAME", default_value = conductor_dashboard::parser::DEFAULT_TRACKS_SUBDIR)]
+    tracks_subdir: String,
+
+    /// Enable write tools (set_task_done, complete_track) that edit plan.md and metadata files
+    #[arg(long)]
+    allow_write: bool,
+
+    /// Plan file name to look for in each track folder; repeat to try several
+    /// in order, using the first that exists (e.g. --plan-file plan.md --plan-file tasks.md)
+    #[arg(long = "plan-file", value_name = "NAME", default_value = conductor_dashboard::parser::DEFAULT_PLAN_FILE)]
+    plan_files: Vec<String>,
+
+    /// Nest H3 phase headings under the H2 phase before them as sub-phases
+    #[arg(long)]
+    sub_phases: bool,
+
+    /// Report complete tracks with their real plan progress instead of
+    /// marking every task done
+    #[arg(long)]
+    no_autocomplete_normalize: bool,
+
+    /// Serve read-only JSON over HTTP on 127.0.0.1:PORT (/tracks, /tracks/{id},
+    /// /summary) instead of MCP over stdio
+    #[arg(long, value_name = "PORT")]
+    serve: Option<u16>,
 }
 
 #[tokio::main]
@@ -35,10 +64,25 @@ async fn main() -> anyhow::Result<()> {
 
     tracing::info!(
         conductor_dir = %cli.conductor_dir.display(),
+        allow_write = cli.allow_write,
         "Starting Conductor MCP server"
     );
 
-    let service = ConductorService::new(&cli.conductor_dir)?;
+    let options = ReadOptions {
+        normalize_complete: !cli.no_autocomplete_normalize,
+        plan_files: cli.plan_files,
+        sub_phases: cli.sub_phases,
+        ..ReadOptions::default()
+    };
+    let service = ConductorService::with_options(&cli.conductor_dir, &cli.tracks_subdir, options)?
+        .with_allow_write(cli.allow_write);
+
+    if let Some(port) = cli.serve {
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", port)).await?;
+        tracing::info!(addr = %listener.local_addr()?, "Serving JSON over HTTP");
+        http::serve(service, listener).await?;
+        return Ok(());
+    }
 
     let server = service.serve(stdio()).await.inspect_err(|e| {
         tracing::error!("serving error: {:?}", e);
@@ -27,7 +27,18 @@ pub struct EventHandler {
 }
 
 impl EventHandler {
-    pub fn new(conductor_dir: PathBuf, watch_enabled: bool) -> Self {
+    /// `index_file` is the tracks index being displayed; it is watched
+    /// alongside the standard conductor files even if it has another name, as
+    /// are plans named in `plan_files` and the board's README.md / overview.md.
+    /// Every dir in `conductor_dirs` is watched recursively. Mouse events are
+    /// dropped unless `mouse_enabled`.
+    pub fn new(
+        conductor_dirs: Vec<PathBuf>,
+        index_file: Option<PathBuf>,
+        plan_files: Vec<String>,
+        watch_enabled: bool,
+        mouse_enabled: bool,
+    ) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
 
         // Spawn crossterm event reader
@@ -35,23 +46,14 @@ impl EventHandler {
         tokio::spawn(async move {
             let mut reader = EventStream::new();
             while let Some(Ok(evt)) = reader.next().await {
-                match evt {
-                    CrosstermEvent::Key(key) if key.kind == KeyEventKind::Press => {
-                        if tx_key.send(Event::Key(key)).is_err() {
-                            break;
-                        }
-                    }
-                    CrosstermEvent::Mouse(mouse) => {
-                        if tx_key.send(Event::Mouse(mouse)).is_err() {
-                            break;
-                        }
-                    }
-                    CrosstermEvent::Resize(w, h) => {
-                        if tx_key.send(Event::Resize(w, h)).is_err() {
-                            break;
-                        }
-                    }
-                    _ => {}
+                let event = match evt {
+                    CrosstermEvent::Key(key) if key.kind == KeyEventKind::Press => Event::Key(key),
+                    CrosstermEvent::Mouse(mouse) if mouse_enabled => Event::Mouse(mouse),
+                    CrosstermEvent::Resize(w, h) => Event::Resize(w, h),
+                    _ => continue,
+                };
+                if tx_key.send(event).is_err() {
+                    break;
                 }
             }
         });
@@ -72,7 +74,9 @@ impl EventHandler {
         if watch_enabled {
             let tx_watch = tx.clone();
             tokio::spawn(async move {
-                if let Err(e) = run_file_watcher(conductor_dir, tx_watch).await {
+                if let Err(e) =
+                    run_file_watcher(conductor_dirs, index_file, plan_files, tx_watch).await
+                {
                     tracing::error!(error = %e, "file watcher failed");
                 }
             });
@@ -87,9 +91,13 @@ impl EventHandler {
 }
 
 async fn run_file_watcher(
-    conductor_dir: PathBuf,
+    conductor_dirs: Vec<PathBuf>,
+    index_file: Option<PathBuf>,
+    plan_files: Vec<String>,
     tx: mpsc::UnboundedSender<Event>,
 ) -> color_eyre::Result<()> {
+    let index_name = index_file.and_then(|p| p.file_name().map(|n| n.to_os_string()));
+
     let (wtx, mut wrx) = mpsc::channel::<Vec<PathBuf>>(100);
 
     let mut debouncer = notify_debouncer_mini::new_debouncer(
@@ -98,7 +106,10 @@ async fn run_file_watcher(
             if let Ok(events) = result {
                 let paths: Vec<_> = events
                     .iter()
-                    .filter(|e| is_conductor_file(&e.path))
+                    .filter(|e| {
+                        is_conductor_file(&e.path, &plan_files)
+                            || (index_name.is_some() && e.path.file_name() == index_name.as_deref())
+                    })
                     .map(|e| e.path.clone())
                     .collect();
                 if !paths.is_empty() {
@@ -108,9 +119,11 @@ async fn run_file_watcher(
         },
     )?;
 
-    debouncer
-        .watcher()
-        .watch(&conductor_dir, notify::RecursiveMode::Recursive)?;
+    for dir in &conductor_dirs {
+        debouncer
+            .watcher()
+            .watch(dir, notify::RecursiveMode::Recursive)?;
+    }
 
     // Keep debouncer alive; forward events
     while let Some(paths) = wrx.recv().await {
@@ -122,9 +135,15 @@ async fn run_file_watcher(
     Ok(())
 }
 
-fn is_conductor_file(path: &Path) -> bool {
-    matches!(
-        path.file_name().and_then(|f| f.to_str()),
-        Some("tracks.md" | "metadata.json" | "meta.yaml" | "plan.md" | "spec.md")
-    )
+fn is_conductor_file(path: &Path, plan_files: &[String]) -> bool {
+    match path.file_name().and_then(|f| f.to_str()) {
+        Some("tracks.md" | "metadata.json" | "meta.yaml" | "meta.toml" | "plan.md" | "spec.md") => {
+            true
+        }
+        Some(name) => {
+            plan_files.iter().any(|p| p == name)
+                || crate::parser::overview::OVERVIEW_FILE_NAMES.contains(&name)
+        }
+        None => false,
+    }
 }
@@ -1,10 +1,8 @@
 mod app;
 mod event;
-pub mod model;
-pub mod parser;
 mod theme;
 
-use std::io::stdout;
+use std::io::{stdout, Read, Write};
 use std::path::PathBuf;
 
 use clap::Parser;
@@ -12,23 +10,126 @@ use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
 use crossterm::execute;
 use crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen};
 
+use conductor_dashboard::{mcp, model, parser};
+
 use crate::model::FilterMode;
+use crate::parser::git::GitDates;
+use crate::parser::index::{IndexSource, StatusSource};
 
 /// Conductor Dashboard — live terminal dashboard for Conductor track progress.
 #[derive(Parser, Debug)]
 #[command(name = "conductor-dashboard", version, about)]
 struct Cli {
-    /// Path to the conductor directory
+    /// Path to the conductor directory. Repeat to merge several into one view
+    /// (tracks from later dirs whose IDs collide are shown as `<origin>/<id>`)
     #[arg(long, default_value = "./conductor")]
-    conductor_dir: PathBuf,
+    conductor_dir: Vec<PathBuf>,
+
+    /// Read tracks.md from this file instead of <conductor-dir>/tracks.md.
+    /// Track files are read from the sibling --tracks-subdir directory. Use `-`
+    /// to read from stdin (track files then come from --conductor-dir).
+    #[arg(long, value_name = "PATH")]
+    tracks_file: Option<PathBuf>,
+
+    /// Directory under the conductor dir holding track folders ("." for the conductor dir itself)
+    #[arg(long, value_name = "NAME", default_value = parser::DEFAULT_TRACKS_SUBDIR)]
+    tracks_subdir: String,
+
+    /// Which tracks.md signal wins for status: checkbox, field, or field-then-checkbox
+    #[arg(long, value_name = "SOURCE", default_value = "field-then-checkbox")]
+    status_source: String,
 
     /// Disable file watching (static mode)
     #[arg(long)]
     no_watch: bool,
 
+    /// Require file watching (the default); errors if it cannot be honoured
+    #[arg(long, conflicts_with = "no_watch")]
+    watch: bool,
+
     /// Initial filter mode
     #[arg(long, default_value = "all")]
     filter: String,
+
+    /// Only show my tracks: branch prefix or owner substring to match (toggle with m)
+    #[arg(long, value_name = "PATTERN")]
+    mine: Option<String>,
+
+    /// Print one track's full detail as JSON and exit (ID or unique substring)
+    #[arg(long, value_name = "ID")]
+    track: Option<String>,
+
+    /// Write the dependency graph as Graphviz DOT to PATH and exit
+    #[arg(long, value_name = "PATH")]
+    export_dot: Option<PathBuf>,
+
+    /// Write a Markdown report (stats, track table, outstanding tasks) to PATH and exit
+    #[arg(long, value_name = "PATH")]
+    export_md: Option<PathBuf>,
+
+    /// Show the clock as HH:MM so an idle dashboard redraws once a minute
+    #[arg(long)]
+    no_clock_seconds: bool,
+
+    /// Don't capture the mouse, so the terminal's own text selection works
+    /// (clicking and scrolling the list are disabled; keys still work)
+    #[arg(long)]
+    no_mouse: bool,
+
+    /// Marker drawn before the selected track, e.g. "> " on terminals
+    /// without the default's glyph
+    #[arg(long, value_name = "TEXT", default_value = app::DEFAULT_HIGHLIGHT_SYMBOL)]
+    highlight_symbol: String,
+
+    /// Show the selected track in reverse video instead of the theme's
+    /// selection colors
+    #[arg(long)]
+    reverse_selection: bool,
+
+    /// Hide complete tracks not updated for this many days (still shown under the Complete filter)
+    #[arg(long, value_name = "DAYS")]
+    archive_complete_after: Option<u32>,
+
+    /// Have the Blocked filter also list tracks waiting on a dependency that
+    /// isn't complete, whatever their own status
+    #[arg(long)]
+    blocked_includes_deps: bool,
+
+    /// Retry failed plan/metadata reads up to N times (at most 5) with a
+    /// short backoff (for network filesystems where files can be caught
+    /// mid-write)
+    #[arg(long, value_name = "N", default_value_t = 0, value_parser = clap::value_parser!(u32).range(0..=5))]
+    read_retries: u32,
+
+    /// Use each track folder's last git commit date (else its mtime) as
+    /// updated_at when metadata has none
+    #[arg(long)]
+    git_dates: bool,
+
+    /// Plan file name to look for in each track folder; repeat to try several
+    /// in order, using the first that exists (e.g. --plan-file plan.md --plan-file tasks.md)
+    #[arg(long = "plan-file", value_name = "NAME", default_value = parser::DEFAULT_PLAN_FILE)]
+    plan_files: Vec<String>,
+
+    /// Read each track's plan only when it is selected, for boards with
+    /// thousands of tracks (progress and task counts fill in as tracks are
+    /// opened; the daily progress metric is off)
+    #[arg(long)]
+    lazy_plans: bool,
+
+    /// Nest H3 phase headings under the H2 phase before them as sub-phases,
+    /// indented in the detail panel
+    #[arg(long)]
+    sub_phases: bool,
+
+    /// Show complete tracks with their real plan progress instead of marking
+    /// every task done
+    #[arg(long)]
+    no_autocomplete_normalize: bool,
+
+    /// Show parser debugging info (raw checkbox vs resolved status) in the detail panel
+    #[arg(long)]
+    debug: bool,
 }
 
 #[tokio::main]
@@ -36,14 +137,125 @@ async fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
 
     let cli = Cli::parse();
+    let primary_dir = cli.conductor_dir[0].clone();
+    let extra_dirs = cli.conductor_dir[1..].to_vec();
+    for dir in &extra_dirs {
+        if !dir.join("tracks.md").exists() {
+            eprintln!("Error: tracks.md not found in {}", dir.display());
+            std::process::exit(1);
+        }
+    }
 
-    // Validate conductor directory
-    if !cli.conductor_dir.join("tracks.md").exists() {
-        eprintln!(
-            "Error: tracks.md not found in {}",
-            cli.conductor_dir.display()
-        );
-        std::process::exit(1);
+    // Resolve where tracks.md comes from and which directory holds `tracks/`
+    let mut no_watch = cli.no_watch;
+    let (conductor_dir, index_source) = match cli.tracks_file {
+        Some(ref path) if path.as_os_str() == "-" => {
+            if cli.watch {
+                eprintln!("Error: --watch cannot be used when reading tracks.md from stdin");
+                std::process::exit(1);
+            }
+            let mut content = String::new();
+            std::io::stdin().read_to_string(&mut content)?;
+            let content = parser::strip_bom(&content).to_string();
+            no_watch = true;
+            (primary_dir.clone(), IndexSource::Content(content))
+        }
+        Some(ref path) => {
+            if !path.is_file() {
+                eprintln!("Error: tracks file not found: {}", path.display());
+                std::process::exit(1);
+            }
+            let dir = match path.parent() {
+                Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+                _ => PathBuf::from("."),
+            };
+            (dir, IndexSource::File(path.clone()))
+        }
+        None => {
+            let index_path = primary_dir.join("tracks.md");
+            if !index_path.exists() {
+                eprintln!("Error: tracks.md not found in {}", primary_dir.display());
+                std::process::exit(1);
+            }
+            (primary_dir.clone(), IndexSource::File(index_path))
+        }
+    };
+
+    let status_source = match cli.status_source.to_lowercase().as_str() {
+        "checkbox" => StatusSource::Checkbox,
+        "field" => StatusSource::Field,
+        "field-then-checkbox" => StatusSource::FieldThenCheckbox,
+        other => {
+            eprintln!(
+                "Error: unknown --status-source '{other}' (expected checkbox, field, or field-then-checkbox)"
+            );
+            std::process::exit(1);
+        }
+    };
+
+    // Tracks for the one-shot modes below
+    let load_tracks = || -> color_eyre::Result<_> {
+        let mut tracks = parser::load_tracks_with_extra_dirs(
+            &index_source,
+            &conductor_dir,
+            &extra_dirs,
+            &cli.tracks_subdir,
+            status_source,
+            &parser::ReadOptions {
+                read_retries: cli.read_retries,
+                normalize_complete: !cli.no_autocomplete_normalize,
+                plan_files: cli.plan_files.clone(),
+                lazy_plans: false,
+                sub_phases: cli.sub_phases,
+            },
+        )?;
+        if cli.git_dates {
+            parser::git::GitDates::default().fill_updated_at(
+                &mut tracks,
+                &conductor_dir,
+                &cli.tracks_subdir,
+            );
+        }
+        Ok(tracks)
+    };
+
+    // One-shot JSON output for scripts
+    if let Some(ref query) = cli.track {
+        let tracks = load_tracks()?;
+        match mcp::find_track(&tracks, query) {
+            Ok(track) => {
+                let tracks_dir = track
+                    .source_dir
+                    .as_ref()
+                    .unwrap_or(&conductor_dir)
+                    .join(&cli.tracks_subdir);
+                let detail = mcp::track_detail(track, &tracks_dir, &cli.plan_files, None);
+                let json = serde_json::to_string_pretty(&detail)?;
+                // A closed pipe (e.g. `| head`) is not an error for a one-shot dump
+                match writeln!(stdout().lock(), "{json}") {
+                    Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => return Err(e.into()),
+                    _ => return Ok(()),
+                }
+            }
+            Err(msg) => {
+                eprintln!("Error: {msg}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // Headless DOT export
+    if let Some(ref path) = cli.export_dot {
+        let tracks = load_tracks()?;
+        std::fs::write(path, model::to_dot(&tracks))?;
+        return Ok(());
+    }
+
+    // Headless Markdown report
+    if let Some(ref path) = cli.export_md {
+        let tracks = load_tracks()?;
+        std::fs::write(path, model::to_markdown(&tracks))?;
+        return Ok(());
     }
 
     // Set up logging to file (we own the terminal)
@@ -64,6 +276,7 @@ async fn main() -> color_eyre::Result<()> {
 
     let initial_filter = match cli.filter.to_lowercase().as_str() {
         "active" => FilterMode::Active,
+        "review" => FilterMode::Review,
         "blocked" => FilterMode::Blocked,
         "complete" => FilterMode::Complete,
         "new" => FilterMode::New,
@@ -78,12 +291,35 @@ async fn main() -> color_eyre::Result<()> {
         original_hook(panic_info);
     }));
 
-    // Set up terminal with mouse capture enabled
-    execute!(stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    // Set up terminal, with mouse capture unless --no-mouse
+    execute!(stdout(), EnterAlternateScreen)?;
+    if !cli.no_mouse {
+        execute!(stdout(), EnableMouseCapture)?;
+    }
     let mut terminal = ratatui::init();
 
     // Run the app
-    let mut app = app::App::new(cli.conductor_dir, cli.no_watch, initial_filter)?;
+    let mut app = app::App::new(conductor_dir, index_source, no_watch, initial_filter)?;
+    app.set_plan_files(cli.plan_files.clone());
+    app.set_tracks_subdir(&cli.tracks_subdir);
+    app.extra_conductor_dirs = extra_dirs;
+    app.status_source = status_source;
+    app.debug = cli.debug;
+    app.read_retries = cli.read_retries;
+    app.git_dates = cli.git_dates.then(GitDates::default);
+    app.normalize_complete = !cli.no_autocomplete_normalize;
+    app.lazy_plans = cli.lazy_plans;
+    app.sub_phases = cli.sub_phases;
+    app.clock_seconds = !cli.no_clock_seconds;
+    app.mouse_enabled = !cli.no_mouse;
+    app.highlight_symbol = cli.highlight_symbol;
+    app.reverse_selection = cli.reverse_selection;
+    app.archive_after = cli
+        .archive_complete_after
+        .map(|days| chrono::Duration::days(days.into()));
+    app.blocked_includes_deps = cli.blocked_includes_deps;
+    app.mine_only = cli.mine.is_some();
+    app.mine_pattern = cli.mine;
     let result = app.run(&mut terminal).await;
 
     // Restore terminal — disable mouse capture before restoring
@@ -0,0 +1,167 @@
+//! Read-only JSON over plain HTTP (`conductor-mcp --serve <port>`), for web
+//! dashboards that don't speak MCP. Responses are exactly what the matching
+//! MCP tools return:
+//!
+//! - `GET /tracks[?status=…&sort=…]` → `list_tracks`
+//! - `GET /tracks/{id}[?relative_paths=true]` → `get_track_detail`
+//! - `GET /summary` → `get_summary`
+//! - `GET /metrics` → `get_metrics` (Prometheus text, for scrapers)
+//!
+//! Each request first reloads the tracks if any conductor file changed.
+
+use rmcp::handler::server::wrapper::Parameters;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::debug;
+
+use super::service::{find_track, ConductorService};
+use super::types::{GetTrackDetailParams, ListTracksParams};
+
+/// Content type of the tool responses.
+const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Content type of the Prometheus text exposition format served at `/metrics`.
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Upper bound on the request line plus headers we are willing to read.
+const MAX_REQUEST_BYTES: u64 = 16 * 1024;
+
+/// Accept connections on `listener` forever, one task per connection.
+pub async fn serve(service: ConductorService, listener: TcpListener) -> std::io::Result<()> {
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let service = service.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(&service, stream).await {
+                debug!(%peer, error = %e, "http connection failed");
+            }
+        });
+    }
+}
+
+/// Read one request, answer it, and close the connection.
+async fn handle_connection(service: &ConductorService, stream: TcpStream) -> std::io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader.take(MAX_REQUEST_BYTES));
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    // Drain the headers; nothing in them changes the response
+    let mut header = String::new();
+    while reader.read_line(&mut header).await? > 0 && !header.trim().is_empty() {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = (
+        parts.next().unwrap_or_default(),
+        parts.next().unwrap_or("/"),
+    );
+    // Stat the conductor files and maybe reload them off the async workers
+    let (method, target) = (method.to_string(), target.to_string());
+    let service = service.clone();
+    let (status, content_type, body) = tokio::task::spawn_blocking(move || {
+        service.refresh_if_changed();
+        respond(&service, &method, &target)
+    })
+    .await
+    .map_err(std::io::Error::other)?;
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\n\
+         Content-Type: {content_type}\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    );
+    writer.write_all(response.as_bytes()).await?;
+    writer.shutdown().await
+}
+
+/// Status line, content type and body for `method target`: JSON, except
+/// the metrics text.
+pub fn respond(
+    service: &ConductorService,
+    method: &str,
+    target: &str,
+) -> (&'static str, &'static str, String) {
+    if method != "GET" {
+        return (
+            "405 Method Not Allowed",
+            JSON_CONTENT_TYPE,
+            error_body("only GET is supported"),
+        );
+    }
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let param = |name: &str| {
+        query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| percent_decode(value))
+    };
+
+    match path.trim_end_matches('/') {
+        "/tracks" => (
+            "200 OK",
+            JSON_CONTENT_TYPE,
+            service.list_tracks(Parameters(ListTracksParams {
+                status: param("status"),
+                sort: param("sort"),
+            })),
+        ),
+        "/summary" => ("200 OK", JSON_CONTENT_TYPE, service.get_summary()),
+        "/metrics" => ("200 OK", PROMETHEUS_CONTENT_TYPE, service.get_metrics()),
+        path => match path.strip_prefix("/tracks/") {
+            Some(id) => {
+                let track_id = percent_decode(id);
+                match find_track(&service.tracks(), &track_id) {
+                    Ok(_) => (
+                        "200 OK",
+                        JSON_CONTENT_TYPE,
+                        service.get_track_detail(Parameters(GetTrackDetailParams {
+                            track_id,
+                            relative_paths: param("relative_paths").map(|v| v == "true"),
+                        })),
+                    ),
+                    Err(msg) => ("404 Not Found", JSON_CONTENT_TYPE, error_body(&msg)),
+                }
+            }
+            None => (
+                "404 Not Found",
+                JSON_CONTENT_TYPE,
+                error_body(&format!("no route for {path}")),
+            ),
+        },
+    }
+}
+
+fn error_body(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+/// Decode `%XX` escapes (and `+` as space) in a path segment or query value.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => match s
+                .get(i + 1..i + 3)
+                .and_then(|h| u8::from_str_radix(h, 16).ok())
+            {
+                Some(byte) => {
+                    out.push(byte);
+                    i += 3;
+                    continue;
+                }
+                None => out.push(b'%'),
+            },
+            b'+' => out.push(b' '),
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
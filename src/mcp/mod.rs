@@ -1,4 +1,5 @@
+pub mod http;
 pub mod service;
 pub mod types;
 
-pub use service::ConductorService;
+pub use service::{display_path, find_track, repo_root, track_detail, ConductorService};
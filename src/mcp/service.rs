@@ -1,14 +1,19 @@
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::SystemTime;
 
+use chrono::{DateTime, FixedOffset, Offset, Utc};
 use rmcp::{
     handler::server::{router::tool::ToolRouter, wrapper::Parameters},
     model::{ServerCapabilities, ServerInfo},
     tool, tool_handler, tool_router, ServerHandler,
 };
 
-use crate::model::{Priority, Status, Track, TrackId};
+use crate::model::{
+    compare_tracks, outstanding_by_priority, PhaseStatus, PlanPhase, Priority, SortMode, Status,
+    Track, TrackId,
+};
 use crate::parser;
 
 use super::types::*;
@@ -19,22 +24,110 @@ use super::types::*;
 
 #[derive(Debug, Clone)]
 pub struct ConductorService {
-    tracks: Arc<BTreeMap<TrackId, Track>>,
+    /// Swapped wholesale after a write, so readers keep a consistent snapshot.
+    tracks: Arc<RwLock<Arc<BTreeMap<TrackId, Track>>>>,
     conductor_dir: PathBuf,
+    tracks_subdir: String,
+    /// Whether write tools (`set_task_done`, `complete_track`) may modify files.
+    allow_write: bool,
+    /// How track folders are read (plan file names, normalisation, retries).
+    read_options: parser::ReadOptions,
+    /// Fingerprint of the conductor files behind the current snapshot.
+    source_stamp: Arc<Mutex<SourceStamp>>,
     tool_router: ToolRouter<Self>,
 }
 
 #[tool_router]
 impl ConductorService {
     pub fn new(conductor_dir: &Path) -> Result<Self, crate::parser::error::ParseError> {
-        let tracks = parser::load_all_tracks(conductor_dir)?;
+        Self::with_tracks_subdir(conductor_dir, parser::DEFAULT_TRACKS_SUBDIR)
+    }
+
+    /// Like [`ConductorService::new`], reading track folders from `tracks_subdir`.
+    pub fn with_tracks_subdir(
+        conductor_dir: &Path,
+        tracks_subdir: &str,
+    ) -> Result<Self, crate::parser::error::ParseError> {
+        Self::with_options(conductor_dir, tracks_subdir, parser::ReadOptions::default())
+    }
+
+    /// Like [`ConductorService::with_tracks_subdir`], reading track files as
+    /// `options` says. Plans are always read up front, whatever
+    /// [`parser::ReadOptions::lazy_plans`] says, since tools report every task.
+    pub fn with_options(
+        conductor_dir: &Path,
+        tracks_subdir: &str,
+        options: parser::ReadOptions,
+    ) -> Result<Self, crate::parser::error::ParseError> {
+        let read_options = parser::ReadOptions {
+            lazy_plans: false,
+            ..options
+        };
+        let stamp = SourceStamp::read(&conductor_dir.join(tracks_subdir), conductor_dir);
+        let tracks =
+            parser::load_all_tracks_with_options(conductor_dir, tracks_subdir, &read_options)?;
         Ok(Self {
-            tracks: Arc::new(tracks),
+            tracks: Arc::new(RwLock::new(Arc::new(tracks))),
             conductor_dir: conductor_dir.to_path_buf(),
+            tracks_subdir: tracks_subdir.to_string(),
+            allow_write: false,
+            read_options,
+            source_stamp: Arc::new(Mutex::new(stamp)),
             tool_router: Self::tool_router(),
         })
     }
 
+    /// Enable or disable write tools (read-only by default).
+    pub fn with_allow_write(mut self, allow_write: bool) -> Self {
+        self.allow_write = allow_write;
+        self
+    }
+
+    fn tracks_dir(&self) -> PathBuf {
+        self.conductor_dir.join(&self.tracks_subdir)
+    }
+
+    /// The plan file of `track`, found as the parser finds it.
+    fn plan_path(&self, track: &Track) -> Option<PathBuf> {
+        parser::find_plan_file(
+            &self.tracks_dir().join(track.dir_name()),
+            &self.read_options.plan_files,
+        )
+    }
+
+    /// Current snapshot of the loaded tracks.
+    pub fn tracks(&self) -> Arc<BTreeMap<TrackId, Track>> {
+        Arc::clone(&self.tracks.read().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Reload the tracks if any conductor file changed since the last load.
+    ///
+    /// Long-running readers (the HTTP server) call this per request, since
+    /// otherwise only MCP writes refresh the snapshot.
+    pub fn refresh_if_changed(&self) {
+        let stamp = SourceStamp::read(&self.tracks_dir(), &self.conductor_dir);
+        if *self.source_stamp.lock().unwrap_or_else(|e| e.into_inner()) == stamp {
+            return;
+        }
+        if let Err(e) = self.reload() {
+            tracing::warn!(error = %e, "failed to reload tracks after a file change");
+        }
+    }
+
+    /// Re-read every track from disk and swap in the new snapshot.
+    fn reload(&self) -> Result<(), crate::parser::error::ParseError> {
+        // Stamp before reading, so a change made mid-load triggers another reload
+        let stamp = SourceStamp::read(&self.tracks_dir(), &self.conductor_dir);
+        let tracks = parser::load_all_tracks_with_options(
+            &self.conductor_dir,
+            &self.tracks_subdir,
+            &self.read_options,
+        )?;
+        *self.tracks.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(tracks);
+        *self.source_stamp.lock().unwrap_or_else(|e| e.into_inner()) = stamp;
+        Ok(())
+    }
+
     // -- helpers (not tools) ------------------------------------------------
 
     fn format_datetime(dt: &Option<chrono::DateTime<chrono::Utc>>) -> Option<String> {
@@ -57,74 +150,91 @@ impl ConductorService {
         }
     }
 
-    fn track_to_detail(&self, track: &Track) -> TrackDetailResponse {
-        let tracks_dir = self.conductor_dir.join("tracks");
-        let track_dir = tracks_dir.join(track.id.as_str());
-
-        let plan_md = track_dir.join("plan.md");
-        let metadata_json = track_dir.join("metadata.json");
-        let meta_yaml = track_dir.join("meta.yaml");
-
-        TrackDetailResponse {
-            id: track.id.as_str().to_string(),
-            title: track.title.clone(),
-            status: format!("{}", track.status),
-            priority: format!("{}", track.priority),
-            track_type: format!("{}", track.track_type),
-            phase: track.phase.clone(),
-            progress_percent: track.progress_percent(),
-            tasks_completed: track.tasks_completed,
-            tasks_total: track.tasks_total,
-            tags: track.tags.clone(),
-            dependencies: track
-                .dependencies
+    fn phase_to_response(phase: &PlanPhase) -> PhaseResponse {
+        PhaseResponse {
+            name: phase.name.clone(),
+            status: format!("{}", phase.status),
+            tasks_completed: phase.tasks_completed(),
+            tasks_total: phase.tasks.len(),
+            progress_percent: phase.progress_percent(),
+            depth: phase.depth,
+            tasks: phase
+                .tasks
                 .iter()
-                .map(|d| d.as_str().to_string())
+                .map(|t| TaskResponse {
+                    text: t.text.clone(),
+                    done: t.done,
+                })
                 .collect(),
-            branch: track.branch.clone(),
-            description: track.description.clone(),
-            created_at: Self::format_datetime(&track.created_at),
-            updated_at: Self::format_datetime(&track.updated_at),
-            plan_phases: track
+        }
+    }
+
+    /// Resolve a phase by 0-based index or case-insensitive name.
+    ///
+    /// An exact name match wins; otherwise a unique substring match is used.
+    fn find_phase<'a>(track: &'a Track, query: &str) -> Result<&'a PlanPhase, String> {
+        let query = query.trim();
+        if let Ok(index) = query.parse::<usize>() {
+            return track.plan_phases.get(index).ok_or_else(|| {
+                format!(
+                    "Phase index {} out of range for track '{}' ({} phases)",
+                    index,
+                    track.id,
+                    track.plan_phases.len()
+                )
+            });
+        }
+
+        let lower = query.to_lowercase();
+        let exact: Vec<&PlanPhase> = track
+            .plan_phases
+            .iter()
+            .filter(|p| p.name.to_lowercase() == lower)
+            .collect();
+        let matches = if exact.is_empty() {
+            track
                 .plan_phases
                 .iter()
-                .map(|p| PhaseResponse {
-                    name: p.name.clone(),
-                    status: format!("{}", p.status),
-                    tasks_completed: p.tasks_completed(),
-                    tasks_total: p.tasks.len(),
-                    progress_percent: p.progress_percent(),
-                    tasks: p
-                        .tasks
-                        .iter()
-                        .map(|t| TaskResponse {
-                            text: t.text.clone(),
-                            done: t.done,
-                        })
-                        .collect(),
-                })
-                .collect(),
-            file_paths: FilePathsResponse {
-                track_dir: track_dir.to_string_lossy().to_string(),
-                plan_md: plan_md
-                    .exists()
-                    .then(|| plan_md.to_string_lossy().to_string()),
-                metadata_json: metadata_json
-                    .exists()
-                    .then(|| metadata_json.to_string_lossy().to_string()),
-                meta_yaml: meta_yaml
-                    .exists()
-                    .then(|| meta_yaml.to_string_lossy().to_string()),
-            },
+                .filter(|p| p.name.to_lowercase().contains(&lower))
+                .collect()
+        } else {
+            exact
+        };
+
+        match matches.len() {
+            0 => Err(format!(
+                "No phase matching '{}' in track '{}'",
+                query, track.id
+            )),
+            1 => Ok(matches[0]),
+            _ => {
+                let names: Vec<&str> = matches.iter().map(|p| p.name.as_str()).collect();
+                Err(format!(
+                    "Multiple phases match '{}': {}. Please be more specific.",
+                    query,
+                    names.join(", ")
+                ))
+            }
         }
     }
 
+    fn track_to_detail(&self, track: &Track, relative_paths: bool) -> TrackDetailResponse {
+        let base = relative_paths.then(|| repo_root(&self.conductor_dir));
+        track_detail(
+            track,
+            &self.tracks_dir(),
+            &self.read_options.plan_files,
+            base,
+        )
+    }
+
     // -- tools --------------------------------------------------------------
 
     #[tool(
         description = "List all tracks with optional filtering by status and sorting. Returns summary info for each track including progress, tasks, tags, and dates."
     )]
     pub fn list_tracks(&self, Parameters(params): Parameters<ListTracksParams>) -> String {
+        let snapshot = self.tracks();
         let status_filter = params
             .status
             .as_deref()
@@ -136,7 +246,7 @@ impl ConductorService {
             .unwrap_or("updated")
             .to_ascii_lowercase();
 
-        let mut tracks: Vec<&Track> = self.tracks.values().collect();
+        let mut tracks: Vec<&Track> = snapshot.values().collect();
 
         // Filter by status
         if status_filter != "all" {
@@ -144,15 +254,13 @@ impl ConductorService {
             tracks.retain(|t| t.status == target);
         }
 
-        // Sort
-        match sort.as_str() {
-            "progress" => tracks.sort_by(|a, b| {
-                b.progress_percent()
-                    .partial_cmp(&a.progress_percent())
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            }),
-            _ => tracks.sort_by(|a, b| b.updated_at.cmp(&a.updated_at)),
-        }
+        // Sort (same ordering as the dashboard)
+        let sort = match sort.as_str() {
+            "progress" => SortMode::Progress,
+            "type" => SortMode::Type,
+            _ => SortMode::Updated,
+        };
+        tracks.sort_by(|a, b| compare_tracks(a, b, sort));
 
         let summaries: Vec<TrackSummaryResponse> =
             tracks.iter().map(|t| Self::track_to_summary(t)).collect();
@@ -164,55 +272,63 @@ impl ConductorService {
         description = "Get full detail for a single track including plan phases, tasks, dependencies, file paths, and all metadata."
     )]
     pub fn get_track_detail(&self, Parameters(params): Parameters<GetTrackDetailParams>) -> String {
-        let track_id = TrackId::new(&params.track_id);
-        match self.tracks.get(&track_id) {
-            Some(track) => {
-                let detail = self.track_to_detail(track);
+        let tracks = self.tracks();
+        match find_track(&tracks, &params.track_id) {
+            Ok(track) => {
+                let detail = self.track_to_detail(track, params.relative_paths.unwrap_or(false));
                 serde_json::to_string_pretty(&detail).unwrap_or_else(|e| format!("Error: {e}"))
             }
-            None => {
-                // Try substring match
-                let matches: Vec<&Track> = self
-                    .tracks
-                    .values()
-                    .filter(|t| t.id.as_str().contains(&params.track_id))
-                    .collect();
-                match matches.len() {
-                    0 => format!("No track found matching '{}'", params.track_id),
-                    1 => {
-                        let detail = self.track_to_detail(matches[0]);
-                        serde_json::to_string_pretty(&detail)
-                            .unwrap_or_else(|e| format!("Error: {e}"))
-                    }
-                    _ => {
-                        let ids: Vec<&str> = matches.iter().map(|t| t.id.as_str()).collect();
-                        format!(
-                            "Multiple tracks match '{}': {}. Please be more specific.",
-                            params.track_id,
-                            ids.join(", ")
-                        )
-                    }
-                }
-            }
+            Err(msg) => msg,
         }
     }
 
     #[tool(
-        description = "Get aggregate summary stats: total track count, counts per status, overall progress percentage, and total task counts."
+        description = "Get a single plan phase of a track, selected by case-insensitive name or 0-based index. Lighter than get_track_detail when working within one phase."
+    )]
+    pub fn get_plan_phase(&self, Parameters(params): Parameters<GetPlanPhaseParams>) -> String {
+        let tracks = self.tracks();
+        let track = match find_track(&tracks, &params.track_id) {
+            Ok(track) => track,
+            Err(msg) => return msg,
+        };
+        match Self::find_phase(track, &params.phase) {
+            Ok(phase) => serde_json::to_string_pretty(&Self::phase_to_response(phase))
+                .unwrap_or_else(|e| format!("Error: {e}")),
+            Err(msg) => msg,
+        }
+    }
+
+    #[tool(
+        description = "Get aggregate summary stats: total track count, counts per status, overall progress percentage, total task counts, and unfinished tasks per track priority (outstanding_tasks_by_priority)."
     )]
     pub fn get_summary(&self) -> String {
-        let total = self.tracks.len();
+        serde_json::to_string_pretty(&self.summary()).unwrap_or_else(|e| format!("Error: {e}"))
+    }
+
+    #[tool(
+        description = "Get board health in Prometheus text exposition format: conductor_tracks_total, conductor_tracks_by_status{status=...}, conductor_tasks_total, conductor_tasks_completed and conductor_overall_progress. Same figures as get_summary."
+    )]
+    pub fn get_metrics(&self) -> String {
+        prometheus_metrics(&self.summary())
+    }
+
+    /// Aggregates behind `get_summary` and `get_metrics`.
+    fn summary(&self) -> SummaryResponse {
+        let tracks = self.tracks();
+        let total = tracks.len();
         let mut new = 0;
         let mut in_progress = 0;
+        let mut review = 0;
         let mut blocked = 0;
         let mut complete = 0;
         let mut total_tasks = 0usize;
         let mut total_completed = 0usize;
 
-        for track in self.tracks.values() {
+        for track in tracks.values() {
             match track.status {
                 Status::New => new += 1,
                 Status::InProgress => in_progress += 1,
+                Status::Review => review += 1,
                 Status::Blocked => blocked += 1,
                 Status::Complete => complete += 1,
             }
@@ -226,29 +342,37 @@ impl ConductorService {
             0.0
         };
 
-        let resp = SummaryResponse {
+        SummaryResponse {
             total_tracks: total,
             by_status: StatusCounts {
                 new,
                 in_progress,
+                review,
                 blocked,
                 complete,
             },
             overall_progress: overall,
             total_tasks,
             total_tasks_completed: total_completed,
-        };
-
-        serde_json::to_string_pretty(&resp).unwrap_or_else(|e| format!("Error: {e}"))
+            outstanding_tasks_by_priority: {
+                let [critical, high, medium, low] = outstanding_by_priority(tracks.values());
+                PriorityCounts {
+                    critical,
+                    high,
+                    medium,
+                    low,
+                }
+            },
+        }
     }
 
     #[tool(
         description = "Search tracks by title, ID, or tag substring (case-insensitive). Returns matching track summaries."
     )]
     pub fn search_tracks(&self, Parameters(params): Parameters<SearchTracksParams>) -> String {
+        let tracks = self.tracks();
         let query = params.query.to_ascii_lowercase();
-        let matches: Vec<TrackSummaryResponse> = self
-            .tracks
+        let matches: Vec<TrackSummaryResponse> = tracks
             .values()
             .filter(|t| {
                 t.id.as_str().to_ascii_lowercase().contains(&query)
@@ -257,7 +381,7 @@ impl ConductorService {
                         .iter()
                         .any(|tag| tag.to_ascii_lowercase().contains(&query))
             })
-            .map(|t| Self::track_to_summary(t))
+            .map(Self::track_to_summary)
             .collect();
 
         serde_json::to_string_pretty(&matches).unwrap_or_else(|e| format!("Error: {e}"))
@@ -270,9 +394,10 @@ impl ConductorService {
         &self,
         Parameters(params): Parameters<GetTrackDependenciesParams>,
     ) -> String {
+        let tracks = self.tracks();
         // Build reverse map: track_id -> list of tracks that depend on it
         let mut blocked_by: BTreeMap<String, Vec<String>> = BTreeMap::new();
-        for track in self.tracks.values() {
+        for track in tracks.values() {
             for dep in &track.dependencies {
                 blocked_by
                     .entry(dep.as_str().to_string())
@@ -283,13 +408,13 @@ impl ConductorService {
 
         let tracks_iter: Box<dyn Iterator<Item = &Track>> = if let Some(ref tid) = params.track_id {
             let track_id = TrackId::new(tid.as_str());
-            if let Some(t) = self.tracks.get(&track_id) {
+            if let Some(t) = tracks.get(&track_id) {
                 Box::new(std::iter::once(t))
             } else {
                 return format!("No track found with ID '{}'", tid);
             }
         } else {
-            Box::new(self.tracks.values())
+            Box::new(tracks.values())
         };
 
         let deps: Vec<DependencyInfo> = tracks_iter
@@ -312,6 +437,66 @@ impl ConductorService {
         serde_json::to_string_pretty(&deps).unwrap_or_else(|e| format!("Error: {e}"))
     }
 
+    #[tool(
+        description = "Rank tracks by impact: how many tracks transitively wait on each one through dependencies. High-impact tracks are the most valuable to unblock. Only tracks with at least one dependent are returned, highest impact first."
+    )]
+    pub fn get_track_impact(&self) -> String {
+        let tracks = self.tracks();
+        let dependents = crate::model::dependents_map(&tracks);
+        let mut impacts: Vec<TrackImpact> = tracks
+            .values()
+            .filter_map(|t| {
+                let impact = crate::model::downstream_dependents(&dependents, &t.id).len();
+                (impact > 0).then(|| TrackImpact {
+                    track_id: t.id.as_str().to_string(),
+                    title: t.title.clone(),
+                    status: format!("{}", t.status),
+                    impact,
+                    blocks: dependents[&t.id]
+                        .iter()
+                        .map(|d| d.as_str().to_string())
+                        .collect(),
+                })
+            })
+            .collect();
+        impacts.sort_by(|a, b| b.impact.cmp(&a.impact).then(a.track_id.cmp(&b.track_id)));
+
+        serde_json::to_string_pretty(&impacts).unwrap_or_else(|e| format!("Error: {e}"))
+    }
+
+    #[tool(
+        description = "Find dependency cycles (tracks that transitively depend on themselves). Returns a JSON array of cycles, each an array of track IDs in dependency order starting from the smallest ID: [\"a\", \"b\"] means a depends on b and b on a. Empty when the dependency graph is acyclic."
+    )]
+    pub fn get_dependency_cycles(&self) -> String {
+        let cycles = crate::model::detect_cycles(&self.tracks());
+        serde_json::to_string_pretty(&cycles).unwrap_or_else(|e| format!("Error: {e}"))
+    }
+
+    #[tool(
+        description = "Recommend which tracks to pick up next. Only actionable tracks are considered: not complete, blocked, or in review, with every dependency complete and no external blockers. Each is scored on priority, impact (how many tracks it transitively unblocks), and whether it is already in progress; returns the top `limit` (default 3), best first, with the rationale for each score."
+    )]
+    pub fn recommend_next_track(
+        &self,
+        Parameters(params): Parameters<RecommendNextTrackParams>,
+    ) -> String {
+        let tracks = self.tracks();
+        let impact = crate::model::track_impact(&tracks);
+        let mut recommendations: Vec<TrackRecommendation> = tracks
+            .values()
+            .filter(|t| {
+                !t.is_complete()
+                    && !matches!(t.status, Status::Blocked | Status::Review)
+                    && t.external_blockers.is_empty()
+                    && crate::model::unmet_dependencies(&tracks, t).is_empty()
+            })
+            .map(|t| recommendation(t, impact[&t.id]))
+            .collect();
+        recommendations.sort_by(|a, b| b.score.cmp(&a.score).then(a.track_id.cmp(&b.track_id)));
+        recommendations.truncate(params.limit.unwrap_or(3));
+
+        serde_json::to_string_pretty(&recommendations).unwrap_or_else(|e| format!("Error: {e}"))
+    }
+
     #[tool(
         description = "Filter tracks by tag (case-insensitive). Returns matching track summaries."
     )]
@@ -319,12 +504,12 @@ impl ConductorService {
         &self,
         Parameters(params): Parameters<GetTracksByTagParams>,
     ) -> String {
+        let tracks = self.tracks();
         let tag = params.tag.to_ascii_lowercase();
-        let matches: Vec<TrackSummaryResponse> = self
-            .tracks
+        let matches: Vec<TrackSummaryResponse> = tracks
             .values()
             .filter(|t| t.tags.iter().any(|tt| tt.to_ascii_lowercase() == tag))
-            .map(|t| Self::track_to_summary(t))
+            .map(Self::track_to_summary)
             .collect();
 
         serde_json::to_string_pretty(&matches).unwrap_or_else(|e| format!("Error: {e}"))
@@ -337,12 +522,62 @@ impl ConductorService {
         &self,
         Parameters(params): Parameters<GetTracksByPriorityParams>,
     ) -> String {
+        let tracks = self.tracks();
         let target = Priority::from_str_loose(&params.priority);
-        let matches: Vec<TrackSummaryResponse> = self
-            .tracks
+        let matches: Vec<TrackSummaryResponse> = tracks
             .values()
             .filter(|t| t.priority == target)
-            .map(|t| Self::track_to_summary(t))
+            .map(Self::track_to_summary)
+            .collect();
+
+        serde_json::to_string_pretty(&matches).unwrap_or_else(|e| format!("Error: {e}"))
+    }
+
+    #[tool(
+        description = "Find tracks with at least one plan phase in the given status (pending, active, complete, blocked), optionally restricted to phases whose name contains a substring. Returns each track with its matching phase names."
+    )]
+    pub fn get_tracks_by_phase_status(
+        &self,
+        Parameters(params): Parameters<GetTracksByPhaseStatusParams>,
+    ) -> String {
+        let tracks = self.tracks();
+        let Some(target) = PhaseStatus::ALL
+            .into_iter()
+            .find(|s| s.label().eq_ignore_ascii_case(params.phase_status.trim()))
+        else {
+            let expected: Vec<String> = PhaseStatus::ALL
+                .iter()
+                .map(|s| s.label().to_ascii_lowercase())
+                .collect();
+            return format!(
+                "Unknown phase status '{}'. Expected one of: {}.",
+                params.phase_status,
+                expected.join(", ")
+            );
+        };
+        let name_filter = params.phase_name.as_deref().map(str::to_ascii_lowercase);
+
+        let matches: Vec<TrackPhaseMatch> = tracks
+            .values()
+            .filter_map(|t| {
+                let phases: Vec<String> = t
+                    .plan_phases
+                    .iter()
+                    .filter(|p| p.status == target)
+                    .filter(|p| {
+                        name_filter
+                            .as_ref()
+                            .is_none_or(|q| p.name.to_ascii_lowercase().contains(q))
+                    })
+                    .map(|p| p.name.clone())
+                    .collect();
+                (!phases.is_empty()).then(|| TrackPhaseMatch {
+                    track_id: t.id.as_str().to_string(),
+                    track_title: t.title.clone(),
+                    status: format!("{}", t.status),
+                    phases,
+                })
+            })
             .collect();
 
         serde_json::to_string_pretty(&matches).unwrap_or_else(|e| format!("Error: {e}"))
@@ -352,74 +587,661 @@ impl ConductorService {
         description = "Get all incomplete (outstanding) tasks across all tracks. Returns the track, phase, and task text for each incomplete task."
     )]
     pub fn get_outstanding_tasks(&self) -> String {
+        let tracks = self.tracks();
         let mut tasks = Vec::new();
-        for track in self.tracks.values() {
+        for track in tracks.values() {
             if track.status == Status::Complete {
                 continue;
             }
-            for phase in &track.plan_phases {
-                for task in &phase.tasks {
-                    if !task.done {
-                        tasks.push(OutstandingTask {
-                            track_id: track.id.as_str().to_string(),
-                            track_title: track.title.clone(),
-                            phase: phase.name.clone(),
-                            task: task.text.clone(),
-                        });
-                    }
-                }
+            for (phase, task) in track.outstanding_tasks() {
+                tasks.push(OutstandingTask {
+                    track_id: track.id.as_str().to_string(),
+                    track_title: track.title.clone(),
+                    phase: phase.name.clone(),
+                    task: task.text.clone(),
+                });
+            }
+        }
+
+        serde_json::to_string_pretty(&tasks).unwrap_or_else(|e| format!("Error: {e}"))
+    }
+
+    #[tool(
+        description = "Get every plan task across all tracks with a stable task ID (`{track_id}#{phase_index}.{task_index}`, 0-based), its track, phase, done state, and text. IDs stay the same while the plan structure is unchanged."
+    )]
+    pub fn get_all_tasks(&self) -> String {
+        let tracks = self.tracks();
+        let tasks: Vec<TaskEntry> = tracks
+            .values()
+            .flat_map(|track| {
+                track.tasks_with_ids().map(|(id, phase, task)| TaskEntry {
+                    id,
+                    track_id: track.id.as_str().to_string(),
+                    track_title: track.title.clone(),
+                    phase: phase.name.clone(),
+                    done: task.done,
+                    text: task.text.clone(),
+                })
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&tasks).unwrap_or_else(|e| format!("Error: {e}"))
+    }
+
+    #[tool(
+        description = "Get unfinished tasks whose inline due date (e.g. `(due 2026-03-01)`) has passed, across all incomplete tracks. Returns the track, phase, task text, and due date, oldest first."
+    )]
+    pub fn get_overdue_tasks(&self) -> String {
+        let tracks = self.tracks();
+        let now = chrono::Utc::now();
+        let mut tasks: Vec<(chrono::DateTime<chrono::Utc>, OverdueTask)> = Vec::new();
+        for track in tracks.values() {
+            if track.status == Status::Complete {
+                continue;
+            }
+            for (phase, task) in track.outstanding_tasks() {
+                let Some(due) = task.due.filter(|_| task.is_overdue(now)) else {
+                    continue;
+                };
+                tasks.push((
+                    due,
+                    OverdueTask {
+                        track_id: track.id.as_str().to_string(),
+                        track_title: track.title.clone(),
+                        phase: phase.name.clone(),
+                        task: task.text.clone(),
+                        due: due.format("%Y-%m-%d").to_string(),
+                    },
+                ));
             }
         }
+        tasks.sort_by_key(|(due, _)| *due);
+        let tasks: Vec<OverdueTask> = tasks.into_iter().map(|(_, t)| t).collect();
 
         serde_json::to_string_pretty(&tasks).unwrap_or_else(|e| format!("Error: {e}"))
     }
 
     #[tool(
-        description = "Get filesystem paths for a track's directory, plan.md, and metadata files."
+        description = "Estimate remaining backlog effort: sum over incomplete tracks of effort * (1 - progress), with a breakdown by priority. Tracks without an effort estimate are excluded and counted."
+    )]
+    pub fn get_estimated_remaining_effort(&self) -> String {
+        let tracks = self.tracks();
+        let mut resp = RemainingEffortResponse {
+            total_remaining: 0.0,
+            by_priority: BTreeMap::new(),
+            tracks_counted: 0,
+            tracks_missing_effort: 0,
+        };
+        for track in tracks.values() {
+            if track.is_complete() {
+                continue;
+            }
+            let Some(effort) = track.effort else {
+                resp.tracks_missing_effort += 1;
+                continue;
+            };
+            let remaining = effort * (1.0 - track.progress_percent() / 100.0);
+            resp.total_remaining += remaining;
+            *resp
+                .by_priority
+                .entry(format!("{}", track.priority))
+                .or_insert(0.0) += remaining;
+            resp.tracks_counted += 1;
+        }
+
+        serde_json::to_string_pretty(&resp).unwrap_or_else(|e| format!("Error: {e}"))
+    }
+
+    #[tool(
+        description = "Changelog of completed tracks for release notes: tracks with status complete whose updated_at is after `since` (all complete tracks when omitted), newest first, each with title, tags, branch, and a ready-made markdown bullet. Optionally grouped by \"tag\" or \"priority\"."
+    )]
+    pub fn get_changelog(&self, Parameters(params): Parameters<GetChangelogParams>) -> String {
+        let since = match params.since.as_deref() {
+            Some(s) => match parser::metadata::parse_datetime(s) {
+                Some(dt) => Some(dt),
+                None => return format!("Invalid date '{s}': expected e.g. 2026-02-01"),
+            },
+            None => None,
+        };
+        let group_by = params.group_by.as_deref().map(str::to_ascii_lowercase);
+        if !matches!(group_by.as_deref(), None | Some("tag" | "priority")) {
+            return format!(
+                "Invalid group_by '{}': expected \"tag\" or \"priority\"",
+                params.group_by.unwrap_or_default()
+            );
+        }
+
+        let tracks = self.tracks();
+        let mut completed: Vec<&Track> = tracks
+            .values()
+            .filter(|t| t.status == Status::Complete)
+            .filter(|t| since.is_none_or(|since| t.updated_at.is_some_and(|at| at > since)))
+            .collect();
+        // Newest first; undated tracks (only without `since`) last
+        completed.sort_by_key(|t| std::cmp::Reverse(t.updated_at));
+
+        // Keyed by (priority, label) so priority groups run critical → low;
+        // tag groups share one priority and sort by name
+        let mut groups: BTreeMap<(Priority, String), Vec<ChangelogEntry>> = BTreeMap::new();
+        for track in completed {
+            let keys: Vec<(Priority, String)> = match group_by.as_deref() {
+                Some("tag") if track.tags.is_empty() => {
+                    vec![(Priority::default(), "untagged".to_string())]
+                }
+                Some("tag") => track
+                    .tags
+                    .iter()
+                    .map(|tag| (Priority::default(), tag.clone()))
+                    .collect(),
+                Some("priority") => vec![(track.priority, track.priority.to_string())],
+                _ => vec![(Priority::default(), "all".to_string())],
+            };
+            for key in keys {
+                groups.entry(key).or_default().push(changelog_entry(track));
+            }
+        }
+        let groups: Vec<ChangelogGroup> = groups
+            .into_iter()
+            .map(|((_, group), entries)| ChangelogGroup { group, entries })
+            .collect();
+
+        serde_json::to_string_pretty(&groups).unwrap_or_else(|e| format!("Error: {e}"))
+    }
+
+    #[tool(
+        description = "Tracks created or completed on a calendar day, for daily retrospectives. `field` is \"created\" (created_at) or \"completed\" (updated_at of a complete track); the day is taken in `utc_offset` (default UTC). Tracks without that timestamp are left out. Returns track summaries."
+    )]
+    pub fn get_tracks_by_date(
+        &self,
+        Parameters(params): Parameters<GetTracksByDateParams>,
+    ) -> String {
+        let Some(day) = parser::metadata::parse_datetime(&params.date).map(|dt| dt.date_naive())
+        else {
+            return format!("Invalid date '{}': expected e.g. 2026-02-10", params.date);
+        };
+        let offset = match params.utc_offset.as_deref().map(parse_utc_offset) {
+            Some(Some(offset)) => offset,
+            Some(None) => {
+                return format!(
+                    "Invalid utc_offset '{}': expected e.g. \"+02:00\"",
+                    params.utc_offset.unwrap_or_default()
+                )
+            }
+            None => Utc.fix(),
+        };
+        let timestamp: fn(&Track) -> Option<DateTime<Utc>> =
+            match params.field.to_ascii_lowercase().as_str() {
+                "created" => |t| t.created_at,
+                "completed" => |t| t.updated_at.filter(|_| t.status == Status::Complete),
+                _ => {
+                    return format!(
+                        "Invalid field '{}': expected \"created\" or \"completed\"",
+                        params.field
+                    )
+                }
+            };
+
+        let tracks = self.tracks();
+        let matches: Vec<TrackSummaryResponse> = tracks
+            .values()
+            .filter(|t| {
+                timestamp(t).is_some_and(|at| at.with_timezone(&offset).date_naive() == day)
+            })
+            .map(Self::track_to_summary)
+            .collect();
+
+        serde_json::to_string_pretty(&matches).unwrap_or_else(|e| format!("Error: {e}"))
+    }
+
+    #[tool(
+        description = "Validate tracks.md against the track folders on disk. Returns issues: missing plans, unresolved dependencies, duplicate IDs, checkbox/Status field mismatches, orphan directories, plans whose task count differs from metadata's total_tasks, metadata files that fail to parse, and tracks with several metadata files (metadata.json, meta.yaml, meta.toml) of which only the first is read. An empty list means the conductor dir is clean."
+    )]
+    pub fn validate_conductor(&self) -> String {
+        match parser::validate::validate_conductor(
+            &self.conductor_dir,
+            &self.tracks_subdir,
+            &self.read_options,
+        ) {
+            Ok(issues) => {
+                let results: Vec<ValidationIssue> = issues
+                    .into_iter()
+                    .map(|i| ValidationIssue {
+                        kind: i.kind.as_str().to_string(),
+                        track_id: i.track_id,
+                        message: i.message,
+                    })
+                    .collect();
+                serde_json::to_string_pretty(&results).unwrap_or_else(|e| format!("Error: {e}"))
+            }
+            Err(e) => format!("Error: {e}"),
+        }
+    }
+
+    #[tool(
+        description = "Mark a plan task done or not done by its task ID from get_all_tasks (`{track_id}#{phase_index}.{task_index}`). Edits the checkbox in the track's plan file (plan.md unless configured otherwise) and returns the updated task; refuses if the task's text changed on disk since it was listed. Only available when the server was started with --allow-write."
+    )]
+    pub fn set_task_done(&self, Parameters(params): Parameters<SetTaskDoneParams>) -> String {
+        if !self.allow_write {
+            return "Error: server is read-only; restart it with --allow-write to enable set_task_done".to_string();
+        }
+        let Some((track_id, phase_index, task_index)) = parse_task_id(&params.task_id) else {
+            return format!(
+                "Invalid task ID '{}': expected {{track_id}}#{{phase_index}}.{{task_index}}",
+                params.task_id
+            );
+        };
+
+        let tracks = self.tracks();
+        let Some(track) = tracks.get(&TrackId::new(track_id)) else {
+            return format!("No track found with ID '{track_id}'");
+        };
+        let Some(task) = track
+            .plan_phases
+            .get(phase_index)
+            .and_then(|p| p.tasks.get(task_index))
+        else {
+            return format!("No task '{}' in track '{track_id}'", params.task_id);
+        };
+
+        let Some(plan_path) = self.plan_path(track) else {
+            return format!("Error: track '{track_id}' has no plan file");
+        };
+        if let Err(e) = parser::plan::write_task_state(
+            &plan_path,
+            phase_index,
+            task_index,
+            &task.text,
+            params.done,
+        ) {
+            return format!("Error: {e}");
+        }
+        if let Err(e) = self.reload() {
+            return format!("Error: plan updated but reload failed: {e}");
+        }
+
+        let tracks = self.tracks();
+        let updated = tracks.get(&TrackId::new(track_id)).and_then(|track| {
+            track
+                .tasks_with_ids()
+                .find(|(id, _, _)| *id == params.task_id)
+                .map(|(id, phase, task)| TaskEntry {
+                    id,
+                    track_id: track.id.as_str().to_string(),
+                    track_title: track.title.clone(),
+                    phase: phase.name.clone(),
+                    done: task.done,
+                    text: task.text.clone(),
+                })
+        });
+        match updated {
+            Some(entry) => {
+                serde_json::to_string_pretty(&entry).unwrap_or_else(|e| format!("Error: {e}"))
+            }
+            None => format!("Error: task '{}' not found after reload", params.task_id),
+        }
+    }
+
+    #[tool(
+        description = "Mark a track complete: sets the status field of its metadata.json, meta.yaml or meta.toml to completed and its updated date to now, editing nothing else, and with tick_all_tasks also ticks every task of its plan file. Refuses when the track has several metadata files or none, or no single status field. Only available when the server was started with --allow-write."
+    )]
+    pub fn complete_track(&self, Parameters(params): Parameters<CompleteTrackParams>) -> String {
+        if !self.allow_write {
+            return "Error: server is read-only; restart it with --allow-write to enable complete_track".to_string();
+        }
+        let tracks = self.tracks();
+        let Some(track) = tracks.get(&TrackId::new(params.track_id.as_str())) else {
+            return format!("No track found with ID '{}'", params.track_id);
+        };
+        let track_dir = self.tracks_dir().join(track.dir_name());
+
+        let metadata_file = match parser::metadata::write_metadata_status(
+            &track_dir,
+            track.id.as_str(),
+            "completed",
+            Utc::now(),
+        ) {
+            Ok(path) => path,
+            Err(e) => return format!("Error: {e}"),
+        };
+        let plan_path = self
+            .plan_path(track)
+            .filter(|_| params.tick_all_tasks.unwrap_or(false));
+        let tasks_ticked = match plan_path.map(|path| parser::plan::write_all_tasks_done(&path)) {
+            Some(Ok(ticked)) => ticked,
+            Some(Err(e)) => return format!("Error: status updated but ticking tasks failed: {e}"),
+            None => 0,
+        };
+        if let Err(e) = self.reload() {
+            return format!("Error: files updated but reload failed: {e}");
+        }
+
+        let tracks = self.tracks();
+        let Some(track) = tracks.get(&TrackId::new(params.track_id.as_str())) else {
+            return format!("Error: track '{}' not found after reload", params.track_id);
+        };
+        let resp = CompleteTrackResponse {
+            track_id: track.id.as_str().to_string(),
+            status: format!("{}", track.status),
+            metadata_file: metadata_file.display().to_string(),
+            tasks_ticked,
+            plan_tasks_completed: track.raw_tasks_completed,
+            plan_tasks_total: track.raw_tasks_total,
+        };
+        serde_json::to_string_pretty(&resp).unwrap_or_else(|e| format!("Error: {e}"))
+    }
+
+    #[tool(
+        description = "Get filesystem paths for a track's directory, plan file (plan.md unless configured otherwise), and metadata files."
     )]
     pub fn get_track_file_paths(
         &self,
         Parameters(params): Parameters<GetTrackFilePathsParams>,
     ) -> String {
-        let tracks_dir = self.conductor_dir.join("tracks");
-        let track_dir = tracks_dir.join(&params.track_id);
+        let track_dir = self.tracks_dir().join(&params.track_id);
 
         if !track_dir.exists() {
             return format!("Track directory not found for '{}'", params.track_id);
         }
 
-        let plan_md = track_dir.join("plan.md");
-        let metadata_json = track_dir.join("metadata.json");
-        let meta_yaml = track_dir.join("meta.yaml");
-
-        let resp = FilePathsResponse {
-            track_dir: track_dir.to_string_lossy().to_string(),
-            plan_md: plan_md
-                .exists()
-                .then(|| plan_md.to_string_lossy().to_string()),
-            metadata_json: metadata_json
-                .exists()
-                .then(|| metadata_json.to_string_lossy().to_string()),
-            meta_yaml: meta_yaml
-                .exists()
-                .then(|| meta_yaml.to_string_lossy().to_string()),
-        };
+        let base = params
+            .relative_paths
+            .unwrap_or(false)
+            .then(|| repo_root(&self.conductor_dir));
+        let resp = file_paths(&track_dir, &self.read_options.plan_files, base);
 
         serde_json::to_string_pretty(&resp).unwrap_or_else(|e| format!("Error: {e}"))
     }
 }
 
+// ---------------------------------------------------------------------------
+// Shared helpers (also used by the dashboard's `--track` JSON mode)
+// ---------------------------------------------------------------------------
+
+/// Resolve a track by exact ID, falling back to a unique substring match.
+pub fn find_track<'a>(
+    tracks: &'a BTreeMap<TrackId, Track>,
+    query: &str,
+) -> Result<&'a Track, String> {
+    if let Some(track) = tracks.get(&TrackId::new(query)) {
+        return Ok(track);
+    }
+    let matches: Vec<&Track> = tracks
+        .values()
+        .filter(|t| t.id.as_str().contains(query))
+        .collect();
+    match matches.len() {
+        0 => Err(format!("No track found matching '{query}'")),
+        1 => Ok(matches[0]),
+        _ => {
+            let ids: Vec<&str> = matches.iter().map(|t| t.id.as_str()).collect();
+            Err(format!(
+                "Multiple tracks match '{}': {}. Please be more specific.",
+                query,
+                ids.join(", ")
+            ))
+        }
+    }
+}
+
+/// Score an actionable track: 10 points per priority level above none
+/// (Low 10 … Critical 40), 5 per track it unblocks, and 5 for work already
+/// under way.
+fn recommendation(track: &Track, impact: usize) -> TrackRecommendation {
+    let priority_points = 10 * (4 - track.priority as u32);
+    let impact_points = 5 * impact as u32;
+    let mut rationale = vec![
+        format!("{} priority (+{priority_points})", track.priority),
+        if track.dependencies.is_empty() {
+            "ready: no dependencies".to_string()
+        } else {
+            format!(
+                "ready: all {} dependencies complete",
+                track.dependencies.len()
+            )
+        },
+    ];
+    if impact > 0 {
+        let noun = if impact == 1 { "track" } else { "tracks" };
+        rationale.push(format!("unblocks {impact} {noun} (+{impact_points})"));
+    }
+    let mut score = priority_points + impact_points;
+    if track.status == Status::InProgress {
+        score += 5;
+        rationale.push("already in progress (+5)".to_string());
+    }
+    TrackRecommendation {
+        track_id: track.id.as_str().to_string(),
+        title: track.title.clone(),
+        status: format!("{}", track.status),
+        priority: format!("{}", track.priority),
+        impact,
+        score,
+        rationale,
+    }
+}
+
+/// Format a completed track as a changelog entry.
+fn changelog_entry(track: &Track) -> ChangelogEntry {
+    let mut entry = format!("- {}", track.title);
+    if let Some(branch) = &track.branch {
+        entry.push_str(&format!(" (`{branch}`)"));
+    }
+    if !track.tags.is_empty() {
+        entry.push_str(&format!(" [{}]", track.tags.join(", ")));
+    }
+    ChangelogEntry {
+        track_id: track.id.as_str().to_string(),
+        title: track.title.clone(),
+        tags: track.tags.clone(),
+        branch: track.branch.clone(),
+        completed_at: ConductorService::format_datetime(&track.updated_at),
+        entry,
+    }
+}
+
+/// Parse a UTC offset such as `+02:00`, `-0500`, `Z` or `UTC`.
+fn parse_utc_offset(s: &str) -> Option<FixedOffset> {
+    let s = s.trim();
+    if s.eq_ignore_ascii_case("z") || s.eq_ignore_ascii_case("utc") {
+        return Some(Utc.fix());
+    }
+    s.parse().ok()
+}
+
+/// Split a task ID `{track_id}#{phase_index}.{task_index}` into its parts.
+fn parse_task_id(task_id: &str) -> Option<(&str, usize, usize)> {
+    let (track_id, position) = task_id.rsplit_once('#')?;
+    let (phase, task) = position.split_once('.')?;
+    Some((track_id, phase.parse().ok()?, task.parse().ok()?))
+}
+
+/// Build the full detail response for a track whose files live under
+/// `tracks_dir`, its plan being the first of `plan_files` present. File
+/// paths are relative to `base` when given (see [`display_path`]).
+pub fn track_detail(
+    track: &Track,
+    tracks_dir: &Path,
+    plan_files: &[String],
+    base: Option<&Path>,
+) -> TrackDetailResponse {
+    TrackDetailResponse {
+        id: track.id.as_str().to_string(),
+        title: track.title.clone(),
+        status: format!("{}", track.status),
+        priority: format!("{}", track.priority),
+        track_type: format!("{}", track.track_type),
+        phase: track.phase.clone(),
+        progress_percent: track.progress_percent(),
+        tasks_completed: track.tasks_completed,
+        tasks_total: track.tasks_total,
+        raw_tasks_completed: track.raw_tasks_completed,
+        raw_tasks_total: track.raw_tasks_total,
+        tags: track.tags.clone(),
+        dependencies: track
+            .dependencies
+            .iter()
+            .map(|d| d.as_str().to_string())
+            .collect(),
+        external_blockers: track.external_blockers.clone(),
+        branch: track.branch.clone(),
+        description: track.description.clone(),
+        created_at: ConductorService::format_datetime(&track.created_at),
+        updated_at: ConductorService::format_datetime(&track.updated_at),
+        plan_phases: track
+            .plan_phases
+            .iter()
+            .map(ConductorService::phase_to_response)
+            .collect(),
+        file_paths: file_paths(&tracks_dir.join(track.dir_name()), plan_files, base),
+    }
+}
+
+/// Paths of a track folder and whichever track files exist in it; the plan
+/// is the first of `plan_files` present.
+fn file_paths(track_dir: &Path, plan_files: &[String], base: Option<&Path>) -> FilePathsResponse {
+    let existing = |name: &str| {
+        let path = track_dir.join(name);
+        path.exists().then(|| display_path(&path, base))
+    };
+    FilePathsResponse {
+        track_dir: display_path(track_dir, base),
+        plan_md: parser::find_plan_file(track_dir, plan_files)
+            .map(|path| display_path(&path, base)),
+        metadata_json: existing("metadata.json"),
+        meta_yaml: existing("meta.yaml"),
+        meta_toml: existing("meta.toml"),
+    }
+}
+
+/// `summary` in Prometheus text exposition format, one gauge per figure and
+/// one `conductor_tracks_by_status` sample per status.
+pub fn prometheus_metrics(summary: &SummaryResponse) -> String {
+    let by_status = &summary.by_status;
+    let mut out = String::new();
+    let mut gauge = |name: &str, help: &str, samples: &[(&str, String)]| {
+        out.push_str(&format!("# HELP {name} {help}\n# TYPE {name} gauge\n"));
+        for (labels, value) in samples {
+            out.push_str(&format!("{name}{labels} {value}\n"));
+        }
+    };
+    gauge(
+        "conductor_tracks_total",
+        "Number of tracks on the board.",
+        &[("", summary.total_tracks.to_string())],
+    );
+    gauge(
+        "conductor_tracks_by_status",
+        "Number of tracks in each status.",
+        &[
+            ("{status=\"new\"}", by_status.new.to_string()),
+            (
+                "{status=\"in_progress\"}",
+                by_status.in_progress.to_string(),
+            ),
+            ("{status=\"review\"}", by_status.review.to_string()),
+            ("{status=\"blocked\"}", by_status.blocked.to_string()),
+            ("{status=\"complete\"}", by_status.complete.to_string()),
+        ],
+    );
+    gauge(
+        "conductor_tasks_total",
+        "Plan tasks across all tracks.",
+        &[("", summary.total_tasks.to_string())],
+    );
+    gauge(
+        "conductor_tasks_completed",
+        "Completed plan tasks across all tracks.",
+        &[("", summary.total_tasks_completed.to_string())],
+    );
+    gauge(
+        "conductor_overall_progress",
+        "Completed tasks as a percentage of all tasks.",
+        &[("", format!("{:.2}", summary.overall_progress))],
+    );
+    out
+}
+
+/// The repository a conductor dir lives in: its parent directory.
+pub fn repo_root(conductor_dir: &Path) -> &Path {
+    conductor_dir.parent().unwrap_or(conductor_dir)
+}
+
+/// `path` relative to `base` when given and `path` lies under it; otherwise
+/// the path as is.
+pub fn display_path(path: &Path, base: Option<&Path>) -> String {
+    base.and_then(|base| path.strip_prefix(base).ok())
+        .unwrap_or(path)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Newest modification time, file count and total size under the conductor
+/// dir's index and track folders; a change in any means the snapshot is
+/// stale. The size catches edits landing in the same mtime tick as the load.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct SourceStamp {
+    newest: Option<SystemTime>,
+    files: usize,
+    bytes: u64,
+}
+
+impl SourceStamp {
+    fn read(tracks_dir: &Path, conductor_dir: &Path) -> Self {
+        let mut stamp = Self::default();
+        stamp.add_dir(conductor_dir);
+        if tracks_dir != conductor_dir {
+            stamp.add_dir(tracks_dir);
+        }
+        for entry in std::fs::read_dir(tracks_dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            if entry.file_type().is_ok_and(|t| t.is_dir()) {
+                stamp.add_dir(&entry.path());
+            }
+        }
+        stamp
+    }
+
+    /// Fold in `dir` itself and every entry directly inside it.
+    fn add_dir(&mut self, dir: &Path) {
+        self.add_path(dir);
+        for entry in std::fs::read_dir(dir).into_iter().flatten().flatten() {
+            self.files += 1;
+            self.add_path(&entry.path());
+        }
+    }
+
+    fn add_path(&mut self, path: &Path) {
+        let Ok(metadata) = std::fs::metadata(path) else {
+            return;
+        };
+        self.newest = self.newest.max(metadata.modified().ok());
+        if metadata.is_file() {
+            self.bytes += metadata.len();
+        }
+    }
+}
+
 #[tool_handler]
 impl ServerHandler for ConductorService {
     fn get_info(&self) -> ServerInfo {
+        let access = if self.allow_write {
+            "read-write access to track progress, statuses, plans, dependencies, and tasks. \
+             set_task_done ticks or unticks a plan task by its ID from get_all_tasks; \
+             complete_track marks a whole track complete."
+        } else {
+            "read-only access to track progress, statuses, plans, dependencies, and tasks. \
+             Write tools such as set_task_done and complete_track are disabled unless the server is started \
+             with --allow-write."
+        };
         ServerInfo {
-            instructions: Some(
-                "Conductor Dashboard MCP Server — read-only access to track progress, \
-                 statuses, plans, dependencies, and tasks. Use list_tracks to see all tracks, \
+            instructions: Some(format!(
+                "Conductor Dashboard MCP Server — {access} Use list_tracks to see all tracks, \
                  get_track_detail for full info on a specific track, and get_summary for \
-                 aggregate stats."
-                    .into(),
-            ),
+                 aggregate stats (get_metrics gives them in Prometheus format)."
+            )),
             capabilities: ServerCapabilities::builder().enable_tools().build(),
             ..Default::default()
         }
@@ -438,7 +1260,7 @@ mod tests {
     #[test]
     fn test_service_loads() {
         let service = ConductorService::new(&conductor_dir()).expect("should load tracks");
-        assert!(!service.tracks.is_empty(), "should have tracks");
+        assert!(!service.tracks().is_empty(), "should have tracks");
     }
 
     #[test]
@@ -487,9 +1309,10 @@ mod tests {
     fn test_get_track_detail_existing() {
         let service = ConductorService::new(&conductor_dir()).unwrap();
         // Use the first track ID
-        let first_id = service.tracks.keys().next().unwrap().as_str().to_string();
+        let first_id = service.tracks().keys().next().unwrap().as_str().to_string();
         let params = GetTrackDetailParams {
             track_id: first_id.clone(),
+            relative_paths: None,
         };
         let result = service.get_track_detail(Parameters(params));
         let parsed: TrackDetailResponse =
@@ -502,6 +1325,7 @@ mod tests {
         let service = ConductorService::new(&conductor_dir()).unwrap();
         let params = GetTrackDetailParams {
             track_id: "nonexistent_track_xyz".into(),
+            relative_paths: None,
         };
         let result = service.get_track_detail(Parameters(params));
         assert!(result.contains("No track found"));
@@ -511,7 +1335,8 @@ mod tests {
     fn test_search_tracks() {
         let service = ConductorService::new(&conductor_dir()).unwrap();
         // Search for something we know should exist
-        let first_track = service.tracks.values().next().unwrap();
+        let tracks = service.tracks();
+        let first_track = tracks.values().next().unwrap();
         let word = first_track
             .title
             .split_whitespace()
@@ -532,7 +1357,18 @@ mod tests {
         let result = service.get_track_dependencies(Parameters(params));
         let parsed: Vec<DependencyInfo> =
             serde_json::from_str(&result).expect("should be valid JSON");
-        assert_eq!(parsed.len(), service.tracks.len());
+        assert_eq!(parsed.len(), service.tracks().len());
+    }
+
+    #[test]
+    fn test_get_dependency_cycles() {
+        let service = ConductorService::new(&conductor_dir()).unwrap();
+        let parsed: Vec<Vec<String>> =
+            serde_json::from_str(&service.get_dependency_cycles()).expect("should be valid JSON");
+        assert_eq!(
+            parsed.len(),
+            crate::model::detect_cycles(&service.tracks()).len()
+        );
     }
 
     #[test]
@@ -543,7 +1379,7 @@ mod tests {
             serde_json::from_str(&result).expect("should be valid JSON");
         // Should have some outstanding tasks (unless all tracks are complete)
         let has_incomplete = service
-            .tracks
+            .tracks()
             .values()
             .any(|t| t.status != Status::Complete && t.tasks_total > t.tasks_completed);
         if has_incomplete {
@@ -554,8 +1390,11 @@ mod tests {
     #[test]
     fn test_get_track_file_paths() {
         let service = ConductorService::new(&conductor_dir()).unwrap();
-        let first_id = service.tracks.keys().next().unwrap().as_str().to_string();
-        let params = GetTrackFilePathsParams { track_id: first_id };
+        let first_id = service.tracks().keys().next().unwrap().as_str().to_string();
+        let params = GetTrackFilePathsParams {
+            track_id: first_id,
+            relative_paths: None,
+        };
         let result = service.get_track_file_paths(Parameters(params));
         let parsed: FilePathsResponse =
             serde_json::from_str(&result).expect("should be valid JSON");
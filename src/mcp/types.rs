@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
@@ -6,10 +8,10 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct ListTracksParams {
-    /// Filter by status: "new", "in_progress", "blocked", "complete", or "all" (default)
+    /// Filter by status: "new", "in_progress", "review", "blocked", "complete", or "all" (default)
     #[schemars(default)]
     pub status: Option<String>,
-    /// Sort by: "updated" (default) or "progress"
+    /// Sort by: "updated" (default), "progress", or "type" (bugs, features, refactors, migrations, other; then title)
     #[schemars(default)]
     pub sort: Option<String>,
 }
@@ -18,6 +20,17 @@ pub struct ListTracksParams {
 pub struct GetTrackDetailParams {
     /// The track ID (directory name), e.g. "otel_observability_20260210"
     pub track_id: String,
+    /// Give file paths relative to the repo root (the conductor dir's parent) instead of absolute
+    #[schemars(default)]
+    pub relative_paths: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetPlanPhaseParams {
+    /// The track ID (directory name)
+    pub track_id: String,
+    /// Phase name (case-insensitive) or 0-based phase index, e.g. "Phase 2" or "1"
+    pub phase: String,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -45,10 +58,67 @@ pub struct GetTracksByPriorityParams {
     pub priority: String,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetTracksByPhaseStatusParams {
+    /// Phase status: "pending", "active", "complete", or "blocked"
+    pub phase_status: String,
+    /// Only match phases whose name contains this substring (case-insensitive)
+    #[schemars(default)]
+    pub phase_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetChangelogParams {
+    /// Only include tracks completed after this date, e.g. "2026-02-01" (default: all)
+    #[schemars(default)]
+    pub since: Option<String>,
+    /// Group entries by "tag" or "priority" (default: one ungrouped list)
+    #[schemars(default)]
+    pub group_by: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetTracksByDateParams {
+    /// Calendar day, e.g. "2026-02-10"
+    pub date: String,
+    /// "created" (created_at) or "completed" (updated_at of a complete track)
+    pub field: String,
+    /// UTC offset the day is taken in, e.g. "+02:00" or "-05:00" (default: UTC)
+    #[schemars(default)]
+    pub utc_offset: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct RecommendNextTrackParams {
+    /// Maximum number of recommendations (default: 3)
+    #[schemars(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SetTaskDoneParams {
+    /// Task ID from get_all_tasks, e.g. "my_track_20260101#1.0"
+    pub task_id: String,
+    /// true to tick the task, false to untick it
+    pub done: bool,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct CompleteTrackParams {
+    /// The track ID (directory name)
+    pub track_id: String,
+    /// Also tick every unticked task in plan.md (default: false)
+    #[schemars(default)]
+    pub tick_all_tasks: Option<bool>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GetTrackFilePathsParams {
     /// The track ID
     pub track_id: String,
+    /// Give paths relative to the repo root (the conductor dir's parent) instead of absolute
+    #[schemars(default)]
+    pub relative_paths: Option<bool>,
 }
 
 // ---------------------------------------------------------------------------
@@ -81,8 +151,16 @@ pub struct TrackDetailResponse {
     pub progress_percent: f32,
     pub tasks_completed: usize,
     pub tasks_total: usize,
+    /// Ticked plan tasks before complete tracks are normalised to 100%
+    #[serde(default)]
+    pub raw_tasks_completed: usize,
+    #[serde(default)]
+    pub raw_tasks_total: usize,
     pub tags: Vec<String>,
     pub dependencies: Vec<String>,
+    /// Blockers that aren't tracks, e.g. "SAP upgrade"
+    #[serde(default)]
+    pub external_blockers: Vec<String>,
     pub branch: Option<String>,
     pub description: Option<String>,
     pub created_at: Option<String>,
@@ -99,6 +177,9 @@ pub struct PhaseResponse {
     pub tasks_total: usize,
     pub progress_percent: f32,
     pub tasks: Vec<TaskResponse>,
+    /// 0 for a top-level phase, 1 for a sub-phase nested under the previous one.
+    #[serde(default)]
+    pub depth: usize,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -114,12 +195,25 @@ pub struct SummaryResponse {
     pub overall_progress: f32,
     pub total_tasks: usize,
     pub total_tasks_completed: usize,
+    /// Unfinished tasks by the priority of their track.
+    #[serde(default)]
+    pub outstanding_tasks_by_priority: PriorityCounts,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PriorityCounts {
+    pub critical: usize,
+    pub high: usize,
+    pub medium: usize,
+    pub low: usize,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StatusCounts {
     pub new: usize,
     pub in_progress: usize,
+    #[serde(default)]
+    pub review: usize,
     pub blocked: usize,
     pub complete: usize,
 }
@@ -141,10 +235,114 @@ pub struct OutstandingTask {
     pub task: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskEntry {
+    /// Stable task ID: `{track_id}#{phase_index}.{task_index}` (0-based)
+    pub id: String,
+    pub track_id: String,
+    pub track_title: String,
+    pub phase: String,
+    pub done: bool,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CompleteTrackResponse {
+    pub track_id: String,
+    pub status: String,
+    /// Metadata file whose status field was edited
+    pub metadata_file: String,
+    /// Plan tasks ticked by this call (0 unless tick_all_tasks was set)
+    pub tasks_ticked: usize,
+    /// Ticked / total tasks in plan.md afterwards
+    pub plan_tasks_completed: usize,
+    pub plan_tasks_total: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TrackPhaseMatch {
+    pub track_id: String,
+    pub track_title: String,
+    pub status: String,
+    pub phases: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TrackImpact {
+    pub track_id: String,
+    pub title: String,
+    pub status: String,
+    /// Number of tracks transitively waiting on this one
+    pub impact: usize,
+    /// Tracks that depend on this one directly
+    pub blocks: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TrackRecommendation {
+    pub track_id: String,
+    pub title: String,
+    pub status: String,
+    pub priority: String,
+    /// Number of tracks transitively waiting on this one
+    pub impact: usize,
+    /// Higher is better; see `rationale` for how it was reached
+    pub score: u32,
+    /// One line per scoring factor, e.g. "HIGH priority (+30)"
+    pub rationale: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OverdueTask {
+    pub track_id: String,
+    pub track_title: String,
+    pub phase: String,
+    pub task: String,
+    pub due: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChangelogEntry {
+    pub track_id: String,
+    pub title: String,
+    pub tags: Vec<String>,
+    pub branch: Option<String>,
+    pub completed_at: Option<String>,
+    /// Markdown bullet ready for release notes, e.g. "- Add tracing (`feat/otel`) [otel]"
+    pub entry: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChangelogGroup {
+    /// Tag or priority label; "all" when ungrouped, "untagged" for tracks without tags
+    pub group: String,
+    pub entries: Vec<ChangelogEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemainingEffortResponse {
+    pub total_remaining: f32,
+    pub by_priority: BTreeMap<String, f32>,
+    pub tracks_counted: usize,
+    pub tracks_missing_effort: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    /// "missing_plan", "unresolved_dependency", "duplicate_id",
+    /// "status_mismatch", "orphan_directory", "task_count_mismatch",
+    /// "invalid_metadata", or "shadowed_metadata"
+    pub kind: String,
+    pub track_id: String,
+    pub message: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FilePathsResponse {
     pub track_dir: String,
     pub plan_md: Option<String>,
     pub metadata_json: Option<String>,
     pub meta_yaml: Option<String>,
+    #[serde(default)]
+    pub meta_toml: Option<String>,
 }
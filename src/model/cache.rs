@@ -16,9 +16,19 @@ pub enum ReloadScope {
 }
 
 /// Caches file modification times to enable incremental reloading.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct TrackCache {
     mtimes: HashMap<PathBuf, SystemTime>,
+    /// Directory (relative to the conductor dir) that holds track folders.
+    tracks_subdir: PathBuf,
+    /// Plan file names besides `plan.md` whose changes reload their track.
+    plan_files: Vec<String>,
+}
+
+impl Default for TrackCache {
+    fn default() -> Self {
+        Self::with_tracks_subdir("tracks")
+    }
 }
 
 impl TrackCache {
@@ -26,6 +36,21 @@ impl TrackCache {
         Self::default()
     }
 
+    /// A cache for track folders living under `tracks_subdir` instead of `tracks`.
+    pub fn with_tracks_subdir(tracks_subdir: &str) -> Self {
+        Self {
+            mtimes: HashMap::new(),
+            tracks_subdir: PathBuf::from(tracks_subdir),
+            plan_files: Vec::new(),
+        }
+    }
+
+    /// Also treat changes to these plan file names as track changes.
+    pub fn with_plan_files(mut self, plan_files: Vec<String>) -> Self {
+        self.plan_files = plan_files;
+        self
+    }
+
     /// Classify a set of changed file paths into a ReloadScope.
     pub fn classify_changes(&self, paths: &[PathBuf]) -> ReloadScope {
         let mut changed_tracks = Vec::new();
@@ -37,12 +62,11 @@ impl TrackCache {
                     "tracks.md" => {
                         full_reload = true;
                     }
-                    "metadata.json" | "meta.yaml" | "plan.md" | "spec.md" => {
-                        if let Some(track_id) = extract_track_id_from_path(path) {
-                            if !changed_tracks.contains(&track_id) {
-                                changed_tracks.push(track_id);
-                            }
-                        }
+                    "metadata.json" | "meta.yaml" | "meta.toml" | "plan.md" | "spec.md" => {
+                        self.note_track_change(path, &mut changed_tracks);
+                    }
+                    name if self.plan_files.iter().any(|p| p == name) => {
+                        self.note_track_change(path, &mut changed_tracks);
                     }
                     _ => {}
                 }
@@ -56,6 +80,15 @@ impl TrackCache {
         }
     }
 
+    /// Add the track owning `path` to `changed_tracks`, once.
+    fn note_track_change(&self, path: &Path, changed_tracks: &mut Vec<TrackId>) {
+        if let Some(track_id) = extract_track_id_from_path(path, &self.tracks_subdir) {
+            if !changed_tracks.contains(&track_id) {
+                changed_tracks.push(track_id);
+            }
+        }
+    }
+
     /// Update cached mtime for a path.
     pub fn update_mtime(&mut self, path: &Path) {
         if let Ok(metadata) = std::fs::metadata(path) {
@@ -78,13 +111,15 @@ impl TrackCache {
 }
 
 /// Extract a TrackId from a file path like `.../tracks/some_track_id/plan.md`
-fn extract_track_id_from_path(path: &Path) -> Option<TrackId> {
+fn extract_track_id_from_path(path: &Path, tracks_subdir: &Path) -> Option<TrackId> {
     let parent = path.parent()?;
     let track_dir_name = parent.file_name()?.to_str()?;
 
-    // Verify the grandparent is "tracks"
+    // Verify the grandparent is the tracks subdirectory. Track folders placed
+    // directly in the conductor dir ("" or ".") accept any grandparent.
     let grandparent = parent.parent()?;
-    if grandparent.file_name()?.to_str()? == "tracks" {
+    let flat = tracks_subdir.as_os_str().is_empty() || tracks_subdir == Path::new(".");
+    if flat || grandparent.ends_with(tracks_subdir) {
         Some(TrackId::new(track_dir_name))
     } else {
         None
@@ -146,13 +181,44 @@ mod tests {
     #[test]
     fn test_extract_track_id() {
         let path = PathBuf::from("/project/conductor/tracks/my_track_123/plan.md");
-        let id = extract_track_id_from_path(&path).unwrap();
+        let id = extract_track_id_from_path(&path, Path::new("tracks")).unwrap();
         assert_eq!(id.as_str(), "my_track_123");
     }
 
     #[test]
     fn test_extract_track_id_not_in_tracks_dir() {
         let path = PathBuf::from("/project/some_other/my_track/plan.md");
-        assert!(extract_track_id_from_path(&path).is_none());
+        assert!(extract_track_id_from_path(&path, Path::new("tracks")).is_none());
+    }
+
+    #[test]
+    fn test_classify_custom_tracks_subdir() {
+        let cache = TrackCache::with_tracks_subdir("work/streams");
+        let paths = vec![
+            PathBuf::from("/project/conductor/work/streams/track_a/plan.md"),
+            PathBuf::from("/project/conductor/tracks/track_b/plan.md"),
+        ];
+        match cache.classify_changes(&paths) {
+            ReloadScope::Tracks(ids) => {
+                assert_eq!(ids, vec![TrackId::new("track_a")]);
+            }
+            _ => panic!("expected Tracks scope"),
+        }
+    }
+
+    #[test]
+    fn test_classify_configured_plan_file() {
+        let paths = vec![PathBuf::from("/project/conductor/tracks/track_a/tasks.md")];
+        assert!(matches!(
+            TrackCache::new().classify_changes(&paths),
+            ReloadScope::Tracks(ids) if ids.is_empty()
+        ));
+        let cache = TrackCache::new().with_plan_files(vec!["tasks.md".to_string()]);
+        match cache.classify_changes(&paths) {
+            ReloadScope::Tracks(ids) => {
+                assert_eq!(ids, vec![TrackId::new("track_a")]);
+            }
+            _ => panic!("expected Tracks scope"),
+        }
     }
 }
@@ -11,19 +11,42 @@ pub enum Status {
     #[default]
     New,
     InProgress,
+    /// Work is done but awaiting review / QA; not yet complete.
+    Review,
     Blocked,
     Complete,
 }
 
 impl Status {
+    /// Every status, in lifecycle order (as listed in the help legend).
+    pub const ALL: [Self; 5] = [
+        Self::New,
+        Self::InProgress,
+        Self::Review,
+        Self::Blocked,
+        Self::Complete,
+    ];
+
     pub fn label(self) -> &'static str {
         match self {
             Self::New => "New",
             Self::InProgress => "Active",
+            Self::Review => "Review",
             Self::Blocked => "Blocked",
             Self::Complete => "Complete",
         }
     }
+
+    /// Symbol shown beside the status in the list and detail panel.
+    pub fn glyph(self) -> &'static str {
+        match self {
+            Self::New => "○",
+            Self::InProgress => "⚙",
+            Self::Review => "◎",
+            Self::Blocked => "⚠",
+            Self::Complete => "✓",
+        }
+    }
 }
 
 impl fmt::Display for Status {
@@ -49,6 +72,7 @@ impl Serialize for Status {
         s.serialize_str(match self {
             Self::New => "new",
             Self::InProgress => "in_progress",
+            Self::Review => "review",
             Self::Blocked => "blocked",
             Self::Complete => "complete",
         })
@@ -58,13 +82,14 @@ impl Serialize for Status {
 impl Status {
     /// Parse a status string leniently.  Handles all observed variants:
     /// `"not_started"`, `"new"`, `"in_progress"`, `"complete"`, `"completed"`,
-    /// `"blocked"`, `"planning"`, `"planned"`, etc.
+    /// `"blocked"`, `"review"`, `"testing"`, `"planning"`, `"planned"`, etc.
     pub fn from_str_loose(s: &str) -> Self {
         let lower = s.to_ascii_lowercase();
         let lower = lower.trim();
         match lower {
             "complete" | "completed" | "done" => Self::Complete,
             "in_progress" | "in-progress" | "active" | "implementation" => Self::InProgress,
+            "review" | "in_review" | "in-review" | "testing" | "qa" => Self::Review,
             "blocked" | "on_hold" => Self::Blocked,
             _ => Self::New, // not_started, new, planning, planned, etc.
         }
@@ -85,6 +110,9 @@ pub enum Priority {
 }
 
 impl Priority {
+    /// Every priority, most urgent first.
+    pub const ALL: [Self; 4] = [Self::Critical, Self::High, Self::Medium, Self::Low];
+
     pub fn label(self) -> &'static str {
         match self {
             Self::Critical => "CRITICAL",
@@ -148,6 +176,15 @@ pub enum CheckboxStatus {
 }
 
 impl CheckboxStatus {
+    /// The raw markdown marker as written in tracks.md.
+    pub fn marker(self) -> &'static str {
+        match self {
+            Self::Unchecked => "[ ]",
+            Self::InProgress => "[~]",
+            Self::Checked => "[x]",
+        }
+    }
+
     /// Map checkbox to a Status, used as a fallback when metadata is missing.
     pub fn to_status(self) -> Status {
         match self {
@@ -173,6 +210,18 @@ pub enum PhaseStatus {
 }
 
 impl PhaseStatus {
+    pub const ALL: [Self; 4] = [Self::Pending, Self::Active, Self::Complete, Self::Blocked];
+
+    /// Symbol shown before the phase name in the plan view.
+    pub fn glyph(self) -> &'static str {
+        match self {
+            Self::Pending => "○",
+            Self::Active => "◐",
+            Self::Complete => "●",
+            Self::Blocked => "⊘",
+        }
+    }
+
     pub fn label(self) -> &'static str {
         match self {
             Self::Pending => "Pending",
@@ -214,6 +263,18 @@ impl TrackType {
         }
     }
 
+    /// Position when grouping tracks by type: bugs first for triage,
+    /// untyped tracks last.
+    pub fn sort_rank(&self) -> u8 {
+        match self {
+            Self::Bug => 0,
+            Self::Feature => 1,
+            Self::Refactor => 2,
+            Self::Migration => 3,
+            Self::Other => 4,
+        }
+    }
+
     pub fn from_str_loose(s: &str) -> Self {
         let lower = s.to_ascii_lowercase();
         match lower.trim() {
@@ -263,6 +324,7 @@ pub enum FilterMode {
     #[default]
     All,
     Active,
+    Review,
     Blocked,
     Complete,
     New,
@@ -272,7 +334,8 @@ impl FilterMode {
     pub fn next(self) -> Self {
         match self {
             Self::All => Self::Active,
-            Self::Active => Self::Blocked,
+            Self::Active => Self::Review,
+            Self::Review => Self::Blocked,
             Self::Blocked => Self::Complete,
             Self::Complete => Self::New,
             Self::New => Self::All,
@@ -283,6 +346,7 @@ impl FilterMode {
         match self {
             Self::All => "All",
             Self::Active => "Active",
+            Self::Review => "Review",
             Self::Blocked => "Blocked",
             Self::Complete => "Done",
             Self::New => "New",
@@ -290,18 +354,149 @@ impl FilterMode {
     }
 }
 
+/// Narrow the list by whether a track has a parsed plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlanFilter {
+    #[default]
+    Any,
+    WithPlan,
+    WithoutPlan,
+}
+
+impl PlanFilter {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Any => Self::WithPlan,
+            Self::WithPlan => Self::WithoutPlan,
+            Self::WithoutPlan => Self::Any,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Any => "Any",
+            Self::WithPlan => "Has plan",
+            Self::WithoutPlan => "No plan",
+        }
+    }
+
+    /// Whether a track with (`has_plan`) or without a plan passes.
+    pub fn matches(self, has_plan: bool) -> bool {
+        match self {
+            Self::Any => true,
+            Self::WithPlan => has_plan,
+            Self::WithoutPlan => !has_plan,
+        }
+    }
+}
+
+/// Narrow the list to tracks of one type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TypeFilter {
+    #[default]
+    All,
+    Feature,
+    Bug,
+    Migration,
+    Refactor,
+    Other,
+}
+
+impl TypeFilter {
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::Feature,
+            Self::Feature => Self::Bug,
+            Self::Bug => Self::Migration,
+            Self::Migration => Self::Refactor,
+            Self::Refactor => Self::Other,
+            Self::Other => Self::All,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::All => "All",
+            Self::Feature => "Feature",
+            Self::Bug => "Bug",
+            Self::Migration => "Migration",
+            Self::Refactor => "Refactor",
+            Self::Other => "Other",
+        }
+    }
+
+    pub fn matches(self, track_type: &TrackType) -> bool {
+        match self {
+            Self::All => true,
+            Self::Feature => *track_type == TrackType::Feature,
+            Self::Bug => *track_type == TrackType::Bug,
+            Self::Migration => *track_type == TrackType::Migration,
+            Self::Refactor => *track_type == TrackType::Refactor,
+            Self::Other => *track_type == TrackType::Other,
+        }
+    }
+}
+
+/// How a multi-tag filter combines its tags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TagMatch {
+    /// Track has at least one of the selected tags (OR)
+    #[default]
+    Any,
+    /// Track has every selected tag (AND)
+    All,
+}
+
+impl TagMatch {
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Any => Self::All,
+            Self::All => Self::Any,
+        }
+    }
+
+    /// Operator shown between selected tags.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Any => "OR",
+            Self::All => "AND",
+        }
+    }
+
+    /// Whether a track with `tags` passes a filter on `selected` (compared
+    /// case-insensitively). An empty selection matches every track.
+    pub fn matches<'a>(
+        self,
+        selected: impl IntoIterator<Item = &'a String>,
+        tags: &[String],
+    ) -> bool {
+        let mut selected = selected.into_iter().peekable();
+        if selected.peek().is_none() {
+            return true;
+        }
+        let has = |tag: &String| tags.iter().any(|t| t.eq_ignore_ascii_case(tag));
+        match self {
+            Self::Any => selected.any(has),
+            Self::All => selected.all(has),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SortMode {
     #[default]
     Updated,
     Progress,
+    /// Grouped by track type (see [`TrackType::sort_rank`]).
+    Type,
 }
 
 impl SortMode {
     pub fn next(self) -> Self {
         match self {
             Self::Updated => Self::Progress,
-            Self::Progress => Self::Updated,
+            Self::Progress => Self::Type,
+            Self::Type => Self::Updated,
         }
     }
 
@@ -309,6 +504,38 @@ impl SortMode {
         match self {
             Self::Updated => "Recent",
             Self::Progress => "Progress",
+            Self::Type => "Type",
+        }
+    }
+}
+
+/// Order of the outstanding-tasks view (`W`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskOrder {
+    /// Most urgent track first, then list order, then plan order.
+    #[default]
+    Priority,
+    /// Grouped by track title, in plan order within each track.
+    Track,
+    /// Earliest phase first across all tracks, so every track's first phase
+    /// comes before any second phase.
+    Phase,
+}
+
+impl TaskOrder {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Priority => Self::Track,
+            Self::Track => Self::Phase,
+            Self::Phase => Self::Priority,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Priority => "Priority",
+            Self::Track => "Track",
+            Self::Phase => "Phase",
         }
     }
 }
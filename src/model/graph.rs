@@ -0,0 +1,328 @@
+//! Dependency graph queries over the track set.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Write;
+
+use super::enums::Status;
+use super::track::{Track, TrackId};
+
+/// Reverse dependency map: track → tracks that list it as a dependency.
+pub fn dependents_map(tracks: &BTreeMap<TrackId, Track>) -> BTreeMap<TrackId, Vec<TrackId>> {
+    let mut dependents: BTreeMap<TrackId, Vec<TrackId>> = BTreeMap::new();
+    for track in tracks.values() {
+        for dep in &track.dependencies {
+            dependents
+                .entry(dep.clone())
+                .or_default()
+                .push(track.id.clone());
+        }
+    }
+    dependents
+}
+
+/// All tracks transitively waiting on `id` (excluding `id` itself).
+/// Cycles are tolerated: each track is visited at most once.
+pub fn downstream_dependents(
+    dependents: &BTreeMap<TrackId, Vec<TrackId>>,
+    id: &TrackId,
+) -> BTreeSet<TrackId> {
+    let mut seen = BTreeSet::new();
+    let mut queue: VecDeque<&TrackId> = VecDeque::from([id]);
+    while let Some(current) = queue.pop_front() {
+        for next in dependents.get(current).into_iter().flatten() {
+            if next != id && seen.insert(next.clone()) {
+                queue.push_back(next);
+            }
+        }
+    }
+    seen
+}
+
+/// Upstream dependencies of `id` in depth-first order, as `(depth, track)`
+/// with direct dependencies at depth 0. Each track is expanded once, so
+/// shared dependencies and cycles don't repeat.
+pub fn dependency_tree(tracks: &BTreeMap<TrackId, Track>, id: &TrackId) -> Vec<(usize, TrackId)> {
+    let deps_of = |of: &TrackId| {
+        tracks
+            .get(of)
+            .map(|t| t.dependencies.as_slice())
+            .unwrap_or(&[])
+    };
+    let mut out = Vec::new();
+    let mut seen = BTreeSet::from([id.clone()]);
+    let mut stack: Vec<(usize, &TrackId)> = deps_of(id).iter().rev().map(|d| (0, d)).collect();
+    while let Some((depth, dep)) = stack.pop() {
+        if !seen.insert(dep.clone()) {
+            continue;
+        }
+        out.push((depth, dep.clone()));
+        stack.extend(deps_of(dep).iter().rev().map(|d| (depth + 1, d)));
+    }
+    out
+}
+
+/// Dependencies of `track` that are not complete or are unknown, nearest
+/// first, following each unmet one upstream. A complete dependency
+/// satisfies everything behind it, so its own dependencies aren't visited.
+pub fn blocking_dependencies(tracks: &BTreeMap<TrackId, Track>, track: &Track) -> Vec<TrackId> {
+    let mut out = Vec::new();
+    let mut seen = BTreeSet::from([track.id.clone()]);
+    let mut queue: VecDeque<&TrackId> = track.dependencies.iter().collect();
+    while let Some(dep) = queue.pop_front() {
+        if !seen.insert(dep.clone()) {
+            continue;
+        }
+        match tracks.get(dep) {
+            Some(track) if track.is_complete() => {}
+            Some(track) => {
+                out.push(dep.clone());
+                queue.extend(&track.dependencies);
+            }
+            None => out.push(dep.clone()),
+        }
+    }
+    out
+}
+
+/// Direct dependencies of `track` that are not complete. Dependencies on
+/// unknown tracks count as unmet. A track with none is ready to start.
+pub fn unmet_dependencies<'a>(
+    tracks: &BTreeMap<TrackId, Track>,
+    track: &'a Track,
+) -> Vec<&'a TrackId> {
+    track
+        .dependencies
+        .iter()
+        .filter(|dep| !tracks.get(dep).is_some_and(Track::is_complete))
+        .collect()
+}
+
+/// Direct edge counts per track: `(depends_on, blocks)`, i.e. how many
+/// tracks it lists as dependencies and how many list it.
+pub fn edge_counts(tracks: &BTreeMap<TrackId, Track>) -> BTreeMap<TrackId, (usize, usize)> {
+    let dependents = dependents_map(tracks);
+    tracks
+        .values()
+        .map(|t| {
+            let blocks = dependents.get(&t.id).map_or(0, Vec::len);
+            (t.id.clone(), (t.dependencies.len(), blocks))
+        })
+        .collect()
+}
+
+/// Number of tracks transitively waiting on each track.
+pub fn track_impact(tracks: &BTreeMap<TrackId, Track>) -> BTreeMap<TrackId, usize> {
+    let dependents = dependents_map(tracks);
+    tracks
+        .keys()
+        .map(|id| (id.clone(), downstream_dependents(&dependents, id).len()))
+        .collect()
+}
+
+/// Dependency cycles, found by depth-first search with a recursion stack.
+/// Each cycle lists its tracks in dependency order starting from the
+/// smallest ID (`[a, b, c]` is a → b → c → a; a self-loop is `[a]`). There
+/// is a result exactly when the graph has a loop, though loops that share
+/// tracks may be reported as one. Dependencies on unknown tracks are
+/// ignored.
+pub fn detect_cycles(tracks: &BTreeMap<TrackId, Track>) -> Vec<Vec<TrackId>> {
+    fn visit<'a>(
+        tracks: &'a BTreeMap<TrackId, Track>,
+        id: &'a TrackId,
+        stack: &mut Vec<&'a TrackId>,
+        done: &mut BTreeSet<&'a TrackId>,
+        cycles: &mut BTreeSet<Vec<TrackId>>,
+    ) {
+        stack.push(id);
+        for dep in tracks
+            .get(id)
+            .map(|t| t.dependencies.as_slice())
+            .unwrap_or(&[])
+        {
+            if !tracks.contains_key(dep) || done.contains(dep) {
+                continue;
+            }
+            match stack.iter().position(|on_stack| *on_stack == dep) {
+                Some(start) => {
+                    let mut cycle: Vec<TrackId> =
+                        stack[start..].iter().map(|&t| t.clone()).collect();
+                    let smallest = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap_or(0);
+                    cycle.rotate_left(smallest);
+                    cycles.insert(cycle);
+                }
+                None => visit(tracks, dep, stack, done, cycles),
+            }
+        }
+        stack.pop();
+        done.insert(id);
+    }
+
+    let mut cycles = BTreeSet::new();
+    let mut done = BTreeSet::new();
+    for id in tracks.keys() {
+        if !done.contains(id) {
+            visit(tracks, id, &mut Vec::new(), &mut done, &mut cycles);
+        }
+    }
+    cycles.into_iter().collect()
+}
+
+/// Render the dependency graph as Graphviz DOT. Nodes are labelled with
+/// track titles and filled by status; edges point from a track to each of
+/// its dependencies. Complete tracks are drawn dashed and greyed out.
+pub fn to_dot(tracks: &BTreeMap<TrackId, Track>) -> String {
+    let mut out = String::from("digraph conductor {\n");
+    out.push_str("    rankdir=LR;\n");
+    out.push_str("    node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+
+    for track in tracks.values() {
+        let title = if track.title.is_empty() {
+            track.id.as_str()
+        } else {
+            &track.title
+        };
+        let (fill, extra) = match track.status {
+            Status::Complete => (
+                "#d9d9d9",
+                ", style=\"rounded,filled,dashed\", fontcolor=\"#666666\"",
+            ),
+            Status::InProgress => ("#fff2cc", ""),
+            Status::Review => ("#d9e8fb", ""),
+            Status::Blocked => ("#f4cccc", ""),
+            Status::New => ("#ffffff", ""),
+        };
+        let _ = writeln!(
+            out,
+            "    \"{}\" [label=\"{}\", fillcolor=\"{fill}\"{extra}];",
+            dot_escape(track.id.as_str()),
+            dot_escape(title),
+        );
+    }
+
+    for track in tracks.values() {
+        for dep in &track.dependencies {
+            let _ = writeln!(
+                out,
+                "    \"{}\" -> \"{}\";",
+                dot_escape(track.id.as_str()),
+                dot_escape(dep.as_str()),
+            );
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracks(edges: &[(&str, &[&str])]) -> BTreeMap<TrackId, Track> {
+        edges
+            .iter()
+            .map(|(id, deps)| {
+                let track = Track {
+                    id: TrackId::new(*id),
+                    dependencies: deps.iter().map(|d| TrackId::new(*d)).collect(),
+                    ..Track::default()
+                };
+                (track.id.clone(), track)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_impact_counts_transitive_dependents() {
+        // a <- b <- c, a <- d
+        let t = tracks(&[("a", &[]), ("b", &["a"]), ("c", &["b"]), ("d", &["a"])]);
+        let impact = track_impact(&t);
+        assert_eq!(impact[&TrackId::new("a")], 3);
+        assert_eq!(impact[&TrackId::new("b")], 1);
+        assert_eq!(impact[&TrackId::new("c")], 0);
+    }
+
+    #[test]
+    fn test_to_dot_nodes_and_edges() {
+        let mut t = tracks(&[("a", &[]), ("b", &["a"])]);
+        let a = t.get_mut(&TrackId::new("a")).unwrap();
+        a.title = "Say \"hi\"".to_string();
+        a.status = Status::Complete;
+        t.get_mut(&TrackId::new("b")).unwrap().title = "Bee".to_string();
+
+        let dot = to_dot(&t);
+        assert!(dot.starts_with("digraph conductor {"));
+        assert!(dot.contains(
+            r##"    "a" [label="Say \"hi\"", fillcolor="#d9d9d9", style="rounded,filled,dashed", fontcolor="#666666"];"##
+        ));
+        assert!(dot.contains(r##"    "b" [label="Bee", fillcolor="#ffffff"];"##));
+        assert!(dot.contains(r#"    "b" -> "a";"#));
+        assert!(!dot.contains(r#""a" -> "#));
+    }
+
+    #[test]
+    fn test_dependency_tree_depth_first() {
+        // d -> (b -> a, c -> a), a -> d closes a cycle
+        let t = tracks(&[
+            ("a", &["d"]),
+            ("b", &["a"]),
+            ("c", &["a"]),
+            ("d", &["b", "c"]),
+        ]);
+        let tree = dependency_tree(&t, &TrackId::new("d"));
+        let tree: Vec<(usize, &str)> = tree
+            .iter()
+            .map(|(depth, id)| (*depth, id.as_str()))
+            .collect();
+        assert_eq!(tree, vec![(0, "b"), (1, "a"), (0, "c")]);
+        assert!(dependency_tree(&t, &TrackId::new("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_edge_counts_are_direct() {
+        // a <- b <- c, a <- d
+        let t = tracks(&[("a", &[]), ("b", &["a"]), ("c", &["b"]), ("d", &["a"])]);
+        let counts = edge_counts(&t);
+        assert_eq!(counts[&TrackId::new("a")], (0, 2));
+        assert_eq!(counts[&TrackId::new("b")], (1, 1));
+        assert_eq!(counts[&TrackId::new("c")], (1, 0));
+    }
+
+    #[test]
+    fn test_detect_three_track_cycle() {
+        // b -> c -> a -> b, with d hanging off the loop
+        let t = tracks(&[("a", &["b"]), ("b", &["c"]), ("c", &["a"]), ("d", &["a"])]);
+        let ids = |ids: &[&str]| ids.iter().map(|id| TrackId::new(*id)).collect::<Vec<_>>();
+        assert_eq!(detect_cycles(&t), vec![ids(&["a", "b", "c"])]);
+    }
+
+    #[test]
+    fn test_detect_self_loop() {
+        let t = tracks(&[("a", &["a"]), ("b", &["a"])]);
+        assert_eq!(detect_cycles(&t), vec![vec![TrackId::new("a")]]);
+    }
+
+    #[test]
+    fn test_no_cycles_in_acyclic_graph() {
+        // Diamond: d -> (b, c) -> a, plus a dependency on an unknown track
+        let t = tracks(&[
+            ("a", &["missing"]),
+            ("b", &["a"]),
+            ("c", &["a"]),
+            ("d", &["b", "c"]),
+        ]);
+        assert!(detect_cycles(&t).is_empty());
+    }
+
+    #[test]
+    fn test_impact_handles_cycles() {
+        let t = tracks(&[("a", &["c"]), ("b", &["a"]), ("c", &["b"])]);
+        let impact = track_impact(&t);
+        assert_eq!(impact[&TrackId::new("a")], 2);
+        assert_eq!(impact[&TrackId::new("b")], 2);
+    }
+}
@@ -0,0 +1,171 @@
+//! ProgressHistory — persisted samples of overall task completion, used for
+//! day-over-day metrics like "+N tasks today".
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Oldest samples are dropped beyond this many.
+const MAX_SAMPLES: usize = 1000;
+
+/// Total completed tasks across all tracks at a point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProgressSample {
+    pub at: DateTime<Utc>,
+    pub completed: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProgressHistory {
+    pub samples: Vec<ProgressSample>,
+}
+
+impl ProgressHistory {
+    /// Load history from `path`; a missing or unreadable file gives an empty history.
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save to `path`, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, format!("{json}\n"))
+    }
+
+    /// Record `completed` at `at`. Only stored when the count changed or the
+    /// previous sample is from an earlier day (in `tz`), so each day has a
+    /// baseline. Returns whether a sample was added.
+    pub fn record<Tz: TimeZone>(&mut self, at: DateTime<Utc>, completed: usize, tz: &Tz) -> bool {
+        if let Some(last) = self.samples.last() {
+            if last.completed == completed && day_in(last.at, tz) == day_in(at, tz) {
+                return false;
+            }
+        }
+        self.samples.push(ProgressSample { at, completed });
+        if self.samples.len() > MAX_SAMPLES {
+            let excess = self.samples.len() - MAX_SAMPLES;
+            self.samples.drain(..excess);
+        }
+        true
+    }
+
+    /// Tasks completed on `day` (in `tz`) so far: `current` minus the first
+    /// sample taken that day. `None` when there is no sample from that day.
+    pub fn net_change_on<Tz: TimeZone>(
+        &self,
+        day: NaiveDate,
+        current: usize,
+        tz: &Tz,
+    ) -> Option<i64> {
+        self.samples
+            .iter()
+            .find(|s| day_in(s.at, tz) == day)
+            .map(|first| current as i64 - first.completed as i64)
+    }
+}
+
+/// Where the history of the board in `conductor_dir` is kept, outside the
+/// repo: `conductor-dashboard/history/` under the user's state directory
+/// (`$XDG_STATE_HOME`, `~/.local/state`), else the local data directory. The
+/// file is named after the board's absolute path. `None` when the platform
+/// has neither directory.
+pub fn history_path(conductor_dir: &Path) -> Option<PathBuf> {
+    let base = dirs::state_dir().or_else(dirs::data_local_dir)?;
+    Some(history_path_in(&base, conductor_dir))
+}
+
+/// Where the pins of the board in `conductor_dir` are kept: like
+/// [`history_path`], but under `conductor-dashboard/pins/`.
+pub fn pins_path(conductor_dir: &Path) -> Option<PathBuf> {
+    let base = dirs::state_dir().or_else(dirs::data_local_dir)?;
+    Some(pins_path_in(&base, conductor_dir))
+}
+
+fn history_path_in(base: &Path, conductor_dir: &Path) -> PathBuf {
+    board_state_path(base, "history", conductor_dir)
+}
+
+fn pins_path_in(base: &Path, conductor_dir: &Path) -> PathBuf {
+    board_state_path(base, "pins", conductor_dir)
+}
+
+/// `<base>/conductor-dashboard/<kind>/<escaped absolute board path>.json`.
+fn board_state_path(base: &Path, kind: &str, conductor_dir: &Path) -> PathBuf {
+    let board =
+        std::fs::canonicalize(conductor_dir).unwrap_or_else(|_| conductor_dir.to_path_buf());
+    // Escape `%` first so distinct paths never share a name
+    let name = board
+        .to_string_lossy()
+        .replace('%', "%25")
+        .replace(['/', '\\'], "%2F")
+        .replace(':', "%3A");
+    base.join("conductor-dashboard")
+        .join(kind)
+        .join(format!("{name}.json"))
+}
+
+fn day_in<Tz: TimeZone>(at: DateTime<Utc>, tz: &Tz) -> NaiveDate {
+    at.with_timezone(tz).date_naive()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, day, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_net_change_uses_first_sample_of_day() {
+        let mut history = ProgressHistory::default();
+        assert!(history.record(at(1, 9), 10, &Utc));
+        assert!(history.record(at(2, 9), 10, &Utc)); // new day, same count
+        assert!(!history.record(at(2, 10), 10, &Utc));
+        assert!(history.record(at(2, 11), 14, &Utc));
+
+        let today = at(2, 12).date_naive();
+        assert_eq!(history.net_change_on(today, 15, &Utc), Some(5));
+        assert_eq!(history.net_change_on(at(3, 0).date_naive(), 15, &Utc), None);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.json");
+        let mut history = ProgressHistory::default();
+        history.record(at(1, 9), 3, &Utc);
+        history.save(&path).unwrap();
+
+        let loaded = ProgressHistory::load(&path);
+        assert_eq!(loaded.samples, history.samples);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(ProgressHistory::load(&path).samples.is_empty());
+    }
+
+    #[test]
+    fn test_history_path_is_per_board_outside_the_board() {
+        let base = Path::new("/state");
+        let api = history_path_in(base, Path::new("/work/api/conductor"));
+        let web = history_path_in(base, Path::new("/work/web/conductor"));
+        assert_eq!(
+            api,
+            Path::new("/state/conductor-dashboard/history/%2Fwork%2Fapi%2Fconductor.json")
+        );
+        assert_ne!(api, web);
+        assert!(!api.starts_with("/work"));
+        assert_eq!(
+            pins_path_in(base, Path::new("/work/api/conductor")),
+            Path::new("/state/conductor-dashboard/pins/%2Fwork%2Fapi%2Fconductor.json")
+        );
+    }
+}
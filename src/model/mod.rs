@@ -1,7 +1,17 @@
 pub mod cache;
 pub mod enums;
+pub mod graph;
+pub mod history;
+pub mod pins;
+pub mod query;
+pub mod report;
 pub mod track;
 
 pub use cache::*;
 pub use enums::*;
+pub use graph::*;
+pub use history::*;
+pub use pins::*;
+pub use query::*;
+pub use report::*;
 pub use track::*;
@@ -0,0 +1,82 @@
+//! Pinned tracks — kept at the top of the track list regardless of sort, and
+//! persisted per board (see [`pins_path`](super::pins_path)) so the current
+//! focus set survives restarts.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::track::TrackId;
+
+/// Pinned track IDs, in the order they were pinned.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Pins {
+    pub tracks: Vec<TrackId>,
+}
+
+impl Pins {
+    /// Load pins from `path`; a missing or unreadable file gives no pins.
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save to `path` atomically, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        crate::parser::write_atomic(path, &format!("{json}\n"))
+    }
+
+    /// Pin `id` after any existing pins, or unpin it if already pinned.
+    /// Returns whether the track is now pinned.
+    pub fn toggle(&mut self, id: &TrackId) -> bool {
+        if let Some(pos) = self.position(id) {
+            self.tracks.remove(pos);
+            false
+        } else {
+            self.tracks.push(id.clone());
+            true
+        }
+    }
+
+    /// Pin order of `id`, or `None` when it is not pinned.
+    pub fn position(&self, id: &TrackId) -> Option<usize> {
+        self.tracks.iter().position(|t| t == id)
+    }
+
+    pub fn contains(&self, id: &TrackId) -> bool {
+        self.position(id).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_keeps_pin_order_and_round_trips() {
+        let (a, b, c) = (TrackId::new("a"), TrackId::new("b"), TrackId::new("c"));
+        let mut pins = Pins::default();
+        assert!(pins.toggle(&b));
+        assert!(pins.toggle(&a));
+        assert!(pins.toggle(&c));
+        assert!(!pins.toggle(&a));
+        assert_eq!(pins.tracks, vec![b.clone(), c.clone()]);
+        assert_eq!(pins.position(&c), Some(1));
+        assert!(!pins.contains(&a));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pins").join("board.json");
+        pins.save(&path).unwrap();
+        assert_eq!(Pins::load(&path).tracks, vec![b, c]);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(Pins::load(&path).tracks.is_empty());
+    }
+}
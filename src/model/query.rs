@@ -0,0 +1,139 @@
+//! Track query language for the `Q` filter: whitespace-separated tokens,
+//! all of which must match, e.g. `status:blocked tag:backend prio:high overdue`.
+//!
+//! - `status:` / `tag:` / `prio:` (`priority:`) / `type:` take a value, or
+//!   several separated by commas (`status:blocked,review`) where any may match
+//! - `overdue` — the plan has an undone task past its due date
+//! - any other bare word matches the title or ID as a substring
+//!
+//! Parsing is lenient: tokens with an unknown key or value are collected in
+//! [`TrackQuery::ignored`] so the UI can point them out, and otherwise skipped.
+
+use chrono::{DateTime, Utc};
+
+use super::enums::{Priority, Status, TrackType};
+use super::track::Track;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TrackQuery {
+    /// One group per `status:` token; a track must match a value in each.
+    pub statuses: Vec<Vec<Status>>,
+    /// Lower-cased tags, one group per `tag:` token.
+    pub tags: Vec<Vec<String>>,
+    pub priorities: Vec<Vec<Priority>>,
+    pub types: Vec<Vec<TrackType>>,
+    pub overdue: bool,
+    /// Lower-cased bare words matched against title and ID.
+    pub words: Vec<String>,
+    /// Tokens that were not understood.
+    pub ignored: Vec<String>,
+}
+
+impl TrackQuery {
+    pub fn parse(input: &str) -> Self {
+        let mut query = Self::default();
+        for token in input.split_whitespace() {
+            let lower = token.to_lowercase();
+            let understood = match lower.split_once(':') {
+                Some((key, values)) => {
+                    let values: Vec<&str> = values.split(',').filter(|v| !v.is_empty()).collect();
+                    match key {
+                        "status" | "is" => push_group(&mut query.statuses, &values, parse_status),
+                        "tag" => push_group(&mut query.tags, &values, |v| Some(v.to_string())),
+                        "prio" | "priority" => {
+                            push_group(&mut query.priorities, &values, parse_priority)
+                        }
+                        "type" => push_group(&mut query.types, &values, parse_type),
+                        _ => false,
+                    }
+                }
+                None if lower == "overdue" => {
+                    query.overdue = true;
+                    true
+                }
+                None => {
+                    query.words.push(lower);
+                    true
+                }
+            };
+            if !understood {
+                query.ignored.push(token.to_string());
+            }
+        }
+        query
+    }
+
+    pub fn matches(&self, track: &Track, now: DateTime<Utc>) -> bool {
+        let title = track.title.to_lowercase();
+        let id = track.id.as_str().to_lowercase();
+        self.statuses
+            .iter()
+            .all(|group| group.contains(&track.status))
+            && self.tags.iter().all(|group| {
+                group
+                    .iter()
+                    .any(|tag| track.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+            })
+            && self
+                .priorities
+                .iter()
+                .all(|group| group.contains(&track.priority))
+            && self
+                .types
+                .iter()
+                .all(|group| group.contains(&track.track_type))
+            && (!self.overdue
+                || track
+                    .plan_phases
+                    .iter()
+                    .flat_map(|p| &p.tasks)
+                    .any(|t| t.is_overdue(now)))
+            && self
+                .words
+                .iter()
+                .all(|word| title.contains(word) || id.contains(word))
+    }
+}
+
+/// Parse every value of a `key:a,b` token into one group. Any value that
+/// doesn't parse makes the whole token ignored.
+fn push_group<T>(
+    groups: &mut Vec<Vec<T>>,
+    values: &[&str],
+    parse: impl Fn(&str) -> Option<T>,
+) -> bool {
+    let group: Option<Vec<T>> = values.iter().map(|v| parse(v)).collect();
+    match group {
+        Some(group) if !group.is_empty() => {
+            groups.push(group);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Strict counterpart of [`Status::from_str_loose`], which maps anything
+/// unrecognised to `New`.
+fn parse_status(value: &str) -> Option<Status> {
+    match value {
+        "new" | "not_started" | "planned" | "planning" | "todo" => Some(Status::New),
+        _ => Some(Status::from_str_loose(value)).filter(|s| *s != Status::New),
+    }
+}
+
+fn parse_priority(value: &str) -> Option<Priority> {
+    match value {
+        "critical" | "crit" => Some(Priority::Critical),
+        "high" => Some(Priority::High),
+        "medium" | "med" => Some(Priority::Medium),
+        "low" => Some(Priority::Low),
+        _ => None,
+    }
+}
+
+fn parse_type(value: &str) -> Option<TrackType> {
+    match value {
+        "other" | "track" => Some(TrackType::Other),
+        _ => Some(TrackType::from_str_loose(value)).filter(|t| *t != TrackType::Other),
+    }
+}
@@ -0,0 +1,131 @@
+//! Human-readable Markdown report of the whole board.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::enums::{SortMode, Status};
+use super::track::{compare_tracks, Track, TrackId};
+
+/// Render a Markdown report: overall stats, a table of tracks (most recently
+/// updated first), and the outstanding tasks of each incomplete track.
+pub fn to_markdown(tracks: &BTreeMap<TrackId, Track>) -> String {
+    let count = |status: Status| tracks.values().filter(|t| t.status == status).count();
+    let total_tasks: usize = tracks.values().map(|t| t.tasks_total).sum();
+    let completed_tasks: usize = tracks.values().map(|t| t.tasks_completed).sum();
+    let overall = if total_tasks > 0 {
+        completed_tasks as f32 / total_tasks as f32 * 100.0
+    } else {
+        0.0
+    };
+
+    let mut sorted: Vec<&Track> = tracks.values().collect();
+    sorted.sort_by(|a, b| compare_tracks(a, b, SortMode::Updated));
+
+    let mut out = String::from("# Conductor Report\n\n");
+    let _ = writeln!(
+        out,
+        "- **Tracks:** {} ({} active, {} in review, {} blocked, {} new, {} complete)",
+        tracks.len(),
+        count(Status::InProgress),
+        count(Status::Review),
+        count(Status::Blocked),
+        count(Status::New),
+        count(Status::Complete),
+    );
+    let _ = writeln!(
+        out,
+        "- **Tasks:** {completed_tasks}/{total_tasks} done ({overall:.0}%)"
+    );
+
+    let _ = writeln!(out, "\n## Tracks ({})\n", tracks.len());
+    out.push_str("| Track | Status | Priority | Progress | Tasks |\n");
+    out.push_str("|---|---|---|---|---|\n");
+    for track in &sorted {
+        let _ = writeln!(
+            out,
+            "| {} (`{}`) | {} | {} | {:.0}% | {}/{} |",
+            md_escape(&track.title),
+            track.id,
+            track.status,
+            track.priority,
+            track.progress_percent(),
+            track.tasks_completed,
+            track.tasks_total,
+        );
+    }
+
+    out.push_str("\n## Outstanding Tasks\n");
+    let mut any = false;
+    for track in sorted.iter().filter(|t| !t.is_complete()) {
+        let mut tasks = track.outstanding_tasks().peekable();
+        if tasks.peek().is_none() {
+            continue;
+        }
+        any = true;
+        let _ = writeln!(out, "\n### {} (`{}`)\n", md_escape(&track.title), track.id);
+        for (phase, task) in tasks {
+            let _ = writeln!(out, "- [ ] {} — {}", phase.name, task.text);
+        }
+    }
+    if !any {
+        out.push_str("\nNone.\n");
+    }
+    out
+}
+
+/// Escape table separators so titles can't break the Markdown table.
+fn md_escape(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{PhaseStatus, PlanPhase, PlanTask};
+
+    #[test]
+    fn test_markdown_report_sections() {
+        let phase = PlanPhase {
+            name: "Phase 1".to_string(),
+            status: PhaseStatus::Active,
+            tasks: vec![
+                PlanTask {
+                    text: "Wire exporter".to_string(),
+                    done: false,
+                    due: None,
+                    depth: 0,
+                },
+                PlanTask {
+                    text: "Add config".to_string(),
+                    done: true,
+                    due: None,
+                    depth: 0,
+                },
+            ],
+            depth: 0,
+            completed_at: None,
+        };
+        let mut tracks = BTreeMap::new();
+        for (id, title, status) in [
+            ("otel", "OTel | tracing", Status::InProgress),
+            ("done", "Shipped thing", Status::Complete),
+        ] {
+            let mut track = Track {
+                id: TrackId::new(id),
+                title: title.to_string(),
+                status,
+                ..Track::default()
+            };
+            track.merge_plan(vec![phase.clone()]);
+            tracks.insert(track.id.clone(), track);
+        }
+
+        let md = to_markdown(&tracks);
+        assert!(md.contains("## Tracks (2)"));
+        assert!(md.contains("(1 active, 0 in review, 0 blocked, 0 new, 1 complete)"));
+        assert!(md.contains("| OTel \\| tracing (`otel`) | Active | MEDIUM | 50% | 1/2 |"));
+        assert!(md.contains("### OTel \\| tracing (`otel`)\n\n- [ ] Phase 1 — Wire exporter\n"));
+        // Complete tracks have no outstanding section
+        assert!(!md.contains("### Shipped thing"));
+    }
+}
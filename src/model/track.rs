@@ -1,15 +1,17 @@
+use std::collections::BTreeMap;
 use std::fmt;
+use std::path::PathBuf;
 
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use super::enums::{CheckboxStatus, PhaseStatus, Priority, Status, TrackType};
+use super::enums::{CheckboxStatus, PhaseStatus, Priority, SortMode, Status, TrackType};
 
 // ---------------------------------------------------------------------------
 // TrackId — newtype for type safety
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct TrackId(pub String);
 
@@ -56,13 +58,38 @@ pub struct Track {
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
     pub dependencies: Vec<TrackId>,
+    /// Blockers outside the track set (e.g. "waiting on vendor").
+    pub external_blockers: Vec<String>,
     pub tasks_total: usize,
     pub tasks_completed: usize,
+    /// Plan counts before complete tracks are normalised to 100%.
+    pub raw_tasks_total: usize,
+    pub raw_tasks_completed: usize,
     pub checkbox_status: CheckboxStatus,
     pub plan_phases: Vec<PlanPhase>,
+    /// The track has a plan file that was skipped at load time
+    /// ([`ReadOptions::lazy_plans`](crate::parser::ReadOptions::lazy_plans)).
+    pub plan_pending: bool,
     pub tags: Vec<String>,
     pub branch: Option<String>,
+    pub owner: Option<String>,
     pub description: Option<String>,
+    /// Task texts declared done in metadata, overriding plan.md checkboxes.
+    pub completed_tasks: Vec<String>,
+    /// `(phase, task)` indices of the plan tasks `completed_tasks` ticked,
+    /// undone before a changed list is applied.
+    #[serde(skip)]
+    pub completed_task_overrides: Vec<(usize, usize)>,
+    /// Effort estimate (story points or similar) from metadata.
+    pub effort: Option<f32>,
+    /// Task count declared in metadata (`total_tasks`), checked against the plan.
+    pub declared_tasks: Option<usize>,
+    /// Conductor dir this track was loaded from, when several are merged.
+    pub source_dir: Option<PathBuf>,
+    /// H1 title of the track's spec.md.
+    pub spec_summary: Option<String>,
+    /// Bullets under spec.md's "Acceptance Criteria" heading.
+    pub acceptance_criteria: Vec<String>,
 }
 
 impl Track {
@@ -73,9 +100,117 @@ impl Track {
         (self.tasks_completed as f32 / self.tasks_total as f32) * 100.0
     }
 
+    /// Warning when metadata declares a task total the parsed plan doesn't
+    /// match, a sign the plan is incomplete or stale.
+    pub fn task_count_warning(&self) -> Option<String> {
+        let declared = self.declared_tasks?;
+        let parsed = self.raw_tasks_total;
+        match parsed.cmp(&declared) {
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Less => {
+                Some(format!("plan has {parsed} of {declared} declared tasks"))
+            }
+            std::cmp::Ordering::Greater => Some(format!(
+                "plan has {parsed} tasks but metadata declares {declared}"
+            )),
+        }
+    }
+
+    /// Whether this track belongs to `pattern`: its branch starts with it
+    /// or its owner contains it (both case-insensitive).
+    pub fn is_owned_by(&self, pattern: &str) -> bool {
+        let pattern = pattern.to_ascii_lowercase();
+        if pattern.is_empty() {
+            return true;
+        }
+        self.branch
+            .as_deref()
+            .is_some_and(|b| b.to_ascii_lowercase().starts_with(&pattern))
+            || self
+                .owner
+                .as_deref()
+                .is_some_and(|o| o.to_ascii_lowercase().contains(&pattern))
+    }
+
+    /// Branch text before the first `/` or `-` (`feat/x` → `feat`,
+    /// `DSS-1234` → `DSS`), or the whole branch when it has neither.
+    pub fn branch_prefix(&self) -> Option<&str> {
+        let branch = self.branch.as_deref()?.trim();
+        let prefix = branch.split(['/', '-']).next().unwrap_or(branch);
+        Some(if prefix.is_empty() { branch } else { prefix }).filter(|p| !p.is_empty())
+    }
+
+    /// Unfinished plan tasks, paired with the phase each belongs to.
+    pub fn outstanding_tasks(&self) -> impl Iterator<Item = (&PlanPhase, &PlanTask)> {
+        self.plan_phases.iter().flat_map(|phase| {
+            phase
+                .tasks
+                .iter()
+                .filter(|t| !t.done)
+                .map(move |task| (phase, task))
+        })
+    }
+
+    /// Stable address of a plan task: `{track_id}#{phase_index}.{task_index}`,
+    /// both indexes 0-based in plan order.
+    pub fn task_id(&self, phase_index: usize, task_index: usize) -> String {
+        format!("{}#{phase_index}.{task_index}", self.id)
+    }
+
+    /// Every plan task with its [`Track::task_id`] and phase.
+    pub fn tasks_with_ids(&self) -> impl Iterator<Item = (String, &PlanPhase, &PlanTask)> {
+        self.plan_phases
+            .iter()
+            .enumerate()
+            .flat_map(move |(pi, phase)| {
+                phase
+                    .tasks
+                    .iter()
+                    .enumerate()
+                    .map(move |(ti, task)| (self.task_id(pi, ti), phase, task))
+            })
+    }
+
+    /// Name of the track's folder under the tracks dir. Same as the ID unless
+    /// the ID was prefixed (`origin/id`) to disambiguate merged conductor dirs.
+    pub fn dir_name(&self) -> &str {
+        self.id.as_str().rsplit('/').next().unwrap_or_default()
+    }
+
+    /// Actual plan completion, ignoring the auto-complete normalisation.
+    pub fn raw_progress_percent(&self) -> f32 {
+        if self.raw_tasks_total == 0 {
+            return 0.0;
+        }
+        (self.raw_tasks_completed as f32 / self.raw_tasks_total as f32) * 100.0
+    }
+
+    /// Dependencies that hold this track up, directly or through another
+    /// unmet dependency: those not complete in `all` (see
+    /// [`Track::is_complete`]) or missing from it. Non-empty means the track
+    /// is effectively blocked, whatever its status says.
+    pub fn is_blocked_by_incomplete(&self, all: &BTreeMap<TrackId, Track>) -> Vec<TrackId> {
+        super::graph::blocking_dependencies(all, self)
+    }
+
+    /// Complete by status, or by having every plan task ticked. A track in
+    /// review never counts: its tasks are done but it still awaits sign-off.
     pub fn is_complete(&self) -> bool {
+        match self.status {
+            Status::Complete => true,
+            Status::Review => false,
+            _ => self.tasks_total > 0 && self.tasks_completed == self.tasks_total,
+        }
+    }
+
+    /// Whether this is a complete track last touched (`updated_at`, else
+    /// `created_at`) more than `max_age` before `now`. Undated tracks never are.
+    pub fn is_archived(&self, now: DateTime<Utc>, max_age: chrono::Duration) -> bool {
         self.status == Status::Complete
-            || (self.tasks_total > 0 && self.tasks_completed == self.tasks_total)
+            && self
+                .updated_at
+                .or(self.created_at)
+                .is_some_and(|at| now - at > max_age)
     }
 
     /// Merge metadata (from metadata.json or meta.yaml) into a track
@@ -100,15 +235,33 @@ impl Track {
         if !meta.dependencies.is_empty() {
             self.dependencies = meta.dependencies.into_iter().map(TrackId::new).collect();
         }
+        if !meta.external_blockers.is_empty() {
+            self.external_blockers = meta.external_blockers;
+        }
         if !meta.tags.is_empty() {
             self.tags = meta.tags;
         }
         if meta.branch.is_some() {
             self.branch = meta.branch;
         }
+        if meta.owner.is_some() {
+            self.owner = meta.owner;
+        }
         if meta.description.is_some() {
             self.description = meta.description;
         }
+        if meta.effort.is_some() {
+            self.effort = meta.effort;
+        }
+        if meta.declared_tasks.is_some() {
+            self.declared_tasks = meta.declared_tasks;
+        }
+        // An empty list is applied too, clearing overrides from a previous read
+        self.completed_tasks = meta.completed_tasks;
+        if !self.plan_phases.is_empty() {
+            self.apply_completed_tasks();
+            self.recount_tasks();
+        }
     }
 
     /// Mark all plan tasks as complete (display-level normalization for tracks
@@ -123,18 +276,25 @@ impl Track {
         self.tasks_completed = self.tasks_total;
     }
 
+    /// Merge spec data (from spec.md) into this track.
+    pub fn merge_spec(&mut self, spec: SpecSummary) {
+        self.spec_summary = spec.title;
+        self.acceptance_criteria = spec.acceptance_criteria;
+    }
+
     /// Merge plan data (from plan.md) into this track.
     pub fn merge_plan(&mut self, phases: Vec<PlanPhase>) {
-        let (total, completed) = phases.iter().fold((0usize, 0usize), |(t, c), phase| {
-            let phase_total = phase.tasks.len();
-            let phase_done = phase.tasks.iter().filter(|t| t.done).count();
-            (t + phase_total, c + phase_done)
-        });
-        self.tasks_total = total;
-        self.tasks_completed = completed;
         self.plan_phases = phases;
+        self.plan_pending = false;
+        // The indices belonged to the previous plan
+        self.completed_task_overrides.clear();
+        self.apply_completed_tasks();
+        self.recount_tasks();
+        self.derive_current_phase();
+    }
 
-        // Derive current phase name from first non-complete phase
+    /// Set `phase` to the first non-complete plan phase, else the last one.
+    fn derive_current_phase(&mut self) {
         if let Some(active) = self
             .plan_phases
             .iter()
@@ -145,6 +305,154 @@ impl Track {
             self.phase = last.name.clone();
         }
     }
+
+    /// Mark plan tasks listed in `completed_tasks` as done (case-insensitive,
+    /// trimmed text match), replacing whatever an earlier list ticked, then
+    /// re-derive phase statuses and the current phase. Entries matching no
+    /// task are logged.
+    fn apply_completed_tasks(&mut self) {
+        let mut changed = !self.completed_task_overrides.is_empty();
+        for (p, t) in self.completed_task_overrides.drain(..) {
+            if let Some(task) = self
+                .plan_phases
+                .get_mut(p)
+                .and_then(|phase| phase.tasks.get_mut(t))
+            {
+                task.done = false;
+            }
+        }
+        for entry in &self.completed_tasks {
+            let wanted = entry.trim().to_lowercase();
+            let mut matched = false;
+            for (p, phase) in self.plan_phases.iter_mut().enumerate() {
+                for (t, task) in phase.tasks.iter_mut().enumerate() {
+                    if task.text.trim().to_lowercase() == wanted {
+                        matched = true;
+                        if !task.done {
+                            task.done = true;
+                            self.completed_task_overrides.push((p, t));
+                            changed = true;
+                        }
+                    }
+                }
+            }
+            if !matched {
+                tracing::warn!(
+                    track_id = self.id.as_str(),
+                    task = entry.as_str(),
+                    "completed_tasks entry matches no plan task"
+                );
+            }
+        }
+        if changed {
+            compute_phase_statuses(&mut self.plan_phases);
+            self.derive_current_phase();
+        }
+    }
+
+    fn recount_tasks(&mut self) {
+        let (total, completed) = self
+            .plan_phases
+            .iter()
+            .fold((0usize, 0usize), |(t, c), phase| {
+                (t + phase.tasks.len(), c + phase.tasks_completed())
+            });
+        self.tasks_total = total;
+        self.tasks_completed = completed;
+        self.raw_tasks_total = total;
+        self.raw_tasks_completed = completed;
+    }
+}
+
+/// Outstanding plan tasks counted under their track's priority, indexed as
+/// [`Priority::ALL`] (critical first).
+pub fn outstanding_by_priority<'a>(tracks: impl IntoIterator<Item = &'a Track>) -> [usize; 4] {
+    let mut counts = [0; 4];
+    for track in tracks {
+        counts[track.priority as usize] += track.outstanding_tasks().count();
+    }
+    counts
+}
+
+/// Canonical track ordering, shared by the dashboard list and MCP `list_tracks`.
+///
+/// - `Updated`: most recent `updated_at` (falling back to `created_at`) first;
+///   undated tracks last.
+/// - `Progress`: highest completion percentage first.
+/// - `Type`: grouped by [`TrackType::sort_rank`], then by title (case-insensitive).
+///
+/// Ties are broken by track ID ascending so the order is fully deterministic.
+pub fn compare_tracks(a: &Track, b: &Track, sort: SortMode) -> std::cmp::Ordering {
+    let primary = match sort {
+        SortMode::Updated => {
+            let a_time = a.updated_at.or(a.created_at);
+            let b_time = b.updated_at.or(b.created_at);
+            b_time.cmp(&a_time)
+        }
+        SortMode::Progress => b.progress_percent().total_cmp(&a.progress_percent()),
+        SortMode::Type => a
+            .track_type
+            .sort_rank()
+            .cmp(&b.track_type.sort_rank())
+            .then_with(|| a.title.to_lowercase().cmp(&b.title.to_lowercase())),
+    };
+    primary.then_with(|| a.id.cmp(&b.id))
+}
+
+/// What changed between two loads of the track set: tracks added, removed,
+/// and tracks whose status moved (`(id, before, after)`), each in ID order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TrackSetDiff {
+    pub added: Vec<TrackId>,
+    pub removed: Vec<TrackId>,
+    pub status_changed: Vec<(TrackId, Status, Status)>,
+}
+
+impl TrackSetDiff {
+    pub fn between(old: &BTreeMap<TrackId, Track>, new: &BTreeMap<TrackId, Track>) -> Self {
+        let mut diff = Self::default();
+        for (id, track) in new {
+            match old.get(id) {
+                None => diff.added.push(id.clone()),
+                Some(prev) if prev.status != track.status => {
+                    diff.status_changed
+                        .push((id.clone(), prev.status, track.status));
+                }
+                Some(_) => {}
+            }
+        }
+        diff.removed = old
+            .keys()
+            .filter(|id| !new.contains_key(*id))
+            .cloned()
+            .collect();
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.status_changed.is_empty()
+    }
+
+    /// One-line summary, e.g. "+2 tracks, 1 removed, 3 status changes".
+    pub fn summary(&self) -> String {
+        let plural = |n: usize, one: &str, many: &str| if n == 1 { one } else { many }.to_string();
+        let mut parts = Vec::new();
+        if !self.added.is_empty() {
+            let n = self.added.len();
+            parts.push(format!("+{n} {}", plural(n, "track", "tracks")));
+        }
+        if !self.removed.is_empty() {
+            parts.push(format!("{} removed", self.removed.len()));
+        }
+        if !self.status_changed.is_empty() {
+            let n = self.status_changed.len();
+            parts.push(format!(
+                "{n} {}",
+                plural(n, "status change", "status changes")
+            ));
+        }
+        parts.join(", ")
+    }
 }
 
 impl Default for Track {
@@ -159,13 +467,25 @@ impl Default for Track {
             created_at: None,
             updated_at: None,
             dependencies: Vec::new(),
+            external_blockers: Vec::new(),
             tasks_total: 0,
             tasks_completed: 0,
+            raw_tasks_total: 0,
+            raw_tasks_completed: 0,
             checkbox_status: CheckboxStatus::Unchecked,
             plan_phases: Vec::new(),
+            plan_pending: false,
             tags: Vec::new(),
             branch: None,
+            owner: None,
             description: None,
+            completed_tasks: Vec::new(),
+            completed_task_overrides: Vec::new(),
+            effort: None,
+            declared_tasks: None,
+            source_dir: None,
+            spec_summary: None,
+            acceptance_criteria: Vec::new(),
         }
     }
 }
@@ -179,6 +499,12 @@ pub struct PlanPhase {
     pub name: String,
     pub status: PhaseStatus,
     pub tasks: Vec<PlanTask>,
+    /// Nesting depth: 0 for a top-level phase, 1 for an H3 sub-phase
+    /// nested under the preceding H2 phase (only with
+    /// [`crate::parser::ReadOptions::sub_phases`]).
+    pub depth: usize,
+    /// Completion date from a `(done YYYY-MM-DD)` heading marker, stripped from `name`.
+    pub completed_at: Option<DateTime<Utc>>,
 }
 
 impl PlanPhase {
@@ -194,6 +520,71 @@ impl PlanPhase {
     }
 }
 
+/// Derive phase statuses from task completion. Each phase with tasks is
+/// classified on its own:
+///
+/// - every task done → Complete
+/// - some tasks done → Active (several phases can be in progress at once)
+/// - no task done → Pending
+///
+/// When no phase is Active, the first unfinished one is, being the next to
+/// pick up; a Pending phase before a started one stays Pending. Phases
+/// without tasks are Pending until the sub-phase rollup below.
+pub fn compute_phase_statuses(phases: &mut [PlanPhase]) {
+    for phase in phases.iter_mut() {
+        let done = phase.tasks.iter().filter(|t| t.done).count();
+        phase.status = if phase.tasks.is_empty() || done == 0 {
+            PhaseStatus::Pending
+        } else if done == phase.tasks.len() {
+            PhaseStatus::Complete
+        } else {
+            PhaseStatus::Active
+        };
+    }
+
+    if !phases.iter().any(|p| p.status == PhaseStatus::Active) {
+        if let Some(next) = phases
+            .iter_mut()
+            .find(|p| p.status == PhaseStatus::Pending && !p.tasks.is_empty())
+        {
+            next.status = PhaseStatus::Active;
+        }
+    }
+
+    // A parent phase whose tasks all live in its sub-phases takes its
+    // status from them.
+    for i in 0..phases.len() {
+        let subs = sub_phases_of(phases, i);
+        if subs.is_empty() {
+            continue;
+        }
+        phases[i].status = if subs.iter().all(|p| p.status == PhaseStatus::Complete) {
+            PhaseStatus::Complete
+        } else if subs
+            .iter()
+            .any(|p| matches!(p.status, PhaseStatus::Active | PhaseStatus::Complete))
+        {
+            PhaseStatus::Active
+        } else {
+            PhaseStatus::Pending
+        };
+    }
+}
+
+/// The sub-phases rolled up into `phases[index]`: the nested phases right
+/// after it when it is a top-level phase without tasks of its own, else none.
+pub fn sub_phases_of(phases: &[PlanPhase], index: usize) -> &[PlanPhase] {
+    let Some(phase) = phases.get(index) else {
+        return &[];
+    };
+    if phase.depth != 0 || !phase.tasks.is_empty() {
+        return &[];
+    }
+    let rest = &phases[index + 1..];
+    let count = rest.iter().take_while(|p| p.depth > 0).count();
+    &rest[..count]
+}
+
 // ---------------------------------------------------------------------------
 // PlanTask
 // ---------------------------------------------------------------------------
@@ -202,6 +593,29 @@ impl PlanPhase {
 pub struct PlanTask {
     pub text: String,
     pub done: bool,
+    /// Deadline from an inline `(due YYYY-MM-DD)` marker, stripped from `text`.
+    pub due: Option<DateTime<Utc>>,
+    /// Nesting under parent tasks: 0 for a top-level task, 1 for its subtask, ...
+    pub depth: u8,
+}
+
+impl PlanTask {
+    /// Whether the task is unfinished and its due date has passed.
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        !self.done && self.due.is_some_and(|due| due < now)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// SpecSummary — intermediate struct from spec.md
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpecSummary {
+    /// Text of the first H1 heading.
+    pub title: Option<String>,
+    /// Top-level bullets under an "Acceptance Criteria" heading.
+    pub acceptance_criteria: Vec<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -216,7 +630,12 @@ pub struct TrackMetadata {
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
     pub dependencies: Vec<String>,
+    pub external_blockers: Vec<String>,
     pub tags: Vec<String>,
     pub branch: Option<String>,
+    pub owner: Option<String>,
     pub description: Option<String>,
+    pub completed_tasks: Vec<String>,
+    pub effort: Option<f32>,
+    pub declared_tasks: Option<usize>,
 }
@@ -0,0 +1,107 @@
+//! `updated_at` fallback from git history (`--git-dates`): a track folder's
+//! last commit date, else its modification time, for tracks whose metadata
+//! has no `updated_at`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chrono::{DateTime, Utc};
+use tracing::debug;
+
+use crate::model::{Track, TrackId};
+
+/// Last-commit dates per track folder, so each folder costs one `git log`.
+#[derive(Debug, Default)]
+pub struct GitDates {
+    cache: HashMap<PathBuf, Option<DateTime<Utc>>>,
+}
+
+impl GitDates {
+    /// Date of the last commit touching `dir`, looked up once per folder.
+    pub fn last_commit(&mut self, dir: &Path) -> Option<DateTime<Utc>> {
+        *self
+            .cache
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| git_last_commit(dir))
+    }
+
+    /// Fill `updated_at` for tracks that have none: the folder's last commit
+    /// date, else the folder's mtime.
+    pub fn fill_updated_at(
+        &mut self,
+        tracks: &mut BTreeMap<TrackId, Track>,
+        conductor_dir: &Path,
+        tracks_subdir: &str,
+    ) {
+        for track in tracks.values_mut().filter(|t| t.updated_at.is_none()) {
+            let dir = track_dir(track, conductor_dir, tracks_subdir);
+            track.updated_at = self.last_commit(&dir).or_else(|| dir_modified(&dir));
+        }
+    }
+
+    /// Like [`GitDates::fill_updated_at`], but without running git: only
+    /// folders already cached are filled. Returns the folders still to look
+    /// up, e.g. with [`git_last_commit`] off the UI thread, then [`GitDates::insert`].
+    pub fn fill_from_cache(
+        &self,
+        tracks: &mut BTreeMap<TrackId, Track>,
+        conductor_dir: &Path,
+        tracks_subdir: &str,
+    ) -> Vec<PathBuf> {
+        let mut uncached = Vec::new();
+        for track in tracks.values_mut().filter(|t| t.updated_at.is_none()) {
+            let dir = track_dir(track, conductor_dir, tracks_subdir);
+            match self.cache.get(&dir) {
+                Some(date) => track.updated_at = date.or_else(|| dir_modified(&dir)),
+                None if !uncached.contains(&dir) => uncached.push(dir),
+                None => {}
+            }
+        }
+        uncached
+    }
+
+    /// Cache a folder's last-commit date looked up elsewhere.
+    pub fn insert(&mut self, dir: PathBuf, date: Option<DateTime<Utc>>) {
+        self.cache.insert(dir, date);
+    }
+
+    /// Forget cached dates so the next lookup asks git again.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+/// Run `git log -1 --format=%cI -- .` in `dir`. `None` when git is missing,
+/// `dir` is not in a repo, or nothing under it has been committed.
+pub fn git_last_commit(dir: &Path) -> Option<DateTime<Utc>> {
+    let output = Command::new("git")
+        .args(["log", "-1", "--format=%cI", "--", "."])
+        .current_dir(dir)
+        .output()
+        .map_err(|e| debug!(dir = %dir.display(), error = %e, "git log failed"))
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    DateTime::parse_from_rfc3339(stdout.trim())
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// A track's folder, resolved like the loaders do:
+/// `<source dir or conductor_dir>/<tracks_subdir>/<dir name>`.
+fn track_dir(track: &Track, conductor_dir: &Path, tracks_subdir: &str) -> PathBuf {
+    track
+        .source_dir
+        .as_deref()
+        .unwrap_or(conductor_dir)
+        .join(tracks_subdir)
+        .join(track.dir_name())
+}
+
+fn dir_modified(dir: &Path) -> Option<DateTime<Utc>> {
+    let modified = std::fs::metadata(dir).and_then(|m| m.modified()).ok()?;
+    Some(modified.into())
+}
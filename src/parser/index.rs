@@ -5,13 +5,15 @@
 //! The body below each H2 contains metadata lines (`**Priority**: High`, etc.)
 //! and an optional description.
 
+use std::borrow::Cow;
 use std::collections::BTreeMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
 
 use crate::model::{CheckboxStatus, Priority, Status, Track, TrackId};
 use crate::parser::error::ParseError;
+use crate::parser::read_text;
 
 /// Result of parsing a single track entry from tracks.md.
 #[derive(Debug, Clone)]
@@ -20,36 +22,117 @@ pub struct IndexEntry {
     pub title: String,
     pub checkbox: CheckboxStatus,
     pub status: Status,
+    /// Whether a `**Status**` field was present (even if it said "new").
+    pub has_status_field: bool,
     pub priority: Priority,
     pub tags: Vec<String>,
     pub branch: Option<String>,
     pub dependencies: Vec<String>,
 }
 
+/// Which tracks.md signal decides a track's status when they disagree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatusSource {
+    /// Only the heading checkbox (`[x]`, `[~]`, `[ ]`).
+    Checkbox,
+    /// The `**Status**` field whenever present, else the checkbox.
+    Field,
+    /// The `**Status**` field unless it is absent or "new", else the checkbox.
+    #[default]
+    FieldThenCheckbox,
+}
+
+impl StatusSource {
+    /// Resolve an entry's status under this precedence.
+    pub fn resolve(self, entry: &IndexEntry) -> Status {
+        match self {
+            Self::Checkbox => entry.checkbox.to_status(),
+            Self::Field if entry.has_status_field => entry.status,
+            Self::FieldThenCheckbox if entry.status != Status::New => entry.status,
+            _ => entry.checkbox.to_status(),
+        }
+    }
+}
+
+/// File name of the track index in a conductor dir.
+pub const INDEX_FILE: &str = "tracks.md";
+
+/// Path of the track index in `conductor_dir`.
+pub fn index_path(conductor_dir: &Path) -> PathBuf {
+    conductor_dir.join(INDEX_FILE)
+}
+
+/// Where the track index is read from.
+#[derive(Debug, Clone)]
+pub enum IndexSource {
+    /// A tracks.md file on disk, re-read on every load.
+    File(PathBuf),
+    /// Index content captured once up front (e.g. piped in on stdin).
+    Content(String),
+}
+
+impl IndexSource {
+    /// Parse the index into tracks (with only index-level data populated).
+    pub fn parse(&self) -> Result<BTreeMap<TrackId, Track>, ParseError> {
+        self.parse_with(StatusSource::default())
+    }
+
+    /// Like [`IndexSource::parse`], resolving statuses with `status_source`.
+    pub fn parse_with(
+        &self,
+        status_source: StatusSource,
+    ) -> Result<BTreeMap<TrackId, Track>, ParseError> {
+        Ok(tracks_from_content_with(&self.content()?, status_source))
+    }
+
+    /// The raw index markdown.
+    pub fn content(&self) -> Result<Cow<'_, str>, ParseError> {
+        match self {
+            Self::File(path) => read_index_file(path).map(Cow::Owned),
+            Self::Content(content) => Ok(Cow::Borrowed(content)),
+        }
+    }
+}
+
 /// Parse `tracks.md` from the given conductor directory.
 /// Returns a map of TrackId → Track (with only index-level data populated).
 pub fn parse_index(conductor_dir: &Path) -> Result<BTreeMap<TrackId, Track>, ParseError> {
-    let index_path = conductor_dir.join("tracks.md");
-    let content = std::fs::read_to_string(&index_path).map_err(|e| {
+    parse_index_file(&index_path(conductor_dir))
+}
+
+/// Parse an index file at an arbitrary path.
+pub fn parse_index_file(index_path: &Path) -> Result<BTreeMap<TrackId, Track>, ParseError> {
+    Ok(tracks_from_content(&read_index_file(index_path)?))
+}
+
+fn read_index_file(index_path: &Path) -> Result<String, ParseError> {
+    read_text(index_path).map_err(|e| {
         if e.kind() == std::io::ErrorKind::NotFound {
-            ParseError::IndexNotFound(index_path.clone())
+            ParseError::IndexNotFound(index_path.to_path_buf())
         } else {
             ParseError::Io {
-                path: index_path.clone(),
+                path: index_path.to_path_buf(),
                 source: e,
             }
         }
-    })?;
+    })
+}
+
+/// Build the track map from raw index markdown.
+pub fn tracks_from_content(content: &str) -> BTreeMap<TrackId, Track> {
+    tracks_from_content_with(content, StatusSource::default())
+}
 
-    let entries = parse_index_content(&content);
+/// Like [`tracks_from_content`], resolving statuses with `status_source`.
+pub fn tracks_from_content_with(
+    content: &str,
+    status_source: StatusSource,
+) -> BTreeMap<TrackId, Track> {
+    let entries = parse_index_content(content);
 
     let mut tracks = BTreeMap::new();
     for entry in entries {
-        let status = if entry.status != Status::New {
-            entry.status
-        } else {
-            entry.checkbox.to_status()
-        };
+        let status = status_source.resolve(&entry);
 
         let track = Track {
             id: entry.id.clone(),
@@ -65,7 +148,7 @@ pub fn parse_index(conductor_dir: &Path) -> Result<BTreeMap<TrackId, Track>, Par
         tracks.insert(entry.id, track);
     }
 
-    Ok(tracks)
+    tracks
 }
 
 /// Parse the raw markdown content of tracks.md into index entries.
@@ -84,6 +167,11 @@ pub fn parse_index_content(content: &str) -> Vec<IndexEntry> {
     let mut in_strong = false;
     let mut strong_text = String::new();
     let mut field_key: Option<String> = None;
+    // Link seen inside the H2 itself (e.g. `## [ ] Track: [Title][ref]`)
+    let mut h2_link: Option<String> = None;
+    // Whether the current entry's ID came from a `/tracks/` link rather than
+    // the last-segment fallback, so a better link later in the body can win
+    let mut id_from_tracks_link = false;
 
     for event in parser {
         match event {
@@ -99,12 +187,20 @@ pub fn parse_index_content(content: &str) -> Vec<IndexEntry> {
                 in_h2 = true;
                 h2_text.clear();
                 body_text.clear();
+                h2_link = None;
+                id_from_tracks_link = false;
             }
 
             // End of H2 heading — parse the heading text
             Event::End(TagEnd::Heading(HeadingLevel::H2)) => {
                 in_h2 = false;
-                if let Some(entry) = parse_h2_heading(&h2_text) {
+                if let Some(mut entry) = parse_h2_heading(&h2_text) {
+                    if let Some(dest) = h2_link.take() {
+                        if let Some(track_id) = extract_track_id_from_link(&dest) {
+                            entry.id = TrackId::new(track_id);
+                            id_from_tracks_link = is_tracks_link(&dest);
+                        }
+                    }
                     current_entry = Some(entry);
                 }
             }
@@ -145,12 +241,19 @@ pub fn parse_index_content(content: &str) -> Vec<IndexEntry> {
             Event::Start(Tag::Emphasis) => {}
             Event::End(TagEnd::Emphasis) => {}
 
-            // Links — extract track ID from link target (first link only)
+            // Links — extract track ID from the link target. Inline and
+            // reference-style links both arrive here with the resolved
+            // destination. The first `/tracks/` link wins; any other link is
+            // only a fallback until one turns up.
             Event::Start(Tag::Link { dest_url, .. }) => {
-                if let Some(ref mut entry) = current_entry {
-                    if entry.id.as_str().is_empty() {
+                if in_h2 {
+                    h2_link.get_or_insert_with(|| dest_url.to_string());
+                } else if let Some(ref mut entry) = current_entry {
+                    let tracks_link = is_tracks_link(&dest_url);
+                    if entry.id.as_str().is_empty() || (tracks_link && !id_from_tracks_link) {
                         if let Some(track_id) = extract_track_id_from_link(&dest_url) {
                             entry.id = TrackId::new(track_id);
+                            id_from_tracks_link = tracks_link;
                         }
                     }
                 }
@@ -203,13 +306,11 @@ fn parse_h2_heading(text: &str) -> Option<IndexEntry> {
     // Some headings are section headers like "## Autopsy Remediation Tracks"
     let track_marker = text.find("Track:")?;
 
-    // Parse checkbox: [x], [ ], [~], [-]
+    // Parse checkbox: [x], [~], [-]; anything else (including [ ]) is unchecked
     let checkbox = if text.starts_with("[x]") || text.starts_with("[X]") {
         CheckboxStatus::Checked
     } else if text.starts_with("[~]") || text.starts_with("[-]") {
         CheckboxStatus::InProgress
-    } else if text.starts_with("[ ]") {
-        CheckboxStatus::Unchecked
     } else {
         CheckboxStatus::Unchecked
     };
@@ -223,6 +324,7 @@ fn parse_h2_heading(text: &str) -> Option<IndexEntry> {
         .trim()
         .to_string();
 
+    let (title, title_priority) = split_title_priority(&title);
     if title.is_empty() {
         return None;
     }
@@ -232,13 +334,66 @@ fn parse_h2_heading(text: &str) -> Option<IndexEntry> {
         title,
         checkbox,
         status: Status::New, // will be overridden from **Status** field
-        priority: Priority::Medium,
+        has_status_field: false,
+        // A **Priority** field, applied later, overrides a title marker
+        priority: title_priority.unwrap_or_default(),
         tags: Vec::new(),
         branch: None,
         dependencies: Vec::new(),
     })
 }
 
+/// Strip priority markers (`[P0]`, `🔥`, `URGENT`, …) from the start and end
+/// of a title, returning the remaining title and the most urgent marker's
+/// priority. Markers mid-title are left alone, so "Handle P0 incidents" keeps
+/// its wording.
+fn split_title_priority(title: &str) -> (String, Option<Priority>) {
+    let mut words: Vec<&str> = title.split_whitespace().collect();
+    let mut priority: Option<Priority> = None;
+    let mut take = |word: &str| {
+        let marker = title_marker_priority(word);
+        if let Some(p) = marker {
+            priority = Some(priority.map_or(p, |q| q.min(p)));
+        }
+        marker.is_some()
+    };
+    while words.first().is_some_and(|w| take(w)) {
+        words.remove(0);
+    }
+    while words.last().is_some_and(|w| take(w)) {
+        words.pop();
+    }
+    if words.is_empty() {
+        // Nothing but markers: keep them as the title rather than drop the track
+        return (title.to_string(), priority);
+    }
+    (words.join(" "), priority)
+}
+
+/// Priority of a single title word used as a marker: `P0`–`P3` (optionally
+/// bracketed), urgency emoji, or an urgency keyword that is bracketed or
+/// written in capitals (so a title starting "Critical Data …" is untouched).
+fn title_marker_priority(word: &str) -> Option<Priority> {
+    let bare = word.trim_matches(|c| matches!(c, '[' | ']' | '(' | ')' | ':' | '\u{fe0f}'));
+    let bracketed = bare.len() < word.trim_end_matches(':').len();
+    let keyword_case = bracketed || bare.chars().all(|c| !c.is_lowercase());
+    match bare {
+        "🚨" => Some(Priority::Critical),
+        "🔥" => Some(Priority::High),
+        _ if bare.eq_ignore_ascii_case("p0") => Some(Priority::Critical),
+        _ if bare.eq_ignore_ascii_case("p1") => Some(Priority::High),
+        _ if bare.eq_ignore_ascii_case("p2") => Some(Priority::Medium),
+        _ if bare.eq_ignore_ascii_case("p3") => Some(Priority::Low),
+        _ if !keyword_case => None,
+        _ => match bare.to_ascii_lowercase().as_str() {
+            "critical" | "urgent" | "blocker" => Some(Priority::Critical),
+            "high" => Some(Priority::High),
+            "low" => Some(Priority::Low),
+            _ => None,
+        },
+    }
+}
+
 /// Extract track ID from a link like `./conductor/tracks/some_track_id/`
 /// or `./tracks/some_track_id/`
 fn extract_track_id_from_link(url: &str) -> Option<String> {
@@ -255,6 +410,11 @@ fn extract_track_id_from_link(url: &str) -> Option<String> {
     url.rsplit('/').next().map(|s| s.to_string())
 }
 
+/// Whether a link points into a `tracks/` directory.
+fn is_tracks_link(url: &str) -> bool {
+    url.trim_end_matches('/').contains("/tracks/")
+}
+
 /// Apply a parsed field value to the current entry.
 fn apply_field(entry: &mut IndexEntry, key: &str, value: &str) {
     let value = value.trim();
@@ -264,6 +424,7 @@ fn apply_field(entry: &mut IndexEntry, key: &str, value: &str) {
         }
         "Status" => {
             entry.status = Status::from_str_loose(value);
+            entry.has_status_field = true;
         }
         "Tags" => {
             entry.tags = value
@@ -401,6 +562,44 @@ mod tests {
         assert_eq!(entries[0].priority, Priority::High);
     }
 
+    #[test]
+    fn test_priority_from_title_markers() {
+        let md = r#"# Tracks
+
+## [ ] Track: [P0] Payments Outage
+*Link: [./tracks/payments/](./tracks/payments/)*
+
+## [ ] Track: 🔥 Flaky Login
+*Link: [./tracks/login/](./tracks/login/)*
+
+## [ ] Track: 🔥 CRITICAL Data Loss
+*Link: [./tracks/data_loss/](./tracks/data_loss/)*
+
+## [ ] Track: Critical Data Integrity Fixes [P3]
+*Link: [./tracks/integrity/](./tracks/integrity/)*
+**Priority**: High
+
+## [ ] Track: Handle P0 Incidents
+*Link: [./tracks/incidents/](./tracks/incidents/)*
+"#;
+        let entries = parse_index_content(md);
+        let found: Vec<(&str, Priority)> = entries
+            .iter()
+            .map(|e| (e.title.as_str(), e.priority))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("Payments Outage", Priority::Critical),
+                ("Flaky Login", Priority::High),
+                ("Data Loss", Priority::Critical),
+                // The field wins; capitalised "Critical" is part of the title
+                ("Critical Data Integrity Fixes", Priority::High),
+                ("Handle P0 Incidents", Priority::Medium),
+            ]
+        );
+    }
+
     #[test]
     fn test_link_id_takes_precedence_over_field() {
         let md = r#"# Tracks
@@ -448,4 +647,88 @@ mod tests {
         assert_eq!(entries[1].id.as_str(), "compliance_enhancements_20260127");
         assert_eq!(entries[1].checkbox, CheckboxStatus::Unchecked);
     }
+
+    #[test]
+    fn test_status_source_precedence() {
+        // Checkbox says done; one field disagrees, one explicitly says "new"
+        let md = r#"# Tracks
+
+## [x] Track: Field Disagrees
+*Link: [./tracks/field_blocked/](./tracks/field_blocked/)*
+**Status**: Blocked
+
+## [x] Track: Field Says New
+*Link: [./tracks/field_new/](./tracks/field_new/)*
+**Status**: Not_started
+
+## [~] Track: No Field
+*Link: [./tracks/no_field/](./tracks/no_field/)*
+"#;
+        let status = |source, id: &str| {
+            tracks_from_content_with(md, source)
+                .get(&TrackId::new(id))
+                .unwrap()
+                .status
+        };
+
+        assert_eq!(
+            status(StatusSource::Checkbox, "field_blocked"),
+            Status::Complete
+        );
+        assert_eq!(
+            status(StatusSource::Checkbox, "field_new"),
+            Status::Complete
+        );
+
+        assert_eq!(
+            status(StatusSource::Field, "field_blocked"),
+            Status::Blocked
+        );
+        assert_eq!(status(StatusSource::Field, "field_new"), Status::New);
+
+        assert_eq!(
+            status(StatusSource::FieldThenCheckbox, "field_blocked"),
+            Status::Blocked
+        );
+        assert_eq!(
+            status(StatusSource::FieldThenCheckbox, "field_new"),
+            Status::Complete
+        );
+
+        // Without a field every mode falls back to the checkbox
+        for source in [
+            StatusSource::Checkbox,
+            StatusSource::Field,
+            StatusSource::FieldThenCheckbox,
+        ] {
+            assert_eq!(status(source, "no_field"), Status::InProgress);
+        }
+    }
+
+    #[test]
+    fn test_reference_style_links() {
+        let md = r#"# Tracks
+
+## [ ] Track: Body Reference
+See the [design notes](https://example.com/notes) first.
+*Link: [track dir][body]*
+
+## [x] Track: [Heading Reference][heading]
+
+## [~] Track: Shortcut
+*Link: [shortcut]*
+
+[body]: ./conductor/tracks/body_ref_20260101/
+[heading]: ./tracks/heading_ref/
+[shortcut]: ./tracks/shortcut_ref/
+"#;
+        let ids: Vec<String> = parse_index_content(md)
+            .into_iter()
+            .map(|e| e.id.to_string())
+            .collect();
+        assert_eq!(
+            ids,
+            vec!["body_ref_20260101", "heading_ref", "shortcut_ref"]
+        );
+    }
 }
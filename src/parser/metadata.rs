@@ -1,19 +1,29 @@
-//! Parse `metadata.json` and `meta.yaml` — per-track metadata files.
+//! Parse `metadata.json`, `meta.yaml` and `meta.toml` — per-track metadata
+//! files, looked for in that order; the first one present is used.
 //!
 //! Two schemas exist in the wild:
 //!   Schema A (older): { id, name, status, owner, start_date, end_date, description, dependencies, tags }
 //!   Schema B (newer): { track_id, type, status, created_at, updated_at, description }
 //!   YAML format:      { name, status, priority, created, branch, tags, completed, commits }
+//!   TOML format:      schema B's keys, plus the YAML date names; dates may be
+//!                     strings or TOML datetimes
 //!
-//! We handle all three with serde defaults so missing fields are fine.
+//! Any of them may also list `completed_tasks` — task texts to mark done
+//! regardless of their plan.md checkbox — an `effort` (or `story_points`)
+//! estimate, `blocked_by_external` — blockers that aren't tracks — and
+//! `total_tasks`, the expected plan size.
+//!
+//! We handle all of them with serde defaults so missing fields are fine.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
 use serde::Deserialize;
+use serde_json::Value;
 
 use crate::model::{Priority, Status, TrackMetadata, TrackType};
 use crate::parser::error::ParseError;
+use crate::parser::{read_text, strip_bom, write_atomic};
 
 // ---------------------------------------------------------------------------
 // JSON deserialization (handles both schema A and B)
@@ -45,6 +55,8 @@ struct RawJsonMetadata {
     #[serde(default)]
     dependencies: Vec<String>,
     #[serde(default)]
+    blocked_by_external: Vec<String>,
+    #[serde(default)]
     tags: Vec<String>,
     #[serde(default)]
     branch: Option<String>,
@@ -52,6 +64,12 @@ struct RawJsonMetadata {
     description: Option<String>,
     #[serde(default)]
     owner: Option<String>,
+    #[serde(default)]
+    completed_tasks: Vec<String>,
+    #[serde(default, alias = "story_points")]
+    effort: Option<f32>,
+    #[serde(default, alias = "tasks_total")]
+    total_tasks: Option<usize>,
 }
 
 // ---------------------------------------------------------------------------
@@ -76,15 +94,64 @@ struct RawYamlMetadata {
     #[serde(default)]
     tags: Vec<String>,
     #[serde(default)]
+    blocked_by_external: Vec<String>,
+    #[serde(default)]
     commits: Vec<String>,
+    #[serde(default)]
+    completed_tasks: Vec<String>,
+    #[serde(default, alias = "story_points")]
+    effort: Option<f32>,
+    #[serde(default, alias = "tasks_total")]
+    total_tasks: Option<usize>,
+}
+
+// ---------------------------------------------------------------------------
+// TOML deserialization
+// ---------------------------------------------------------------------------
+
+#[derive(Deserialize, Debug, Default)]
+#[allow(dead_code)]
+struct RawTomlMetadata {
+    #[serde(default, alias = "track_id")]
+    id: Option<String>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    status: Option<Status>,
+    #[serde(default)]
+    priority: Option<Priority>,
+    #[serde(default, rename = "type")]
+    track_type: Option<TrackType>,
+    #[serde(default, alias = "created", alias = "start_date")]
+    created_at: Option<toml::Value>,
+    #[serde(default, alias = "completed", alias = "end_date")]
+    updated_at: Option<toml::Value>,
+    #[serde(default)]
+    dependencies: Vec<String>,
+    #[serde(default)]
+    blocked_by_external: Vec<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    branch: Option<String>,
+    #[serde(default)]
+    owner: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    completed_tasks: Vec<String>,
+    #[serde(default, alias = "story_points")]
+    effort: Option<f32>,
+    #[serde(default, alias = "tasks_total")]
+    total_tasks: Option<usize>,
 }
 
 // ---------------------------------------------------------------------------
 // Public API
 // ---------------------------------------------------------------------------
 
-/// Try to load metadata for a track. Tries metadata.json first, then meta.yaml.
-/// Returns Ok(None) if neither file exists.
+/// Try to load metadata for a track. Tries metadata.json first, then
+/// meta.yaml, then meta.toml. Returns Ok(None) if none of them exists.
 pub fn parse_metadata(
     track_dir: &Path,
     track_id: &str,
@@ -93,7 +160,7 @@ pub fn parse_metadata(
     let yaml_path = track_dir.join("meta.yaml");
 
     if json_path.exists() {
-        let content = std::fs::read_to_string(&json_path).map_err(|e| ParseError::Io {
+        let content = read_text(&json_path).map_err(|e| ParseError::Io {
             path: json_path.clone(),
             source: e,
         })?;
@@ -101,23 +168,36 @@ pub fn parse_metadata(
     }
 
     if yaml_path.exists() {
-        let content = std::fs::read_to_string(&yaml_path).map_err(|e| ParseError::Io {
+        let content = read_text(&yaml_path).map_err(|e| ParseError::Io {
             path: yaml_path.clone(),
             source: e,
         })?;
         return parse_yaml_metadata(&content, track_id).map(Some);
     }
 
+    let toml_path = track_dir.join("meta.toml");
+    if toml_path.exists() {
+        let content = read_text(&toml_path).map_err(|e| ParseError::Io {
+            path: toml_path.clone(),
+            source: e,
+        })?;
+        return parse_toml_metadata(&content, track_id).map(Some);
+    }
+
     Ok(None)
 }
 
-/// Parse JSON metadata content.
+/// Parse JSON metadata content. Besides a bare object, accepts the shapes
+/// some tools emit: a single-element array, or an object whose only key is
+/// `track` or `metadata`.
 pub fn parse_json_metadata(content: &str, track_id: &str) -> Result<TrackMetadata, ParseError> {
+    let invalid = |e: serde_json::Error| ParseError::MetadataInvalid {
+        track_id: track_id.to_string(),
+        message: e.to_string(),
+    };
+    let value: Value = serde_json::from_str(content).map_err(invalid)?;
     let raw: RawJsonMetadata =
-        serde_json::from_str(content).map_err(|e| ParseError::MetadataInvalid {
-            track_id: track_id.to_string(),
-            message: e.to_string(),
-        })?;
+        serde_json::from_value(unwrap_json_metadata(value)).map_err(invalid)?;
 
     let created_at = raw
         .created_at
@@ -138,12 +218,47 @@ pub fn parse_json_metadata(content: &str, track_id: &str) -> Result<TrackMetadat
         created_at,
         updated_at,
         dependencies: raw.dependencies,
+        external_blockers: raw.blocked_by_external,
         tags: raw.tags,
         branch: raw.branch,
+        owner: raw.owner.filter(|o| !o.trim().is_empty()),
         description: raw.description,
+        completed_tasks: raw.completed_tasks,
+        effort: raw.effort,
+        declared_tasks: raw.total_tasks,
     })
 }
 
+/// Key of a `{"track": {…}}` / `{"metadata": {…}}` wrapper object.
+fn wrapper_key(map: &serde_json::Map<String, Value>) -> Option<&'static str> {
+    if map.len() != 1 {
+        return None;
+    }
+    ["track", "metadata"]
+        .into_iter()
+        .find(|key| map.get(*key).is_some_and(Value::is_object))
+}
+
+/// Peel a wrapping array (first element) or `{"track": …}` / `{"metadata": …}`
+/// object off the metadata; anything else is returned as is.
+fn unwrap_json_metadata(value: Value) -> Value {
+    match value {
+        Value::Array(items) => items
+            .into_iter()
+            .next()
+            .map(unwrap_json_metadata)
+            .unwrap_or(Value::Null),
+        Value::Object(mut map) => match wrapper_key(&map) {
+            Some(key) => map
+                .remove(key)
+                .map(unwrap_json_metadata)
+                .unwrap_or_default(),
+            None => Value::Object(map),
+        },
+        other => other,
+    }
+}
+
 /// Parse YAML metadata content.
 pub fn parse_yaml_metadata(content: &str, track_id: &str) -> Result<TrackMetadata, ParseError> {
     let raw: RawYamlMetadata =
@@ -162,17 +277,327 @@ pub fn parse_yaml_metadata(content: &str, track_id: &str) -> Result<TrackMetadat
         created_at,
         updated_at,
         dependencies: Vec::new(),
+        external_blockers: raw.blocked_by_external,
         tags: raw.tags,
         branch: raw.branch,
+        owner: None,
         description: None,
+        completed_tasks: raw.completed_tasks,
+        effort: raw.effort,
+        declared_tasks: raw.total_tasks,
     })
 }
 
+/// Parse TOML metadata content.
+pub fn parse_toml_metadata(content: &str, track_id: &str) -> Result<TrackMetadata, ParseError> {
+    let raw: RawTomlMetadata =
+        toml::from_str(content).map_err(|e| ParseError::MetadataInvalid {
+            track_id: track_id.to_string(),
+            message: e.to_string(),
+        })?;
+
+    // Quoted dates arrive as strings, bare ones as TOML datetimes
+    let date = |value: Option<toml::Value>| match value? {
+        toml::Value::String(s) => parse_datetime(&s),
+        toml::Value::Datetime(dt) => parse_datetime(&dt.to_string()),
+        _ => None,
+    };
+
+    Ok(TrackMetadata {
+        status: raw.status.unwrap_or_default(),
+        priority: raw.priority.unwrap_or_default(),
+        track_type: raw.track_type.unwrap_or_default(),
+        created_at: date(raw.created_at),
+        updated_at: date(raw.updated_at),
+        dependencies: raw.dependencies,
+        external_blockers: raw.blocked_by_external,
+        tags: raw.tags,
+        branch: raw.branch,
+        owner: raw.owner.filter(|o| !o.trim().is_empty()),
+        description: raw.description,
+        completed_tasks: raw.completed_tasks,
+        effort: raw.effort,
+        declared_tasks: raw.total_tasks,
+    })
+}
+
+/// Metadata file names, in the order [`parse_metadata`] looks for them.
+pub const METADATA_FILES: [&str; 3] = ["metadata.json", "meta.yaml", "meta.toml"];
+
+/// Set the track's status in whichever metadata file it has, and its
+/// last-updated date to `updated_at`, editing only those values so key order,
+/// formatting and comments survive. Returns the edited file.
+///
+/// The date goes in the field the parser reads it from (`updated_at`, or
+/// `completed` in meta.yaml), added after the status when the file has none.
+/// Refuses (with [`ParseError::MetadataInvalid`]) rather than guess when the
+/// track has several metadata files, none, or a file without exactly one
+/// `status` field, and when the edit would not read back as `status`.
+pub fn write_metadata_status(
+    track_dir: &Path,
+    track_id: &str,
+    status: &str,
+    updated_at: DateTime<Utc>,
+) -> Result<PathBuf, ParseError> {
+    let refuse = |message: String| ParseError::MetadataInvalid {
+        track_id: track_id.to_string(),
+        message,
+    };
+    let present: Vec<&str> = METADATA_FILES
+        .into_iter()
+        .filter(|name| track_dir.join(name).exists())
+        .collect();
+    let file_name = match present.as_slice() {
+        [name] => *name,
+        [] => {
+            return Err(refuse(
+                "no metadata.json, meta.yaml or meta.toml".to_string(),
+            ))
+        }
+        several => {
+            return Err(refuse(format!(
+                "{} exist; not editing any",
+                several.join(" and ")
+            )))
+        }
+    };
+    let path = track_dir.join(file_name);
+
+    let io_err = |e| ParseError::Io {
+        path: path.clone(),
+        source: e,
+    };
+    let raw = std::fs::read_to_string(&path).map_err(io_err)?;
+    let content = strip_bom(&raw);
+    let bom = &raw[..raw.len() - content.len()];
+    let stamp = updated_at.to_rfc3339_opts(SecondsFormat::Secs, true);
+    let updated = match file_name {
+        "metadata.json" => set_json_status(content, status)
+            .and_then(|c| set_json_updated(&c, &stamp))
+            .ok_or_else(|| refuse(format!("{file_name} has no single status field to edit"))),
+        "meta.yaml" => set_yaml_status(content, status)
+            .and_then(|c| set_yaml_field(&c, "completed", &stamp))
+            .ok_or_else(|| refuse(format!("{file_name} has no single status field to edit"))),
+        _ => set_toml_field(content, "status", status)
+            .and_then(|c| set_toml_updated(&c, &stamp))
+            .ok_or_else(|| refuse(format!("{file_name} has no single status field to edit"))),
+    }?;
+
+    let reread = match file_name {
+        "metadata.json" => parse_json_metadata(&updated, track_id)?,
+        "meta.yaml" => parse_yaml_metadata(&updated, track_id)?,
+        _ => parse_toml_metadata(&updated, track_id)?,
+    };
+    if reread.status != Status::from_str_loose(status) {
+        return Err(refuse(format!(
+            "editing the status field of {file_name} would not change the track's status"
+        )));
+    }
+    write_atomic(&path, &format!("{bom}{updated}")).map_err(io_err)?;
+    Ok(path)
+}
+
+/// Spans of every `"key": …` field in metadata.json text: the key's offset,
+/// then the value's span when it is a plain string (`None` otherwise).
+fn json_string_fields(content: &str, key: &str) -> Vec<(usize, Option<(usize, usize)>)> {
+    let quoted = format!("\"{key}\"");
+    content
+        .match_indices(quoted.as_str())
+        .filter(|(at, _)| !content[..*at].ends_with('\\'))
+        .filter_map(|(at, key)| {
+            let rest = content[at + key.len()..].trim_start().strip_prefix(':')?;
+            let value = rest.trim_start();
+            let start = content.len() - value.len();
+            // A key whose value isn't a plain string still counts, as ambiguous
+            let len = value
+                .strip_prefix('"')
+                .and_then(|v| v.find(['"', '\\']))
+                .filter(|end| value[end + 1..].starts_with('"'));
+            Some((at, len.map(|end| (start, start + end + 2))))
+        })
+        .collect()
+}
+
+/// Replace the value of the one `"key": "…"` field in metadata.json text.
+/// `None` when there is no such field, more than one, or a non-string one.
+fn set_json_string(content: &str, key: &str, value: &str) -> Option<String> {
+    let [(_, Some((start, end)))] = json_string_fields(content, key)[..] else {
+        return None;
+    };
+    Some(format!(
+        "{}{}{}",
+        &content[..start],
+        serde_json::Value::String(value.to_string()),
+        &content[end..]
+    ))
+}
+
+/// Replace the value of the one `"status": "…"` field in metadata.json text.
+/// `None` when there is no such field, more than one, or a non-string one.
+pub fn set_json_status(content: &str, status: &str) -> Option<String> {
+    set_json_string(content, "status", status)
+}
+
+/// Set the last-updated date in metadata.json text: `updated_at`, or schema
+/// A's `end_date`, else a new `updated_at` field after `status`.
+fn set_json_updated(content: &str, stamp: &str) -> Option<String> {
+    for key in ["updated_at", "end_date"] {
+        if !json_string_fields(content, key).is_empty() {
+            return set_json_string(content, key, stamp);
+        }
+    }
+    let [(key_at, Some((_, end)))] = json_string_fields(content, "status")[..] else {
+        return None;
+    };
+    // On a line of its own like the status field, or right after it
+    let line_start = content[..key_at].rfind('\n').map_or(0, |i| i + 1);
+    let indent = &content[line_start..key_at];
+    let separator = if indent.trim().is_empty() && line_start > 0 {
+        format!("\n{indent}")
+    } else {
+        " ".to_string()
+    };
+    Some(format!(
+        "{},{separator}\"updated_at\": {}{}",
+        &content[..end],
+        serde_json::Value::String(stamp.to_string()),
+        &content[end..]
+    ))
+}
+
+/// Replace the value of the one top-level `status:` line in meta.yaml text,
+/// keeping any trailing comment. `None` unless there is exactly one.
+pub fn set_yaml_status(content: &str, status: &str) -> Option<String> {
+    let prefix = "status:";
+    if content.lines().filter(|l| l.starts_with(prefix)).count() != 1 {
+        return None;
+    }
+    set_yaml_field(content, "status", status)
+}
+
+/// Replace the value of the top-level `key:` line in meta.yaml text, keeping
+/// any trailing comment, or append the line when there is none. `None` when
+/// there are several.
+fn set_yaml_field(content: &str, key: &str, value: &str) -> Option<String> {
+    let prefix = format!("{key}:");
+    let mut lines: Vec<String> = content.split_inclusive('\n').map(String::from).collect();
+    let mut matching = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with(&prefix))
+        .map(|(i, _)| i);
+    let Some(index) = matching.next() else {
+        let ending = if content.contains("\r\n") {
+            "\r\n"
+        } else {
+            "\n"
+        };
+        let separator = if content.is_empty() || content.ends_with('\n') {
+            ""
+        } else {
+            ending
+        };
+        return Some(format!("{content}{separator}{key}: {value}{ending}"));
+    };
+    if matching.next().is_some() {
+        return None;
+    }
+    let line = &lines[index];
+    let body = line.trim_end_matches(['\r', '\n']);
+    let ending = &line[body.len()..];
+    // The comment with the spacing before it
+    let comment = body
+        .find(" #")
+        .map_or("", |at| &body[body[..at].trim_end().len()..]);
+    lines[index] = format!("{key}: {value}{comment}{ending}");
+    Some(lines.concat())
+}
+
+/// Index of each top-level `key = …` line in meta.toml text (before the
+/// first table header).
+fn toml_key_lines(lines: &[String], key: &str) -> Vec<usize> {
+    lines
+        .iter()
+        .take_while(|line| !line.trim_start().starts_with('['))
+        .enumerate()
+        .filter(|(_, line)| {
+            line.strip_prefix(key)
+                .is_some_and(|rest| rest.trim_start().starts_with('='))
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Set the top-level `key` of meta.toml text to the string `value`, keeping
+/// any trailing comment, or add it before the first table when missing.
+/// `None` when the key appears more than once.
+fn set_toml_field(content: &str, key: &str, value: &str) -> Option<String> {
+    let mut lines: Vec<String> = content.split_inclusive('\n').map(String::from).collect();
+    let quoted = toml::Value::String(value.to_string()).to_string();
+    match toml_key_lines(&lines, key)[..] {
+        [index] => {
+            let line = &lines[index];
+            let body = line.trim_end_matches(['\r', '\n']);
+            let ending = &line[body.len()..];
+            let old = body[body.find('=')? + 1..].trim_start();
+            // A comment after a quoted value, or anywhere after a bare one
+            let value_end = match old.strip_prefix('"') {
+                Some(rest) => rest.find('"').map_or(old.len(), |end| end + 2),
+                None => old.find('#').unwrap_or(old.len()),
+            };
+            let comment = old[value_end..].trim();
+            let comment = if comment.starts_with('#') {
+                format!(" {comment}")
+            } else {
+                String::new()
+            };
+            lines[index] = format!("{key} = {quoted}{comment}{ending}");
+        }
+        [] => {
+            let ending = if content.contains("\r\n") {
+                "\r\n"
+            } else {
+                "\n"
+            };
+            let at = lines
+                .iter()
+                .position(|line| line.trim_start().starts_with('['))
+                .unwrap_or(lines.len());
+            if at > 0 && !lines[at - 1].ends_with('\n') {
+                lines[at - 1].push_str(ending);
+            }
+            lines.insert(at, format!("{key} = {quoted}{ending}"));
+        }
+        _ => return None,
+    }
+    Some(lines.concat())
+}
+
+/// Set the last-updated date in meta.toml text, in whichever of its names
+/// (`updated_at`, `completed`, `end_date`) the file uses.
+fn set_toml_updated(content: &str, stamp: &str) -> Option<String> {
+    let lines: Vec<String> = content.split_inclusive('\n').map(String::from).collect();
+    let key = ["updated_at", "completed", "end_date"]
+        .into_iter()
+        .find(|key| !toml_key_lines(&lines, key).is_empty())
+        .unwrap_or("updated_at");
+    set_toml_field(content, key, stamp)
+}
+
+/// Naive (timezone-less) datetime formats, interpreted as UTC.
+const DATETIME_FORMATS: &[&str] = &["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S"];
+
+/// Date-only formats, interpreted as midnight UTC.
+const DATE_FORMATS: &[&str] = &["%Y/%m/%d", "%b %d, %Y", "%B %d, %Y", "%d %b %Y"];
+
 /// Parse a datetime string flexibly. Handles:
 /// - ISO 8601: `2026-02-12T14:45:00Z`
 /// - Date only: `2026-02-04`
 /// - Date with parens: `(2026-02-06)` → strip parens
-fn parse_datetime(s: &str) -> Option<DateTime<Utc>> {
+/// - Space-separated datetime: `2026-02-12 14:45`
+/// - Slashed date: `2026/02/12`
+/// - Written date: `Feb 12, 2026`
+pub(crate) fn parse_datetime(s: &str) -> Option<DateTime<Utc>> {
     let s = s.trim().trim_matches('(').trim_matches(')').trim();
 
     // Try ISO 8601 first
@@ -185,6 +610,18 @@ fn parse_datetime(s: &str) -> Option<DateTime<Utc>> {
         return Some(date.and_hms_opt(0, 0, 0)?.and_utc());
     }
 
+    for fmt in DATETIME_FORMATS {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(s, fmt) {
+            return Some(dt.and_utc());
+        }
+    }
+
+    for fmt in DATE_FORMATS {
+        if let Ok(date) = NaiveDate::parse_from_str(s, fmt) {
+            return Some(date.and_hms_opt(0, 0, 0)?.and_utc());
+        }
+    }
+
     None
 }
 
@@ -192,6 +629,95 @@ fn parse_datetime(s: &str) -> Option<DateTime<Utc>> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_set_json_status_edits_only_the_value() {
+        let json = r#"{"status": "new", "title": "The \"status\" page", "tags": ["status"]}"#;
+        assert_eq!(
+            set_json_status(json, "completed").unwrap(),
+            r#"{"status": "completed", "title": "The \"status\" page", "tags": ["status"]}"#
+        );
+        // Missing, repeated, or non-string status fields are left alone
+        assert!(set_json_status(r#"{"title": "x"}"#, "completed").is_none());
+        assert!(set_json_status(
+            r#"{"status": "new", "phases": [{"status": "done"}]}"#,
+            "completed"
+        )
+        .is_none());
+        assert!(set_json_status(r#"{"status": null}"#, "completed").is_none());
+    }
+
+    #[test]
+    fn test_set_json_updated_replaces_or_adds_the_field() {
+        let stamp = "2026-10-16T09:30:00Z";
+        assert_eq!(
+            set_json_updated(
+                r#"{"status": "completed", "updated_at": "2026-01-01"}"#,
+                stamp
+            )
+            .unwrap(),
+            r#"{"status": "completed", "updated_at": "2026-10-16T09:30:00Z"}"#
+        );
+        assert_eq!(
+            set_json_updated(r#"{"status": "completed", "end_date": ""}"#, stamp).unwrap(),
+            r#"{"status": "completed", "end_date": "2026-10-16T09:30:00Z"}"#
+        );
+        // Added after the status, on its own line when the status has one
+        assert_eq!(
+            set_json_updated("{\n  \"status\": \"completed\"\n}\n", stamp).unwrap(),
+            "{\n  \"status\": \"completed\",\n  \"updated_at\": \"2026-10-16T09:30:00Z\"\n}\n"
+        );
+        assert_eq!(
+            set_json_updated(r#"{"status": "completed"}"#, stamp).unwrap(),
+            r#"{"status": "completed", "updated_at": "2026-10-16T09:30:00Z"}"#
+        );
+    }
+
+    #[test]
+    fn test_set_yaml_field_appends_missing_key() {
+        assert_eq!(
+            set_yaml_field("status: completed\r\n", "completed", "2026-10-16").unwrap(),
+            "status: completed\r\ncompleted: 2026-10-16\r\n"
+        );
+        assert_eq!(
+            set_yaml_field("status: completed", "completed", "2026-10-16").unwrap(),
+            "status: completed\ncompleted: 2026-10-16\n"
+        );
+        assert!(set_yaml_field("a: 1\na: 2\n", "a", "3").is_none());
+    }
+
+    #[test]
+    fn test_set_toml_field_keeps_tables_and_comments() {
+        let toml = "status = \"new\" # by hand\npriority = \"high\"\n\n[extra]\nstatus = \"x\"\n";
+        let updated = set_toml_field(toml, "status", "completed").unwrap();
+        assert_eq!(
+            updated,
+            toml.replace("status = \"new\"", "status = \"completed\"")
+        );
+        // Missing top-level keys go before the first table
+        assert_eq!(
+            set_toml_updated(&updated, "2026-10-16T09:30:00Z").unwrap(),
+            updated.replace(
+                "\n\n[extra]",
+                "\n\nupdated_at = \"2026-10-16T09:30:00Z\"\n[extra]"
+            )
+        );
+        assert_eq!(
+            set_toml_updated("completed = 2026-01-01\n", "2026-10-16").unwrap(),
+            "completed = \"2026-10-16\"\n"
+        );
+        assert!(set_toml_field("status = \"a\"\nstatus = \"b\"\n", "status", "c").is_none());
+    }
+
+    #[test]
+    fn test_set_yaml_status_keeps_comment_and_line_ending() {
+        let yaml = "name: X\r\nstatus: new  # todo\r\nphase:\r\n  status: open\r\n";
+        assert_eq!(
+            set_yaml_status(yaml, "completed").unwrap(),
+            "name: X\r\nstatus: completed  # todo\r\nphase:\r\n  status: open\r\n"
+        );
+        assert!(set_yaml_status("name: X\n", "completed").is_none());
+    }
+
     #[test]
     fn test_parse_json_schema_a() {
         let json = r#"{
@@ -211,6 +737,46 @@ mod tests {
         assert!(meta.description.is_some());
     }
 
+    #[test]
+    fn test_parse_json_owner() {
+        let meta = parse_json_metadata(r#"{"owner": "ldee"}"#, "test").unwrap();
+        assert_eq!(meta.owner.as_deref(), Some("ldee"));
+        let meta = parse_json_metadata(r#"{"owner": "  "}"#, "test").unwrap();
+        assert!(meta.owner.is_none());
+    }
+
+    #[test]
+    fn test_parse_external_blockers() {
+        let json = r#"{"dependencies": ["auth"], "blocked_by_external": ["SAP upgrade"]}"#;
+        let meta = parse_json_metadata(json, "test").unwrap();
+        assert_eq!(meta.dependencies, vec!["auth"]);
+        assert_eq!(meta.external_blockers, vec!["SAP upgrade"]);
+
+        let yaml = "blocked_by_external:\n  - Vendor API keys\n  - Legal sign-off\n";
+        let meta = parse_yaml_metadata(yaml, "test").unwrap();
+        assert_eq!(
+            meta.external_blockers,
+            vec!["Vendor API keys", "Legal sign-off"]
+        );
+    }
+
+    #[test]
+    fn test_parse_wrapped_json_metadata() {
+        for json in [
+            r#"{"track": {"status": "in_progress", "priority": "high"}}"#,
+            r#"{"metadata": {"status": "in_progress", "priority": "high"}}"#,
+            r#"[{"status": "in_progress", "priority": "high"}]"#,
+        ] {
+            let meta = parse_json_metadata(json, "test").unwrap();
+            assert_eq!(meta.status, Status::InProgress, "{json}");
+            assert_eq!(meta.priority, Priority::High, "{json}");
+        }
+        // A lone `track` key that isn't an object is an ordinary field
+        let meta = parse_json_metadata(r#"{"track": "x", "status": "blocked"}"#, "test").unwrap();
+        assert_eq!(meta.status, Status::Blocked);
+        assert!(parse_json_metadata("[]", "test").is_err());
+    }
+
     #[test]
     fn test_parse_json_schema_b() {
         let json = r#"{
@@ -247,6 +813,44 @@ tags:
         assert_eq!(meta.tags.len(), 2);
     }
 
+    #[test]
+    fn test_parse_toml() {
+        let toml = r#"
+track_id = "otel_collector_20260212"
+type = "feature"
+status = "in_progress"
+priority = "high"
+created_at = 2026-02-12T14:45:00Z
+completed = "2026-02-20"
+branch = "DSS-4074"
+owner = "ldee"
+dependencies = ["auth"]
+blocked_by_external = ["SAP upgrade"]
+tags = ["performance", "bug-fix"]
+story_points = 5
+"#;
+        let meta = parse_toml_metadata(toml, "test").unwrap();
+        assert_eq!(meta.status, Status::InProgress);
+        assert_eq!(meta.priority, Priority::High);
+        assert_eq!(meta.track_type, TrackType::Feature);
+        let created = meta.created_at.unwrap();
+        assert_eq!((created.day(), created.hour()), (12, 14));
+        assert_eq!(meta.updated_at.unwrap().day(), 20);
+        assert_eq!(meta.branch.as_deref(), Some("DSS-4074"));
+        assert_eq!(meta.owner.as_deref(), Some("ldee"));
+        assert_eq!(meta.dependencies, vec!["auth"]);
+        assert_eq!(meta.external_blockers, vec!["SAP upgrade"]);
+        assert_eq!(meta.tags.len(), 2);
+        assert_eq!(meta.effort, Some(5.0));
+    }
+
+    #[test]
+    fn test_parse_toml_bare_date_and_invalid() {
+        let meta = parse_toml_metadata("created = 2026-02-04\n", "test").unwrap();
+        assert_eq!(meta.created_at.unwrap().month(), 2);
+        assert!(parse_toml_metadata("status = ", "test").is_err());
+    }
+
     #[test]
     fn test_parse_datetime_iso() {
         let dt = parse_datetime("2026-02-12T14:45:00Z").unwrap();
@@ -261,10 +865,35 @@ tags:
         assert_eq!(dt.day(), 4);
     }
 
+    #[test]
+    fn test_parse_datetime_slashed() {
+        let dt = parse_datetime("2026/02/12").unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2026, 2, 12));
+    }
+
+    #[test]
+    fn test_parse_datetime_written_month() {
+        let dt = parse_datetime("Feb 12, 2026").unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2026, 2, 12));
+        let dt = parse_datetime("February 12, 2026").unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2026, 2, 12));
+    }
+
+    #[test]
+    fn test_parse_datetime_space_separated() {
+        let dt = parse_datetime("2026-02-12 14:45").unwrap();
+        assert_eq!((dt.day(), dt.hour(), dt.minute()), (12, 14, 45));
+        let dt = parse_datetime("2026-02-12 14:45:30").unwrap();
+        assert_eq!(dt.second(), 30);
+    }
+
     #[test]
     fn test_parse_datetime_invalid() {
         assert!(parse_datetime("not a date").is_none());
         assert!(parse_datetime("").is_none());
+        assert!(parse_datetime("2026/13/45").is_none());
+        assert!(parse_datetime("Febtember 12, 2026").is_none());
+        assert!(parse_datetime("2026-02-12 25:99").is_none());
     }
 
     #[test]
@@ -274,5 +903,14 @@ tags:
         assert_eq!(meta.priority, Priority::Medium);
     }
 
-    use chrono::Datelike;
+    #[test]
+    fn test_empty_toml() {
+        let meta = parse_toml_metadata("", "test").unwrap();
+        assert_eq!(meta.status, Status::New);
+        assert_eq!(meta.priority, Priority::Medium);
+        assert_eq!(meta.track_type, TrackType::Other);
+        assert!(meta.created_at.is_none());
+    }
+
+    use chrono::{Datelike, Timelike};
 }
@@ -1,34 +1,309 @@
 pub mod error;
+pub mod git;
 pub mod index;
 pub mod metadata;
+pub mod overview;
 pub mod plan;
+pub mod spec;
+pub mod validate;
 
-use std::collections::BTreeMap;
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use tracing::{debug, warn};
 
 use crate::model::{Track, TrackId};
 use crate::parser::error::ParseError;
+use crate::parser::index::{IndexSource, StatusSource};
+
+/// Default name of the directory (under the conductor dir) holding track folders.
+pub const DEFAULT_TRACKS_SUBDIR: &str = "tracks";
+
+/// Plan file looked for in each track folder unless `--plan-file` names others.
+pub const DEFAULT_PLAN_FILE: &str = "plan.md";
+
+/// Delay before the first read retry; doubled for each further attempt.
+const RETRY_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Longest wait between two read attempts, however many retries are asked for.
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(1);
 
 /// Load all tracks from a conductor directory.
 ///
 /// 1. Parse `tracks.md` to get the master list of tracks.
 /// 2. For each track, try to load `metadata.json` or `meta.yaml`.
-/// 3. For each track, try to load `plan.md`.
+/// 3. For each track, try to load `plan.md` (see [`ReadOptions::plan_files`]).
+/// 4. For each track, try to load `spec.md`.
 ///
-/// Partial failures (bad metadata, missing plan) are logged but don't
+/// Partial failures (bad metadata, missing plan or spec) are logged but don't
 /// prevent other tracks from loading.
 pub fn load_all_tracks(conductor_dir: &Path) -> Result<BTreeMap<TrackId, Track>, ParseError> {
-    let mut tracks = index::parse_index(conductor_dir)?;
+    load_all_tracks_with_subdir(conductor_dir, DEFAULT_TRACKS_SUBDIR)
+}
+
+/// Like [`load_all_tracks`], reading track folders from `tracks_subdir`
+/// (relative to the conductor dir; `""` or `"."` for the conductor dir itself).
+pub fn load_all_tracks_with_subdir(
+    conductor_dir: &Path,
+    tracks_subdir: &str,
+) -> Result<BTreeMap<TrackId, Track>, ParseError> {
+    load_all_tracks_with_options(conductor_dir, tracks_subdir, &ReadOptions::default())
+}
+
+/// Like [`load_all_tracks_with_subdir`], reading track files as `options` says.
+pub fn load_all_tracks_with_options(
+    conductor_dir: &Path,
+    tracks_subdir: &str,
+    options: &ReadOptions,
+) -> Result<BTreeMap<TrackId, Track>, ParseError> {
+    let tracks = index::parse_index(conductor_dir)?;
+    Ok(load_track_files(
+        tracks,
+        &conductor_dir.join(tracks_subdir),
+        options,
+    ))
+}
+
+/// How the files inside each track folder are read.
+#[derive(Debug, Clone)]
+pub struct ReadOptions {
+    /// Extra attempts for failed plan and metadata reads (see [`retry_read`]).
+    pub read_retries: u32,
+    /// Show every task of a Complete track as done, whatever its plan says.
+    pub normalize_complete: bool,
+    /// Plan file names in order of preference; the first that exists in a
+    /// track folder is its plan (e.g. `plan.md`, then `tasks.md`).
+    pub plan_files: Vec<String>,
+    /// Leave plan files unread, marking tracks [`Track::plan_pending`]
+    /// until [`load_pending_plan`] reads them. Saves parsing every plan of
+    /// a large board when only the selected track's is shown.
+    pub lazy_plans: bool,
+    /// Nest H3 phase headings under the H2 phase before them as sub-phases
+    /// (see [`plan::parse_plan_with_sub_phases`]).
+    pub sub_phases: bool,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self {
+            read_retries: 0,
+            normalize_complete: true,
+            plan_files: vec![DEFAULT_PLAN_FILE.to_string()],
+            lazy_plans: false,
+            sub_phases: false,
+        }
+    }
+}
+
+/// The first of `plan_files` that exists in `track_dir`.
+pub fn find_plan_file(track_dir: &Path, plan_files: &[String]) -> Option<PathBuf> {
+    plan_files
+        .iter()
+        .map(|name| track_dir.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Load all tracks from an arbitrary index source, reading per-track files
+/// from `tracks_dir`.
+pub fn load_tracks_from_source(
+    source: &IndexSource,
+    tracks_dir: &Path,
+) -> Result<BTreeMap<TrackId, Track>, ParseError> {
+    load_tracks_with_status_source(
+        source,
+        tracks_dir,
+        StatusSource::default(),
+        &ReadOptions::default(),
+    )
+}
+
+/// Like [`load_tracks_from_source`], with explicit checkbox/field precedence
+/// and track folders read as `options` says.
+pub fn load_tracks_with_status_source(
+    source: &IndexSource,
+    tracks_dir: &Path,
+    status_source: StatusSource,
+    options: &ReadOptions,
+) -> Result<BTreeMap<TrackId, Track>, ParseError> {
+    let tracks = source.parse_with(status_source)?;
+    Ok(load_track_files(tracks, tracks_dir, options))
+}
+
+/// Load tracks from `source` (with track folders under `conductor_dir`),
+/// merging in any `extra_dirs`, each with its own `tracks.md` and
+/// `tracks_subdir` (see [`merge_track_set`]). With no extra dirs
+/// `source_dir` stays unset. Track folders are read as `options` says.
+pub fn load_tracks_with_extra_dirs(
+    source: &IndexSource,
+    conductor_dir: &Path,
+    extra_dirs: &[PathBuf],
+    tracks_subdir: &str,
+    status_source: StatusSource,
+    options: &ReadOptions,
+) -> Result<BTreeMap<TrackId, Track>, ParseError> {
+    let primary = load_track_files(
+        source.parse_with(status_source)?,
+        &conductor_dir.join(tracks_subdir),
+        options,
+    );
+    if extra_dirs.is_empty() {
+        return Ok(primary);
+    }
+    let mut merged = BTreeMap::new();
+    merge_track_set(&mut merged, primary, conductor_dir);
+    for dir in extra_dirs {
+        let source = IndexSource::File(index::index_path(dir));
+        let tracks = load_track_files(
+            source.parse_with(status_source)?,
+            &dir.join(tracks_subdir),
+            options,
+        );
+        merge_track_set(&mut merged, tracks, dir);
+    }
+    Ok(merged)
+}
+
+/// Run `read`, retrying up to `retries` more times with exponential backoff
+/// when it fails. Smooths over files caught mid-write on network
+/// filesystems; only the last error is returned.
+pub fn retry_read<T>(
+    retries: u32,
+    mut read: impl FnMut() -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let mut attempt = 0;
+    loop {
+        match read() {
+            Err(e) if attempt < retries => {
+                debug!(attempt, error = %e, "read failed, retrying");
+                wait_before_retry(retry_backoff(attempt));
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Delay before retry number `attempt` (from 0), capped at
+/// [`MAX_RETRY_BACKOFF`].
+fn retry_backoff(attempt: u32) -> Duration {
+    2u32.checked_pow(attempt)
+        .map_or(MAX_RETRY_BACKOFF, |factor| {
+            RETRY_BACKOFF.saturating_mul(factor)
+        })
+        .min(MAX_RETRY_BACKOFF)
+}
+
+/// Sleep for `delay`, handing the worker thread back to the tokio runtime
+/// first when called from one, so the dashboard's input and watcher tasks
+/// keep running while a read is retried.
+fn wait_before_retry(delay: Duration) {
+    use tokio::runtime::{Handle, RuntimeFlavor};
+
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(|| std::thread::sleep(delay));
+        }
+        _ => std::thread::sleep(delay),
+    }
+}
+
+/// Add `tracks` loaded from `conductor_dir` to `merged`, recording their
+/// origin. An ID already present is re-keyed as `<origin>/<id>`, where origin
+/// names `conductor_dir` (see [`dir_label`]), and dependencies within
+/// `tracks` on it are rewritten to match.
+pub fn merge_track_set(
+    merged: &mut BTreeMap<TrackId, Track>,
+    tracks: BTreeMap<TrackId, Track>,
+    conductor_dir: &Path,
+) {
+    let renamed: HashMap<TrackId, TrackId> = tracks
+        .keys()
+        .filter(|id| merged.contains_key(*id))
+        .map(|id| {
+            let prefixed = TrackId::new(format!("{}/{}", dir_label(conductor_dir), id));
+            warn!(
+                track_id = id.as_str(),
+                renamed = prefixed.as_str(),
+                "duplicate track ID across conductor dirs"
+            );
+            (id.clone(), prefixed)
+        })
+        .collect();
+    for (id, mut track) in tracks {
+        track.source_dir = Some(conductor_dir.to_path_buf());
+        for dep in &mut track.dependencies {
+            if let Some(new_id) = renamed.get(dep) {
+                *dep = new_id.clone();
+            }
+        }
+        let id = renamed.get(&id).cloned().unwrap_or(id);
+        track.id = id.clone();
+        merged.insert(id, track);
+    }
+}
+
+/// Drop a leading UTF-8 byte order mark, as written by some Windows editors.
+pub fn strip_bom(content: &str) -> &str {
+    content.strip_prefix('\u{feff}').unwrap_or(content)
+}
 
-    let tracks_dir = conductor_dir.join("tracks");
+/// Read a markdown or metadata file as text, without any leading BOM.
+pub(crate) fn read_text(path: &Path) -> std::io::Result<String> {
+    let mut content = std::fs::read_to_string(path)?;
+    let bom_len = content.len() - strip_bom(&content).len();
+    content.drain(..bom_len);
+    Ok(content)
+}
 
+/// Replace the file at `path` with `contents` via a temporary file in the
+/// same folder, so readers (and a crash mid-write) never see it half written.
+pub fn write_atomic(path: &Path, contents: &str) -> std::io::Result<()> {
+    let name = path
+        .file_name()
+        .ok_or_else(|| std::io::Error::other("path has no file name"))?;
+    let mut tmp_name = std::ffi::OsString::from(".");
+    tmp_name.push(name);
+    tmp_name.push(".tmp");
+    let tmp = path.with_file_name(tmp_name);
+    std::fs::write(&tmp, contents)?;
+    std::fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp);
+    })
+}
+
+/// Short name for a conductor dir: its folder name, or the parent's name when
+/// the folder is the conventional `conductor` (e.g. `services/api/conductor` → `api`).
+pub fn dir_label(conductor_dir: &Path) -> String {
+    let name = |p: &Path| {
+        p.file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| p.display().to_string())
+    };
+    match conductor_dir.file_name() {
+        Some(n) if n == "conductor" => conductor_dir
+            .parent()
+            .filter(|p| p.file_name().is_some())
+            .map(name)
+            .unwrap_or_else(|| name(conductor_dir)),
+        _ => name(conductor_dir),
+    }
+}
+
+/// Merge metadata and plan files from `tracks_dir` into index-level tracks.
+fn load_track_files(
+    mut tracks: BTreeMap<TrackId, Track>,
+    tracks_dir: &Path,
+    options: &ReadOptions,
+) -> BTreeMap<TrackId, Track> {
+    let read_retries = options.read_retries;
     for (id, track) in tracks.iter_mut() {
         let track_dir = tracks_dir.join(id.as_str());
 
         // Load metadata
-        match metadata::parse_metadata(&track_dir, id.as_str()) {
+        match retry_read(read_retries, || {
+            metadata::parse_metadata(&track_dir, id.as_str())
+        }) {
             Ok(Some(meta)) => {
                 debug!(track_id = id.as_str(), "loaded metadata");
                 track.merge_metadata(meta);
@@ -42,27 +317,134 @@ pub fn load_all_tracks(conductor_dir: &Path) -> Result<BTreeMap<TrackId, Track>,
         }
 
         // Load plan
-        let plan_path = track_dir.join("plan.md");
-        if plan_path.exists() {
-            match plan::parse_plan(&plan_path) {
-                Ok(phases) => {
-                    debug!(track_id = id.as_str(), phases = phases.len(), "loaded plan");
-                    track.merge_plan(phases);
+        if let Some(plan_path) = find_plan_file(&track_dir, &options.plan_files) {
+            if options.lazy_plans {
+                track.plan_pending = true;
+            } else {
+                read_plan(track, &plan_path, options);
+            }
+        }
+        // Load spec
+        let spec_path = track_dir.join("spec.md");
+        if spec_path.exists() {
+            match retry_read(read_retries, || spec::parse_spec(&spec_path)) {
+                Ok(spec) => {
+                    debug!(track_id = id.as_str(), "loaded spec");
+                    track.merge_spec(spec);
                 }
                 Err(e) => {
-                    warn!(track_id = id.as_str(), error = %e, "failed to parse plan");
+                    warn!(track_id = id.as_str(), error = %e, "failed to parse spec");
                 }
             }
         }
+        // A pending plan has no tasks yet to check against the metadata
+        if let Some(warning) = track.task_count_warning().filter(|_| !track.plan_pending) {
+            warn!(track_id = id.as_str(), "{warning}");
+        }
     }
 
     // Auto-complete tasks for tracks marked as done — display-level normalization
     // so the dashboard shows 100% progress when metadata says Complete.
+    if options.normalize_complete {
+        for track in tracks.values_mut() {
+            if track.status == crate::model::Status::Complete {
+                track.mark_all_tasks_complete();
+            }
+        }
+    }
+
+    resolve_title_dependencies(&mut tracks);
+    tracks
+}
+
+/// Read the plan of a track loaded with [`ReadOptions::lazy_plans`] from
+/// `track_dir`, normalising it like a full load would. Does nothing for a
+/// track whose plan is not pending; returns whether a plan was read.
+pub fn load_pending_plan(track: &mut Track, track_dir: &Path, options: &ReadOptions) -> bool {
+    if !track.plan_pending {
+        return false;
+    }
+    track.plan_pending = false;
+    let Some(plan_path) = find_plan_file(track_dir, &options.plan_files) else {
+        return false;
+    };
+    if !read_plan(track, &plan_path, options) {
+        return false;
+    }
+    if options.normalize_complete && track.status == crate::model::Status::Complete {
+        track.mark_all_tasks_complete();
+    }
+    true
+}
+
+/// Parse `plan_path` into `track`, logging (and keeping any previous plan)
+/// on failure.
+fn read_plan(track: &mut Track, plan_path: &Path, options: &ReadOptions) -> bool {
+    match retry_read(options.read_retries, || {
+        plan::parse_plan_with_sub_phases(plan_path, options.sub_phases)
+    }) {
+        Ok(phases) => {
+            debug!(
+                track_id = track.id.as_str(),
+                phases = phases.len(),
+                "loaded plan"
+            );
+            track.merge_plan(phases);
+            true
+        }
+        Err(e) => {
+            warn!(track_id = track.id.as_str(), error = %e, "failed to parse plan");
+            false
+        }
+    }
+}
+
+/// Rewrite dependencies written as a track's title rather than its ID (e.g.
+/// `Auth Refactor` for `auth_refactor_20260101`) to that ID. Titles match
+/// case-insensitively; a title shared by several tracks resolves to none.
+/// Dependencies that match neither stay as written, so they are still
+/// reported as unresolved. A track listed both by ID and by title is kept
+/// once, at its first position.
+pub fn resolve_title_dependencies(tracks: &mut BTreeMap<TrackId, Track>) {
+    let mut by_title: HashMap<String, Option<TrackId>> = HashMap::new();
+    for track in tracks.values() {
+        by_title
+            .entry(track.title.trim().to_lowercase())
+            .and_modify(|id| *id = None)
+            .or_insert_with(|| Some(track.id.clone()));
+    }
+    let ids: Vec<TrackId> = tracks.keys().cloned().collect();
+
     for track in tracks.values_mut() {
-        if track.status == crate::model::Status::Complete {
-            track.mark_all_tasks_complete();
+        for dep in &mut track.dependencies {
+            if ids.binary_search(dep).is_ok() {
+                continue;
+            }
+            if let Some(Some(id)) = by_title.get(&dep.as_str().trim().to_lowercase()) {
+                debug!(
+                    track_id = track.id.as_str(),
+                    dependency = dep.as_str(),
+                    resolved = id.as_str(),
+                    "resolved dependency by track title"
+                );
+                *dep = id.clone();
+            }
         }
+        let mut seen = HashSet::new();
+        track.dependencies.retain(|dep| seen.insert(dep.clone()));
     }
+}
 
-    Ok(tracks)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_backoff_doubles_up_to_cap() {
+        assert_eq!(retry_backoff(0), RETRY_BACKOFF);
+        assert_eq!(retry_backoff(2), RETRY_BACKOFF * 4);
+        assert_eq!(retry_backoff(10), MAX_RETRY_BACKOFF);
+        // 2^32 doesn't fit in a u32: capped rather than overflowing
+        assert_eq!(retry_backoff(40), MAX_RETRY_BACKOFF);
+    }
 }
@@ -0,0 +1,80 @@
+//! Parse the board-level overview: the first section of `README.md` (or
+//! `overview.md`) in the conductor dir, e.g. "Q1 2026 Roadmap" plus a short
+//! description. A missing file just means no overview.
+
+use std::path::Path;
+
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+
+use crate::parser::read_text;
+
+/// Files checked for an overview, in order.
+pub const OVERVIEW_FILE_NAMES: [&str; 2] = ["README.md", "overview.md"];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BoardOverview {
+    /// Text of the leading heading; empty when the file starts with prose.
+    pub title: String,
+    /// Paragraphs and list items up to the next heading, one per line.
+    pub description: String,
+}
+
+impl BoardOverview {
+    /// One-line summary for the title bar: the heading, else the first line.
+    pub fn headline(&self) -> &str {
+        if self.title.is_empty() {
+            self.description.lines().next().unwrap_or_default()
+        } else {
+            &self.title
+        }
+    }
+}
+
+/// Read the overview from the first existing overview file in `conductor_dir`.
+pub fn read_overview(conductor_dir: &Path) -> Option<BoardOverview> {
+    OVERVIEW_FILE_NAMES
+        .iter()
+        .map(|name| conductor_dir.join(name))
+        .find(|path| path.is_file())
+        .and_then(|path| read_text(&path).ok())
+        .and_then(|content| parse_overview(&content))
+}
+
+/// Extract the first section: an optional leading heading and the text after
+/// it, stopping at the next heading. `None` when there is nothing to show.
+pub fn parse_overview(content: &str) -> Option<BoardOverview> {
+    let mut overview = BoardOverview::default();
+    let mut in_heading = false;
+    let mut line = String::new();
+
+    for event in Parser::new(content) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => {
+                if !overview.title.is_empty() || !overview.description.is_empty() {
+                    break;
+                }
+                in_heading = true;
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                overview.title = line.trim().to_string();
+                line.clear();
+                in_heading = false;
+            }
+            Event::Text(text) | Event::Code(text) => line.push_str(&text),
+            Event::SoftBreak | Event::HardBreak => line.push(' '),
+            Event::End(TagEnd::Paragraph | TagEnd::Item) if !in_heading => {
+                let text = line.trim();
+                if !text.is_empty() {
+                    if !overview.description.is_empty() {
+                        overview.description.push('\n');
+                    }
+                    overview.description.push_str(text);
+                }
+                line.clear();
+            }
+            _ => {}
+        }
+    }
+
+    (!overview.title.is_empty() || !overview.description.is_empty()).then_some(overview)
+}
@@ -1,62 +1,120 @@
 //! Parse `plan.md` — extract phases and tasks with checkbox state.
 //!
 //! Phases are identified by H2 (`##`) headings containing "Phase".
+//! H3 phase headings are top-level phases too, unless sub-phases are asked
+//! for: then those directly under an H2 phase are recorded as sub-phases
+//! (`depth == 1`).
 //! Tasks are list items starting with `- [x]` (done) or `- [ ]` (pending).
+//! A trailing `(due 2026-03-01)` marker becomes the task's due date, and a
+//! `(done 2026-02-10)` marker on a phase heading its completion date.
+//! Plain (non-checkbox) sub-bullets under a task continue its text; nested
+//! checkbox items are tasks of their own, with a `depth` counting the tasks
+//! they are nested under.
 //! Nested content (code blocks, descriptions) is skipped.
 
 use std::path::Path;
 
 use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
 
-use crate::model::{PhaseStatus, PlanPhase, PlanTask};
+use crate::model::{compute_phase_statuses, PhaseStatus, PlanPhase, PlanTask};
 use crate::parser::error::ParseError;
+use crate::parser::metadata::parse_datetime;
+use crate::parser::{read_text, strip_bom, write_atomic};
 
 /// Parse a plan.md file and return structured phases.
 pub fn parse_plan(plan_path: &Path) -> Result<Vec<PlanPhase>, ParseError> {
-    let content = std::fs::read_to_string(plan_path).map_err(|e| ParseError::Io {
+    parse_plan_with_sub_phases(plan_path, false)
+}
+
+/// Like [`parse_plan`], nesting H3 phases under their H2 phase when
+/// `sub_phases` is set.
+pub fn parse_plan_with_sub_phases(
+    plan_path: &Path,
+    sub_phases: bool,
+) -> Result<Vec<PlanPhase>, ParseError> {
+    let content = read_text(plan_path).map_err(|e| ParseError::Io {
         path: plan_path.to_path_buf(),
         source: e,
     })?;
 
-    Ok(parse_plan_content(&content))
+    Ok(parse_plan_content_with_sub_phases(&content, sub_phases))
 }
 
 /// Parse plan.md content into phases.  This is the core logic.
 pub fn parse_plan_content(content: &str) -> Vec<PlanPhase> {
+    parse_plan_content_with_sub_phases(content, false)
+}
+
+/// Like [`parse_plan_content`], nesting H3 phases under their H2 phase when
+/// `sub_phases` is set.
+pub fn parse_plan_content_with_sub_phases(content: &str, sub_phases: bool) -> Vec<PlanPhase> {
+    parse_plan_with_markers(content, sub_phases).0
+}
+
+/// Parse phases, also returning `(phase_index, byte_offset)` of each kept
+/// task's checkbox marker, in task order.
+fn parse_plan_with_markers(
+    content: &str,
+    sub_phases: bool,
+) -> (Vec<PlanPhase>, Vec<(usize, usize)>) {
     let opts = Options::ENABLE_TASKLISTS;
-    let parser = Parser::new_ext(content, opts);
+    let parser = Parser::new_ext(content, opts).into_offset_iter();
+    let mut markers: Vec<(usize, usize)> = Vec::new();
+    let mut marker_offset = 0;
 
     let mut phases: Vec<PlanPhase> = Vec::new();
     let mut in_heading = false;
-    let mut _heading_level: Option<HeadingLevel> = None;
     let mut heading_text = String::new();
+    // Whether the most recent H1/H2 heading opened a phase
+    let mut in_h2_phase = false;
     let mut in_task_item = false;
     let mut task_text = String::new();
     let mut task_done = false;
-
-    for event in parser {
+    // List item nesting, and the level of the item that opened the current task
+    let mut item_depth = 0usize;
+    let mut task_depth = 0usize;
+    // Levels of the open items that are tasks; a task nested in N of them is a
+    // subtask at depth N
+    let mut task_items: Vec<usize> = Vec::new();
+    let mut task_nesting = 0u8;
+
+    for (event, range) in parser {
         match event {
             Event::Start(Tag::Heading { level, .. }) => {
                 // Flush any pending task
-                flush_task(&mut phases, &mut in_task_item, &mut task_text, &task_done);
+                flush_task(
+                    &mut phases,
+                    &mut in_task_item,
+                    &mut task_text,
+                    (task_done, task_nesting),
+                    (marker_offset, &mut markers),
+                );
 
                 in_heading = true;
-                _heading_level = Some(level);
+                if level <= HeadingLevel::H2 {
+                    in_h2_phase = false;
+                }
                 heading_text.clear();
             }
 
             Event::End(TagEnd::Heading(level)) => {
                 in_heading = false;
-                let name = heading_text.trim().to_string();
+                let (name, completed_at) = split_date_marker(heading_text.trim(), "done");
 
                 // Only treat H2 or H3 headings that look like phases
                 if (level == HeadingLevel::H2 || level == HeadingLevel::H3)
                     && is_phase_heading(&name)
                 {
+                    let depth = usize::from(sub_phases && level == HeadingLevel::H3 && in_h2_phase);
+                    if level == HeadingLevel::H2 {
+                        in_h2_phase = true;
+                    }
                     phases.push(PlanPhase {
                         name,
                         status: PhaseStatus::Pending,
                         tasks: Vec::new(),
+                        depth,
+                        completed_at,
                     });
                 }
             }
@@ -64,15 +122,46 @@ pub fn parse_plan_content(content: &str) -> Vec<PlanPhase> {
             // Task list checkbox events from pulldown-cmark
             Event::TaskListMarker(checked) => {
                 // Flush any previous task first
-                flush_task(&mut phases, &mut in_task_item, &mut task_text, &task_done);
+                flush_task(
+                    &mut phases,
+                    &mut in_task_item,
+                    &mut task_text,
+                    (task_done, task_nesting),
+                    (marker_offset, &mut markers),
+                );
 
                 in_task_item = true;
                 task_done = checked;
                 task_text.clear();
+                task_depth = item_depth;
+                task_nesting = u8::try_from(task_items.len()).unwrap_or(u8::MAX);
+                task_items.push(item_depth);
+                marker_offset = range.start;
+            }
+
+            Event::Start(Tag::Item) => {
+                item_depth += 1;
+                // A sub-bullet of a task continues its text
+                if in_task_item {
+                    task_text.push(' ');
+                }
             }
 
             Event::End(TagEnd::Item) => {
-                flush_task(&mut phases, &mut in_task_item, &mut task_text, &task_done);
+                // Only the task's own item ends it, not a continuation sub-bullet
+                if item_depth == task_depth {
+                    flush_task(
+                        &mut phases,
+                        &mut in_task_item,
+                        &mut task_text,
+                        (task_done, task_nesting),
+                        (marker_offset, &mut markers),
+                    );
+                }
+                if task_items.last() == Some(&item_depth) {
+                    task_items.pop();
+                }
+                item_depth = item_depth.saturating_sub(1);
             }
 
             Event::Text(text) => {
@@ -106,25 +195,33 @@ pub fn parse_plan_content(content: &str) -> Vec<PlanPhase> {
     }
 
     // Flush final task
-    flush_task(&mut phases, &mut in_task_item, &mut task_text, &task_done);
+    flush_task(
+        &mut phases,
+        &mut in_task_item,
+        &mut task_text,
+        (task_done, task_nesting),
+        (marker_offset, &mut markers),
+    );
 
     // Compute phase statuses
     compute_phase_statuses(&mut phases);
 
-    phases
+    (phases, markers)
 }
 
-/// Flush a pending task into the current (last) phase.
+/// Flush a pending task into the current (last) phase, recording where its
+/// checkbox marker sits.
 fn flush_task(
     phases: &mut Vec<PlanPhase>,
     in_task_item: &mut bool,
     task_text: &mut String,
-    task_done: &bool,
+    (task_done, depth): (bool, u8),
+    (marker_offset, markers): (usize, &mut Vec<(usize, usize)>),
 ) {
     if !*in_task_item {
         return;
     }
-    let text = clean_task_text(task_text);
+    let (text, due) = split_date_marker(&clean_task_text(task_text), "due");
     if !text.is_empty() {
         // If no phase exists yet, create a default one
         if phases.is_empty() {
@@ -132,17 +229,136 @@ fn flush_task(
                 name: "Tasks".to_string(),
                 status: PhaseStatus::Pending,
                 tasks: Vec::new(),
+                depth: 0,
+                completed_at: None,
             });
         }
         phases.last_mut().unwrap().tasks.push(PlanTask {
             text,
-            done: *task_done,
+            done: task_done,
+            due,
+            depth,
         });
+        markers.push((phases.len() - 1, marker_offset));
     }
     *in_task_item = false;
     task_text.clear();
 }
 
+/// Set the checkbox of task `task_index` in phase `phase_index` (0-based, as
+/// parsed by [`parse_plan_content`]). Returns the edited content, or `None` if
+/// no such task exists. Only the marker character changes.
+pub fn set_task_state(
+    content: &str,
+    phase_index: usize,
+    task_index: usize,
+    done: bool,
+) -> Option<String> {
+    let (_, markers) = parse_plan_with_markers(content, false);
+    let offset = markers
+        .iter()
+        .filter(|(phase, _)| *phase == phase_index)
+        .nth(task_index)?
+        .1;
+    let (state, state_len) = marker_state(content, offset)?;
+    Some(format!(
+        "{}{}{}",
+        &content[..state],
+        if done { 'x' } else { ' ' },
+        &content[state + state_len..]
+    ))
+}
+
+/// Byte span of the state character inside the checkbox marker whose span
+/// starts at `offset`, e.g. the space in `[ ]`.
+fn marker_state(content: &str, offset: usize) -> Option<(usize, usize)> {
+    // The marker span starts at `[`; the state is the character after it
+    let open = offset + content[offset..].find('[')?;
+    let state = open + 1;
+    let state_len = content[state..].chars().next()?.len_utf8();
+    content[state + state_len..]
+        .starts_with(']')
+        .then_some((state, state_len))
+}
+
+/// Rewrite one task's checkbox in the plan at `plan_path`; see
+/// [`set_task_state`]. Errors, leaving the file alone, if the task does not
+/// exist or its text is no longer `expected_text` (the plan changed since
+/// the caller read it, so the index may point at another task).
+pub fn write_task_state(
+    plan_path: &Path,
+    phase_index: usize,
+    task_index: usize,
+    expected_text: &str,
+    done: bool,
+) -> Result<(), ParseError> {
+    let io_err = |e| ParseError::Io {
+        path: plan_path.to_path_buf(),
+        source: e,
+    };
+    let raw = std::fs::read_to_string(plan_path).map_err(io_err)?;
+    // Edit past any BOM so offsets match what the parser saw, then keep it
+    let content = strip_bom(&raw);
+    let bom = &raw[..raw.len() - content.len()];
+    let phases = parse_plan_content(content);
+    let current = phases
+        .get(phase_index)
+        .and_then(|phase| phase.tasks.get(task_index))
+        .map(|task| task.text.as_str());
+    if current.is_some_and(|text| text != expected_text) {
+        return Err(io_err(std::io::Error::other(format!(
+            "task {phase_index}.{task_index} changed on disk (expected '{expected_text}'); re-read the plan and retry"
+        ))));
+    }
+    let updated = set_task_state(content, phase_index, task_index, done).ok_or_else(|| {
+        io_err(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("no task {phase_index}.{task_index} in plan"),
+        ))
+    })?;
+    write_atomic(plan_path, &format!("{bom}{updated}")).map_err(io_err)
+}
+
+/// Tick every unticked task in `content`, changing only their markers.
+/// Returns the edited content and how many tasks were ticked.
+pub fn set_all_tasks_done(content: &str) -> (String, usize) {
+    let (phases, markers) = parse_plan_with_markers(content, false);
+    // Markers are recorded in task order, phase by phase
+    let undone = phases
+        .iter()
+        .flat_map(|phase| &phase.tasks)
+        .zip(&markers)
+        .filter(|(task, _)| !task.done)
+        .filter_map(|(_, (_, offset))| marker_state(content, *offset));
+    let mut updated = String::with_capacity(content.len());
+    let mut copied = 0;
+    let mut ticked = 0;
+    for (state, state_len) in undone {
+        updated.push_str(&content[copied..state]);
+        updated.push('x');
+        copied = state + state_len;
+        ticked += 1;
+    }
+    updated.push_str(&content[copied..]);
+    (updated, ticked)
+}
+
+/// Tick every task in the plan at `plan_path`; see [`set_all_tasks_done`].
+pub fn write_all_tasks_done(plan_path: &Path) -> Result<usize, ParseError> {
+    let io_err = |e| ParseError::Io {
+        path: plan_path.to_path_buf(),
+        source: e,
+    };
+    let raw = std::fs::read_to_string(plan_path).map_err(io_err)?;
+    let content = strip_bom(&raw);
+    let bom = &raw[..raw.len() - content.len()];
+    let (updated, ticked) = set_all_tasks_done(content);
+    if ticked > 0 {
+        write_atomic(plan_path, &format!("{bom}{updated}")).map_err(io_err)?;
+    }
+    Ok(ticked)
+}
+
 /// Check if a heading looks like a phase header.
 /// Matches patterns like "Phase 1: Infrastructure", "Phase 2 (TDD)", etc.
 fn is_phase_heading(name: &str) -> bool {
@@ -157,41 +373,35 @@ fn clean_task_text(text: &str) -> String {
     text.to_string()
 }
 
-/// Derive phase statuses from task completion.
-fn compute_phase_statuses(phases: &mut [PlanPhase]) {
-    let mut found_active = false;
-
-    for phase in phases.iter_mut() {
-        if phase.tasks.is_empty() {
-            phase.status = PhaseStatus::Pending;
-            continue;
-        }
-
-        let all_done = phase.tasks.iter().all(|t| t.done);
-        let any_done = phase.tasks.iter().any(|t| t.done);
-
-        if all_done {
-            phase.status = PhaseStatus::Complete;
-        } else if any_done || !found_active {
-            // First incomplete phase with some progress, or the first incomplete phase
-            if any_done || !found_active {
-                phase.status = PhaseStatus::Active;
-                found_active = true;
-            }
-        } else {
-            phase.status = PhaseStatus::Pending;
-        }
-    }
-
-    // If we never found an active phase and there are incomplete phases,
-    // mark the first incomplete one as active.
-    if !found_active {
-        if let Some(phase) = phases
-            .iter_mut()
-            .find(|p| p.status == PhaseStatus::Pending && !p.tasks.is_empty())
-        {
-            phase.status = PhaseStatus::Active;
+/// Extract an inline `(<keyword> <date>)` marker, e.g. `(due 2026-03-01)`.
+/// The keyword must be followed by a space or `:`, so `(duet …)` is not a
+/// marker. Returns the text with the marker removed and the parsed date;
+/// unparseable markers are left in place.
+fn split_date_marker(text: &str, keyword: &str) -> (String, Option<chrono::DateTime<chrono::Utc>>) {
+    let open = format!("({keyword}");
+    let lower = text.to_ascii_lowercase();
+    let Some(start) = lower
+        .match_indices(&open)
+        .map(|(i, _)| i)
+        .find(|&i| lower[i + open.len()..].starts_with(|c: char| c == ':' || c.is_whitespace()))
+    else {
+        return (text.to_string(), None);
+    };
+    let Some(len) = text[start..].find(')') else {
+        return (text.to_string(), None);
+    };
+    let end = start + len;
+    let date = text[start + open.len()..end].trim_start_matches(':').trim();
+    match parse_datetime(date) {
+        Some(due) => {
+            let stripped = format!(
+                "{} {}",
+                text[..start].trim_end(),
+                text[end + 1..].trim_start()
+            );
+            (stripped.trim().to_string(), Some(due))
         }
+        None => (text.to_string(), None),
     }
 }
 
@@ -238,12 +448,117 @@ mod tests {
         assert_eq!(phases[0].status, PhaseStatus::Complete);
     }
 
+    fn statuses(md: &str) -> Vec<PhaseStatus> {
+        parse_plan_content(md).iter().map(|p| p.status).collect()
+    }
+
+    #[test]
+    fn test_untouched_plan_activates_only_first_phase() {
+        let md = "## Phase 1\n- [ ] A\n\n## Phase 2\n- [ ] B\n\n## Phase 3\n- [ ] C\n";
+        assert_eq!(
+            statuses(md),
+            [
+                PhaseStatus::Active,
+                PhaseStatus::Pending,
+                PhaseStatus::Pending
+            ]
+        );
+    }
+
+    #[test]
+    fn test_finished_plan_has_no_active_phase() {
+        let md = "## Phase 1\n- [x] A\n\n## Phase 2\n- [x] B\n";
+        assert_eq!(statuses(md), [PhaseStatus::Complete, PhaseStatus::Complete]);
+    }
+
+    #[test]
+    fn test_untouched_phase_between_complete_ones_is_active() {
+        let md = "## Phase 1\n- [x] A\n\n## Phase 2\n- [ ] B\n\n## Phase 3\n- [x] C\n";
+        assert_eq!(
+            statuses(md),
+            [
+                PhaseStatus::Complete,
+                PhaseStatus::Active,
+                PhaseStatus::Complete
+            ]
+        );
+    }
+
+    #[test]
+    fn test_started_phase_is_active_over_earlier_untouched_one() {
+        let md = "## Phase 1\n- [ ] A\n\n## Phase 2\n- [x] B\n- [ ] C\n\n## Phase 3\n- [x] D\n- [ ] E\n\n## Phase 4\n- [ ] F\n";
+        assert_eq!(
+            statuses(md),
+            [
+                PhaseStatus::Pending,
+                PhaseStatus::Active,
+                PhaseStatus::Active,
+                PhaseStatus::Pending
+            ]
+        );
+    }
+
     #[test]
     fn test_empty_plan() {
         let phases = parse_plan_content("# Nothing here\n\nJust a description.\n");
         assert!(phases.is_empty());
     }
 
+    #[test]
+    fn test_due_date_extracted_and_stripped() {
+        let md = r#"## Phase 1: Ship
+- [ ] Ship API (due 2026-03-01)
+- [ ] Write docs (Due: 2026/03/15) for users
+- [x] Plain task
+- [ ] Odd marker (due whenever)
+- [ ] Tune the duet (duet 2026-01-01) (due 2026-04-01)
+- [ ] No gap (due2026-05-01)
+"#;
+        let phases = parse_plan_content(md);
+        let tasks = &phases[0].tasks;
+
+        assert_eq!(tasks[0].text, "Ship API");
+        assert_eq!(
+            tasks[0].due.unwrap().format("%Y-%m-%d").to_string(),
+            "2026-03-01"
+        );
+        assert_eq!(tasks[1].text, "Write docs for users");
+        assert_eq!(
+            tasks[1].due.unwrap().format("%Y-%m-%d").to_string(),
+            "2026-03-15"
+        );
+        assert!(tasks[2].due.is_none());
+        // Unparseable dates stay in the text
+        assert_eq!(tasks[3].text, "Odd marker (due whenever)");
+        assert!(tasks[3].due.is_none());
+        // The keyword has to end at a space or colon
+        assert_eq!(tasks[4].text, "Tune the duet (duet 2026-01-01)");
+        assert_eq!(
+            tasks[4].due.unwrap().format("%Y-%m-%d").to_string(),
+            "2026-04-01"
+        );
+        assert_eq!(tasks[5].text, "No gap (due2026-05-01)");
+        assert!(tasks[5].due.is_none());
+    }
+
+    #[test]
+    fn test_phase_completion_date_extracted_and_stripped() {
+        let md = "## Phase 1: Setup (done 2026-02-10)\n- [x] A\n\n## Phase 2: Build\n- [ ] B\n";
+        let phases = parse_plan_content(md);
+
+        assert_eq!(phases[0].name, "Phase 1: Setup");
+        assert_eq!(
+            phases[0]
+                .completed_at
+                .unwrap()
+                .format("%Y-%m-%d")
+                .to_string(),
+            "2026-02-10"
+        );
+        assert_eq!(phases[1].name, "Phase 2: Build");
+        assert!(phases[1].completed_at.is_none());
+    }
+
     #[test]
     fn test_task_text_cleanup() {
         assert_eq!(
@@ -266,6 +581,66 @@ mod tests {
         assert_eq!(phases[0].tasks.len(), 2);
     }
 
+    #[test]
+    fn test_h3_sub_phases_nest_under_h2() {
+        let md = r#"## Phase 1: Backend
+### Phase 1a: Schema
+- [x] Task: Add table
+- [x] Task: Add index
+
+### Phase 1b: API
+- [x] Task: Add endpoint
+- [ ] Task: Add auth
+
+## Phase 2: Frontend
+- [ ] Task: Build page
+"#;
+        let phases = parse_plan_content_with_sub_phases(md, true);
+        let shape: Vec<(&str, usize)> = phases.iter().map(|p| (p.name.as_str(), p.depth)).collect();
+        assert_eq!(
+            shape,
+            vec![
+                ("Phase 1: Backend", 0),
+                ("Phase 1a: Schema", 1),
+                ("Phase 1b: API", 1),
+                ("Phase 2: Frontend", 0),
+            ]
+        );
+        assert_eq!(phases[1].status, PhaseStatus::Complete);
+        assert_eq!(phases[2].status, PhaseStatus::Active);
+        // The parent has no direct tasks, so it mirrors its sub-phases
+        assert_eq!(phases[0].status, PhaseStatus::Active);
+        assert_eq!(phases[3].status, PhaseStatus::Pending);
+    }
+
+    #[test]
+    fn test_h3_phases_stay_top_level_without_sub_phases() {
+        let md = r#"## Phase 1: Backend
+### Phase 1a: Schema
+- [x] Task: Add table
+
+### Phase 1b: API
+- [ ] Task: Add auth
+"#;
+        let phases = parse_plan_content(md);
+        let depths: Vec<usize> = phases.iter().map(|p| p.depth).collect();
+        assert_eq!(depths, vec![0, 0, 0]);
+        // The task-less H2 phase is not rolled up from the H3 phases
+        assert_eq!(phases[0].status, PhaseStatus::Pending);
+        assert_eq!(phases[1].status, PhaseStatus::Complete);
+    }
+
+    #[test]
+    fn test_h3_phase_without_h2_phase_is_top_level() {
+        let md = r#"## Overview
+### Phase 1: Setup
+- [ ] Task: A
+"#;
+        let phases = parse_plan_content_with_sub_phases(md, true);
+        assert_eq!(phases.len(), 1);
+        assert_eq!(phases[0].depth, 0);
+    }
+
     #[test]
     fn test_phase_with_description_paragraph() {
         let md = r#"## Phase 1: Infrastructure & Foundation
@@ -286,4 +661,52 @@ Configure the collector.
         assert_eq!(phases[1].tasks.len(), 2);
         assert!(phases[1].tasks[0].done);
     }
+
+    #[test]
+    fn test_set_task_state_edits_only_the_marker() {
+        let content = "\
+## Phase 1: Setup
+- [x] First
+- [ ] Second
+
+## Phase 2: Build
+- [ ] Third [with brackets]
+  - [ ] Nested
+";
+        let updated = set_task_state(content, 1, 0, true).unwrap();
+        assert_eq!(
+            updated,
+            content.replace("- [ ] Third", "- [x] Third"),
+            "only the targeted checkbox changes"
+        );
+
+        let updated = set_task_state(content, 0, 0, false).unwrap();
+        let phases = parse_plan_content(&updated);
+        assert!(!phases[0].tasks[0].done);
+        assert!(!phases[0].tasks[1].done);
+
+        // Nested tasks are counted in their phase like the parser does
+        let updated = set_task_state(content, 1, 1, true).unwrap();
+        assert!(parse_plan_content(&updated)[1].tasks[1].done);
+
+        assert!(set_task_state(content, 0, 2, true).is_none());
+        assert!(set_task_state(content, 5, 0, true).is_none());
+    }
+
+    #[test]
+    fn test_set_all_tasks_done_ticks_each_open_marker_once() {
+        let content = "\
+## Phase 1: Setup
+- [x] First
+- [ ] Second [with brackets]
+
+## Phase 2: Build
+- [ ] Third
+  - [ ] Nested
+";
+        let (updated, ticked) = set_all_tasks_done(content);
+        assert_eq!(ticked, 3);
+        assert_eq!(updated, content.replace("[ ]", "[x]"));
+        assert_eq!(set_all_tasks_done(&updated), (updated.clone(), 0));
+    }
 }
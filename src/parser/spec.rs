@@ -0,0 +1,136 @@
+//! Parse `spec.md` — the spec's H1 title and its acceptance criteria.
+//!
+//! The first H1 heading is the title. Top-level list items under a heading
+//! reading "Acceptance Criteria" (any level below H1, case-insensitive, with
+//! or without a section number like `4.`) are the criteria; the section ends
+//! at the next heading of the same or a higher level. Checkbox markers are
+//! dropped and nested bullets are skipped.
+
+use std::path::Path;
+
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+use crate::model::SpecSummary;
+use crate::parser::error::ParseError;
+use crate::parser::read_text;
+
+/// Parse a spec.md file into its title and acceptance criteria.
+pub fn parse_spec(path: &Path) -> Result<SpecSummary, ParseError> {
+    let content = read_text(path).map_err(|e| ParseError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+
+    Ok(parse_spec_content(&content))
+}
+
+/// Parse spec.md content into its title and acceptance criteria.
+pub fn parse_spec_content(content: &str) -> SpecSummary {
+    let mut spec = SpecSummary::default();
+    let mut heading_text: Option<String> = None;
+    // Level of the "Acceptance Criteria" heading while inside its section
+    let mut criteria_level: Option<HeadingLevel> = None;
+    let mut list_depth = 0usize;
+    let mut item_text: Option<String> = None;
+
+    for event in Parser::new_ext(content, Options::ENABLE_TASKLISTS) {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                if criteria_level.is_some_and(|open| level <= open) {
+                    criteria_level = None;
+                }
+                heading_text = Some(String::new());
+            }
+            Event::End(TagEnd::Heading(level)) => {
+                let text = heading_text.take().unwrap_or_default();
+                let text = text.trim();
+                if level == HeadingLevel::H1 {
+                    if spec.title.is_none() && !text.is_empty() {
+                        spec.title = Some(text.to_string());
+                    }
+                } else if is_criteria_heading(text) {
+                    criteria_level = Some(level);
+                }
+            }
+            Event::Start(Tag::List(_)) => list_depth += 1,
+            Event::End(TagEnd::List(_)) => list_depth = list_depth.saturating_sub(1),
+            Event::Start(Tag::Item) if criteria_level.is_some() && list_depth == 1 => {
+                item_text = Some(String::new());
+            }
+            Event::End(TagEnd::Item) if list_depth == 1 => {
+                if let Some(text) = item_text.take() {
+                    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                    if !text.is_empty() {
+                        spec.acceptance_criteria.push(text);
+                    }
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(heading) = &mut heading_text {
+                    heading.push_str(&text);
+                } else if let Some(item) = item_text.as_mut().filter(|_| list_depth == 1) {
+                    item.push_str(&text);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                if let Some(item) = item_text.as_mut().filter(|_| list_depth == 1) {
+                    item.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+
+    spec
+}
+
+/// "Acceptance Criteria", allowing a section number (`4. `) and a trailing colon.
+fn is_criteria_heading(text: &str) -> bool {
+    text.trim_start_matches(|c: char| c.is_ascii_digit() || c == '.' || c == ' ')
+        .trim_end_matches(':')
+        .eq_ignore_ascii_case("acceptance criteria")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_and_acceptance_criteria() {
+        let md = r#"# Auth Refactor
+
+Some context.
+
+## Acceptance Criteria
+- [ ] Tokens refresh
+  silently
+- [x] `login` returns 401 on bad password
+  - nested detail is skipped
+
+## Out of Scope
+- SSO
+"#;
+        let spec = parse_spec_content(md);
+        assert_eq!(spec.title.as_deref(), Some("Auth Refactor"));
+        assert_eq!(
+            spec.acceptance_criteria,
+            vec![
+                "Tokens refresh silently".to_string(),
+                "login returns 401 on bad password".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_criteria_section_runs_through_subheadings() {
+        let md = "# Spec\n\n## 4. acceptance criteria:\n\n### API\n1. First\n\n### UI\n2. Second\n\n# Appendix\n- Not a criterion\n";
+        let spec = parse_spec_content(md);
+        assert_eq!(spec.acceptance_criteria, vec!["First", "Second"]);
+    }
+
+    #[test]
+    fn test_spec_without_title_or_criteria() {
+        let spec = parse_spec_content("Just prose.\n\n- a list\n");
+        assert_eq!(spec, SpecSummary::default());
+    }
+}
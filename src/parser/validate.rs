@@ -0,0 +1,227 @@
+//! Structural diagnostics for a conductor directory.
+//!
+//! Collects problems that the loaders tolerate silently (a missing plan, a
+//! dependency on an unknown track, …) so they can be reported in one place.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::model::TrackId;
+use crate::parser::error::ParseError;
+use crate::parser::index::{self, IndexSource};
+use crate::parser::metadata::{parse_metadata, METADATA_FILES};
+use crate::parser::{find_plan_file, ReadOptions};
+
+/// Category of a validation issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    /// A track listed in tracks.md has no plan file (see
+    /// [`ReadOptions::plan_files`](crate::parser::ReadOptions::plan_files)).
+    MissingPlan,
+    /// A track depends on an ID that is not in tracks.md.
+    UnresolvedDependency,
+    /// The same track ID appears more than once in tracks.md.
+    DuplicateId,
+    /// The heading checkbox and the `**Status**` field disagree.
+    StatusMismatch,
+    /// A folder under the tracks dir is not referenced by tracks.md.
+    OrphanDirectory,
+    /// Metadata's `total_tasks` differs from the number of tasks in plan.md.
+    TaskCountMismatch,
+    /// The track's metadata file (metadata.json, meta.yaml or meta.toml)
+    /// can't be parsed, so its defaults are used.
+    InvalidMetadata,
+    /// The track has several metadata files; only the first is read.
+    ShadowedMetadata,
+}
+
+impl IssueKind {
+    /// Snake-case name, matching the serialized form.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::MissingPlan => "missing_plan",
+            Self::UnresolvedDependency => "unresolved_dependency",
+            Self::DuplicateId => "duplicate_id",
+            Self::StatusMismatch => "status_mismatch",
+            Self::OrphanDirectory => "orphan_directory",
+            Self::TaskCountMismatch => "task_count_mismatch",
+            Self::InvalidMetadata => "invalid_metadata",
+            Self::ShadowedMetadata => "shadowed_metadata",
+        }
+    }
+}
+
+/// A single problem found by [`validate_conductor`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Issue {
+    pub kind: IssueKind,
+    /// Track the issue concerns (the folder name for orphan directories).
+    pub track_id: String,
+    pub message: String,
+}
+
+/// Validate `tracks.md` in `conductor_dir` against the track folders under
+/// `tracks_subdir`, reading them as `options` says.
+pub fn validate_conductor(
+    conductor_dir: &Path,
+    tracks_subdir: &str,
+    options: &ReadOptions,
+) -> Result<Vec<Issue>, ParseError> {
+    let source = IndexSource::File(index::index_path(conductor_dir));
+    Ok(validate_content(
+        &source.content()?,
+        &conductor_dir.join(tracks_subdir),
+        options,
+    ))
+}
+
+/// Validate raw index markdown against the track folders in `tracks_dir`.
+/// Issues are grouped by kind.
+///
+/// When `tracks_dir` is the conductor dir itself (it holds the index), only
+/// folders with a plan, metadata or spec file can be orphan tracks, so the
+/// usual `code_styleguides/`, `archive/`, … are not reported.
+pub fn validate_content(content: &str, tracks_dir: &Path, options: &ReadOptions) -> Vec<Issue> {
+    let entries = index::parse_index_content(content);
+    let mut issues = Vec::new();
+
+    let mut counts: BTreeMap<&TrackId, usize> = BTreeMap::new();
+    for entry in &entries {
+        *counts.entry(&entry.id).or_default() += 1;
+    }
+    for (id, n) in &counts {
+        if *n > 1 {
+            issues.push(Issue {
+                kind: IssueKind::DuplicateId,
+                track_id: id.to_string(),
+                message: format!("listed {n} times in tracks.md"),
+            });
+        }
+    }
+
+    for entry in &entries {
+        let checkbox_status = entry.checkbox.to_status();
+        if entry.has_status_field && entry.status != checkbox_status {
+            issues.push(Issue {
+                kind: IssueKind::StatusMismatch,
+                track_id: entry.id.to_string(),
+                message: format!(
+                    "checkbox {} means {} but Status field says {}",
+                    entry.checkbox.marker(),
+                    checkbox_status,
+                    entry.status
+                ),
+            });
+        }
+    }
+
+    // Dependencies as the loaders see them, including metadata overrides
+    let options = ReadOptions {
+        lazy_plans: false,
+        ..options.clone()
+    };
+    let tracks = super::load_track_files(index::tracks_from_content(content), tracks_dir, &options);
+    for track in tracks.values() {
+        let track_dir = tracks_dir.join(track.id.as_str());
+        if find_plan_file(&track_dir, &options.plan_files).is_none() {
+            issues.push(Issue {
+                kind: IssueKind::MissingPlan,
+                track_id: track.id.to_string(),
+                message: format!(
+                    "no {} in the track directory",
+                    options.plan_files.join(" or ")
+                ),
+            });
+        }
+        let metadata_files: Vec<&str> = METADATA_FILES
+            .into_iter()
+            .filter(|name| track_dir.join(name).is_file())
+            .collect();
+        if let [read, ignored @ ..] = metadata_files.as_slice() {
+            if !ignored.is_empty() {
+                issues.push(Issue {
+                    kind: IssueKind::ShadowedMetadata,
+                    track_id: track.id.to_string(),
+                    message: format!("{} ignored; {read} is read", ignored.join(" and ")),
+                });
+            }
+        }
+        if let Err(e) = parse_metadata(&track_dir, track.id.as_str()) {
+            issues.push(Issue {
+                kind: IssueKind::InvalidMetadata,
+                track_id: track.id.to_string(),
+                message: e.to_string(),
+            });
+        }
+        if let Some(warning) = track.task_count_warning() {
+            issues.push(Issue {
+                kind: IssueKind::TaskCountMismatch,
+                track_id: track.id.to_string(),
+                message: warning,
+            });
+        }
+        for dep in &track.dependencies {
+            if !tracks.contains_key(dep) {
+                issues.push(Issue {
+                    kind: IssueKind::UnresolvedDependency,
+                    track_id: track.id.to_string(),
+                    message: format!("depends on unknown track '{dep}'"),
+                });
+            }
+        }
+    }
+
+    let known: BTreeSet<&str> = counts.keys().map(|id| id.as_str()).collect();
+    let in_conductor_root = tracks_dir.join(index::INDEX_FILE).is_file();
+    let looks_like_track = |dir: &Path| {
+        !in_conductor_root
+            || find_plan_file(dir, &options.plan_files).is_some()
+            || METADATA_FILES
+                .iter()
+                .chain(&["spec.md"])
+                .any(|name| dir.join(name).is_file())
+    };
+    if let Ok(dir) = std::fs::read_dir(tracks_dir) {
+        let mut orphans: Vec<String> = dir
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir() && looks_like_track(&e.path()))
+            .filter_map(|e| e.file_name().to_str().map(str::to_string))
+            .filter(|name| !name.starts_with('.') && !known.contains(name.as_str()))
+            .collect();
+        orphans.sort();
+        issues.extend(orphans.into_iter().map(|name| Issue {
+            kind: IssueKind::OrphanDirectory,
+            track_id: name,
+            message: "directory is not referenced by tracks.md".to_string(),
+        }));
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_and_mismatch() {
+        let content = "\
+## [x] Track: One
+*Link: [./tracks/one/](./tracks/one/)*
+**Status**: in_progress
+
+## [ ] Track: One again
+*Link: [./tracks/one/](./tracks/one/)*
+";
+        let issues = validate_content(content, Path::new("/nonexistent"), &ReadOptions::default());
+        assert!(issues
+            .iter()
+            .any(|i| i.kind == IssueKind::DuplicateId && i.track_id == "one"));
+        assert!(issues
+            .iter()
+            .any(|i| i.kind == IssueKind::StatusMismatch && i.track_id == "one"));
+    }
+}
@@ -20,6 +20,8 @@ pub struct Theme {
     pub warning: Color,
     pub success: Color,
     pub error: Color,
+    /// Tracks awaiting review / QA.
+    pub review: Color,
 
     // Surfaces
     #[allow(dead_code)]
@@ -36,15 +38,20 @@ pub struct Theme {
     pub progress_done: Color,
     pub progress_blocked: Color,
     pub progress_new: Color,
+
+    // Selected row in the track list
+    pub selection_bg: Color,
+    pub selection_fg: Color,
 }
 
-const ALL_THEMES: [Theme; 6] = [
+const ALL_THEMES: [Theme; 7] = [
     Theme::mako(),
     Theme::warm_dark(),
     Theme::midnight(),
     Theme::ember(),
     Theme::dusk(),
     Theme::light(),
+    Theme::deuteranopia(),
 ];
 
 impl Theme {
@@ -58,6 +65,7 @@ impl Theme {
             warning: Color::Rgb(178, 140, 84),
             success: Color::Rgb(44, 95, 45),
             error: Color::Rgb(184, 80, 66),
+            review: Color::Rgb(128, 90, 170),
             bg: Color::Rgb(244, 246, 251),
             surface: Color::Rgb(255, 255, 255),
             border: Color::Rgb(209, 217, 232),
@@ -67,6 +75,8 @@ impl Theme {
             progress_done: Color::Rgb(44, 95, 45),
             progress_blocked: Color::Rgb(178, 140, 84),
             progress_new: Color::Rgb(107, 122, 153),
+            selection_bg: Color::Rgb(84, 113, 223),
+            selection_fg: Color::White,
         }
     }
 
@@ -80,6 +90,7 @@ impl Theme {
             warning: Color::Rgb(201, 168, 76),
             success: Color::Rgb(120, 140, 93),
             error: Color::Rgb(196, 91, 91),
+            review: Color::Rgb(180, 142, 220),
             bg: Color::Rgb(25, 25, 24),
             surface: Color::Rgb(37, 37, 36),
             border: Color::Rgb(51, 51, 49),
@@ -89,6 +100,8 @@ impl Theme {
             progress_done: Color::Rgb(120, 140, 93),
             progress_blocked: Color::Rgb(201, 168, 76),
             progress_new: Color::Rgb(106, 155, 204),
+            selection_bg: Color::Rgb(106, 155, 204),
+            selection_fg: Color::White,
         }
     }
 
@@ -102,6 +115,7 @@ impl Theme {
             warning: Color::Rgb(201, 168, 76),
             success: Color::Rgb(125, 155, 106),
             error: Color::Rgb(196, 91, 91),
+            review: Color::Rgb(180, 142, 230),
             bg: Color::Rgb(18, 20, 26),
             surface: Color::Rgb(30, 34, 48),
             border: Color::Rgb(46, 51, 64),
@@ -111,6 +125,8 @@ impl Theme {
             progress_done: Color::Rgb(125, 155, 106),
             progress_blocked: Color::Rgb(201, 168, 76),
             progress_new: Color::Rgb(123, 170, 212),
+            selection_bg: Color::Rgb(123, 170, 212),
+            selection_fg: Color::White,
         }
     }
 
@@ -124,6 +140,7 @@ impl Theme {
             warning: Color::Rgb(201, 168, 76),
             success: Color::Rgb(138, 155, 104),
             error: Color::Rgb(196, 91, 91),
+            review: Color::Rgb(190, 140, 210),
             bg: Color::Rgb(26, 20, 18),
             surface: Color::Rgb(40, 32, 28),
             border: Color::Rgb(61, 49, 43),
@@ -133,6 +150,8 @@ impl Theme {
             progress_done: Color::Rgb(138, 155, 104),
             progress_blocked: Color::Rgb(201, 168, 76),
             progress_new: Color::Rgb(106, 155, 204),
+            selection_bg: Color::Rgb(106, 155, 204),
+            selection_fg: Color::White,
         }
     }
 
@@ -146,6 +165,7 @@ impl Theme {
             warning: Color::Rgb(201, 168, 76),
             success: Color::Rgb(120, 140, 93),
             error: Color::Rgb(196, 91, 91),
+            review: Color::Rgb(186, 150, 228),
             bg: Color::Rgb(44, 43, 40),
             surface: Color::Rgb(58, 57, 55),
             border: Color::Rgb(78, 77, 72),
@@ -155,6 +175,8 @@ impl Theme {
             progress_done: Color::Rgb(120, 140, 93),
             progress_blocked: Color::Rgb(201, 168, 76),
             progress_new: Color::Rgb(106, 155, 204),
+            selection_bg: Color::Rgb(106, 155, 204),
+            selection_fg: Color::White,
         }
     }
 
@@ -168,6 +190,7 @@ impl Theme {
             warning: Color::Rgb(154, 123, 46),
             success: Color::Rgb(93, 122, 66),
             error: Color::Rgb(184, 76, 63),
+            review: Color::Rgb(120, 84, 160),
             bg: Color::Rgb(244, 243, 238),
             surface: Color::Rgb(250, 249, 245),
             border: Color::Rgb(223, 221, 213),
@@ -177,6 +200,35 @@ impl Theme {
             progress_done: Color::Rgb(93, 122, 66),
             progress_blocked: Color::Rgb(154, 123, 46),
             progress_new: Color::Rgb(74, 125, 168),
+            selection_bg: Color::Rgb(74, 125, 168),
+            selection_fg: Color::White,
+        }
+    }
+
+    /// Colour-blind-friendly dark theme. Uses the Okabe–Ito blue/orange axis
+    /// instead of red/green, so done vs. blocked never hinges on hue alone.
+    pub const fn deuteranopia() -> Self {
+        Self {
+            name: "Deuteranopia",
+            bar_bg: Color::Rgb(20, 24, 32),
+            text_on_bar: Color::Rgb(230, 232, 236),
+            accent: Color::Rgb(86, 180, 233),
+            accent_light: Color::Rgb(150, 208, 240),
+            warning: Color::Rgb(230, 159, 0),
+            success: Color::Rgb(0, 114, 178),
+            error: Color::Rgb(213, 94, 0),
+            review: Color::Rgb(204, 121, 167),
+            bg: Color::Rgb(16, 18, 24),
+            surface: Color::Rgb(30, 34, 44),
+            border: Color::Rgb(60, 66, 80),
+            text_primary: Color::Rgb(230, 232, 236),
+            text_secondary: Color::Rgb(140, 146, 160),
+            progress_active: Color::Rgb(86, 180, 233),
+            progress_done: Color::Rgb(0, 114, 178),
+            progress_blocked: Color::Rgb(230, 159, 0),
+            progress_new: Color::Rgb(140, 146, 160),
+            selection_bg: Color::Rgb(86, 180, 233),
+            selection_fg: Color::White,
         }
     }
 
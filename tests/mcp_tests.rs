@@ -1,13 +1,43 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use conductor_dashboard::mcp::service::ConductorService;
 use conductor_dashboard::mcp::types::*;
+use conductor_dashboard::model::Priority;
 use rmcp::handler::server::wrapper::Parameters;
 
 fn conductor_dir() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("conductor")
 }
 
+/// A small conductor directory under `tests/fixtures`, for cases the real
+/// one doesn't have.
+fn fixture_dir(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+/// A scratch copy of [`fixture_dir`] for tests that write, removed when the
+/// returned dir is dropped.
+fn fixture_copy(name: &str) -> tempfile::TempDir {
+    let tmp = tempfile::tempdir().unwrap();
+    copy_dir(&fixture_dir(name), tmp.path());
+    tmp
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    for entry in std::fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let target = to.join(entry.file_name());
+        if entry.file_type().unwrap().is_dir() {
+            std::fs::create_dir_all(&target).unwrap();
+            copy_dir(&entry.path(), &target);
+        } else {
+            std::fs::copy(entry.path(), &target).unwrap();
+        }
+    }
+}
+
 fn service() -> ConductorService {
     ConductorService::new(&conductor_dir()).expect("should load tracks from real conductor dir")
 }
@@ -84,6 +114,7 @@ fn test_summary_status_counts_add_up() {
     let summary: SummaryResponse = serde_json::from_str(&result).unwrap();
     let sum = summary.by_status.new
         + summary.by_status.in_progress
+        + summary.by_status.review
         + summary.by_status.blocked
         + summary.by_status.complete;
     assert_eq!(
@@ -92,6 +123,73 @@ fn test_summary_status_counts_add_up() {
     );
 }
 
+#[test]
+fn test_summary_outstanding_tasks_by_priority() {
+    let svc = service();
+    let summary: SummaryResponse = serde_json::from_str(&svc.get_summary()).unwrap();
+    let tracks = svc.tracks();
+
+    let open = |priority: Priority| -> usize {
+        tracks
+            .values()
+            .filter(|t| t.priority == priority)
+            .map(|t| t.outstanding_tasks().count())
+            .sum()
+    };
+    let by_priority = &summary.outstanding_tasks_by_priority;
+    assert_eq!(by_priority.critical, open(Priority::Critical));
+    assert_eq!(by_priority.high, open(Priority::High));
+    assert_eq!(by_priority.medium, open(Priority::Medium));
+    assert_eq!(by_priority.low, open(Priority::Low));
+}
+
+#[test]
+fn test_metrics_match_summary_in_prometheus_format() {
+    let svc = service();
+    let metrics = svc.get_metrics();
+    let summary: SummaryResponse = serde_json::from_str(&svc.get_summary()).unwrap();
+
+    for name in [
+        "conductor_tracks_total",
+        "conductor_tracks_by_status",
+        "conductor_tasks_total",
+        "conductor_tasks_completed",
+        "conductor_overall_progress",
+    ] {
+        assert!(
+            metrics.contains(&format!("# TYPE {name} gauge\n")),
+            "{name} missing:\n{metrics}"
+        );
+    }
+    let sample = |prefix: &str| -> f64 {
+        metrics
+            .lines()
+            .find_map(|l| l.strip_prefix(prefix))
+            .unwrap_or_else(|| panic!("no {prefix} sample:\n{metrics}"))
+            .trim()
+            .parse()
+            .unwrap()
+    };
+
+    let total = sample("conductor_tracks_total ");
+    assert_eq!(total, summary.total_tracks as f64);
+    let by_status: f64 = metrics
+        .lines()
+        .filter_map(|l| l.strip_prefix("conductor_tracks_by_status{status=\""))
+        .map(|l| l.rsplit(' ').next().unwrap().parse::<f64>().unwrap())
+        .sum();
+    assert_eq!(by_status, total, "status labels should sum to total");
+    assert_eq!(
+        sample("conductor_tracks_by_status{status=\"blocked\"} "),
+        summary.by_status.blocked as f64
+    );
+    assert_eq!(
+        sample("conductor_tasks_completed "),
+        summary.total_tasks_completed as f64
+    );
+    assert!((sample("conductor_overall_progress ") - summary.overall_progress as f64).abs() < 0.01);
+}
+
 #[test]
 fn test_summary_progress_bounded() {
     let svc = service();
@@ -122,6 +220,7 @@ fn test_detail_has_plan_phases() {
     if let Some(t) = tracks.iter().find(|t| t.tasks_total > 0) {
         let result = svc.get_track_detail(Parameters(GetTrackDetailParams {
             track_id: t.id.clone(),
+            relative_paths: None,
         }));
         let detail: TrackDetailResponse = serde_json::from_str(&result).unwrap();
         assert!(!detail.plan_phases.is_empty(), "should have plan phases");
@@ -144,6 +243,7 @@ fn test_detail_substring_match() {
     let partial = &first.id[..first.id.len().min(10)];
     let result = svc.get_track_detail(Parameters(GetTrackDetailParams {
         track_id: partial.to_string(),
+        relative_paths: None,
     }));
     // Should either find exactly one or report multiple matches
     assert!(
@@ -152,6 +252,79 @@ fn test_detail_substring_match() {
     );
 }
 
+#[test]
+fn test_shared_track_detail_matches_tool_output() {
+    let svc = service();
+    let tracks = conductor_dashboard::parser::load_all_tracks(&conductor_dir()).unwrap();
+
+    let track = conductor_dashboard::mcp::find_track(&tracks, "critical_data_integrity")
+        .expect("unique substring should resolve");
+    let shared = conductor_dashboard::mcp::track_detail(
+        track,
+        &conductor_dir().join("tracks"),
+        &[conductor_dashboard::parser::DEFAULT_PLAN_FILE.to_string()],
+        None,
+    );
+
+    let tool: TrackDetailResponse =
+        serde_json::from_str(&svc.get_track_detail(Parameters(GetTrackDetailParams {
+            track_id: track.id.as_str().to_string(),
+            relative_paths: None,
+        })))
+        .unwrap();
+    assert_eq!(shared.id, tool.id);
+    assert_eq!(shared.tasks_total, tool.tasks_total);
+    assert_eq!(shared.plan_phases.len(), tool.plan_phases.len());
+
+    assert!(conductor_dashboard::mcp::find_track(&tracks, "zzz_nonexistent").is_err());
+}
+
+// ---------------------------------------------------------------------------
+// get_plan_phase
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_plan_phase_by_index_and_name() {
+    let svc = service();
+    let by_index = svc.get_plan_phase(Parameters(GetPlanPhaseParams {
+        track_id: "critical_data_integrity_bugs_20260212".into(),
+        phase: "2".into(),
+    }));
+    let phase: PhaseResponse = serde_json::from_str(&by_index).expect("valid JSON phase");
+    assert!(phase.name.starts_with("Phase 3"), "got {}", phase.name);
+    assert_eq!(phase.tasks.len(), phase.tasks_total);
+
+    let by_name = svc.get_plan_phase(Parameters(GetPlanPhaseParams {
+        track_id: "critical_data_integrity_bugs_20260212".into(),
+        phase: phase.name.to_uppercase(),
+    }));
+    let same: PhaseResponse = serde_json::from_str(&by_name).expect("valid JSON phase");
+    assert_eq!(same.name, phase.name);
+}
+
+#[test]
+fn test_plan_phase_errors() {
+    let svc = service();
+    let out_of_range = svc.get_plan_phase(Parameters(GetPlanPhaseParams {
+        track_id: "critical_data_integrity_bugs_20260212".into(),
+        phase: "99".into(),
+    }));
+    assert!(out_of_range.contains("out of range"), "got: {out_of_range}");
+
+    // "phase" is a substring of every phase name
+    let ambiguous = svc.get_plan_phase(Parameters(GetPlanPhaseParams {
+        track_id: "critical_data_integrity_bugs_20260212".into(),
+        phase: "phase".into(),
+    }));
+    assert!(ambiguous.contains("Multiple phases"), "got: {ambiguous}");
+
+    let missing_track = svc.get_plan_phase(Parameters(GetPlanPhaseParams {
+        track_id: "nonexistent_xyz".into(),
+        phase: "0".into(),
+    }));
+    assert!(missing_track.contains("No track found"));
+}
+
 // ---------------------------------------------------------------------------
 // search_tracks
 // ---------------------------------------------------------------------------
@@ -246,6 +419,60 @@ fn test_dependencies_nonexistent() {
     assert!(result.contains("No track found"));
 }
 
+// ---------------------------------------------------------------------------
+// get_track_impact
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_track_impact_sorted_and_consistent() {
+    let svc = service();
+    let impacts: Vec<TrackImpact> = serde_json::from_str(&svc.get_track_impact()).unwrap();
+
+    for pair in impacts.windows(2) {
+        assert!(pair[0].impact >= pair[1].impact);
+    }
+    for t in &impacts {
+        assert!(t.impact > 0);
+        // The transitive closure contains at least the direct dependents
+        assert!(t.impact >= t.blocks.len());
+    }
+}
+
+// ---------------------------------------------------------------------------
+// recommend_next_track
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_recommend_next_track_prefers_ready_high_impact() {
+    // alpha: ready, high priority, unblocks beta and gamma. beta: critical,
+    // but waits on alpha. delta: ready but unblocks nothing. stuck is
+    // blocked and done is complete.
+    let svc = ConductorService::new(&fixture_dir("recommend")).unwrap();
+    let recommend = |limit: Option<usize>| -> Vec<TrackRecommendation> {
+        serde_json::from_str(
+            &svc.recommend_next_track(Parameters(RecommendNextTrackParams { limit })),
+        )
+        .unwrap()
+    };
+
+    let recs = recommend(None);
+    let ids: Vec<&str> = recs.iter().map(|r| r.track_id.as_str()).collect();
+    assert_eq!(ids, vec!["alpha", "delta"], "only ready, actionable tracks");
+    assert_eq!(recs[0].impact, 2);
+    // HIGH (30) + two unblocked (10) + in progress (5)
+    assert_eq!(recs[0].score, 45);
+    assert!(recs[0]
+        .rationale
+        .iter()
+        .any(|r| r == "unblocks 2 tracks (+10)"));
+    assert!(recs[1]
+        .rationale
+        .iter()
+        .any(|r| r == "ready: all 1 dependencies complete"));
+
+    assert_eq!(recommend(Some(1)).len(), 1);
+}
+
 // ---------------------------------------------------------------------------
 // get_tracks_by_priority
 // ---------------------------------------------------------------------------
@@ -262,6 +489,60 @@ fn test_filter_by_priority() {
     }
 }
 
+// ---------------------------------------------------------------------------
+// get_tracks_by_phase_status
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_tracks_by_phase_status_match_detail() {
+    let svc = service();
+    let result = svc.get_tracks_by_phase_status(Parameters(GetTracksByPhaseStatusParams {
+        phase_status: "Complete".into(),
+        phase_name: None,
+    }));
+    let matches: Vec<TrackPhaseMatch> = serde_json::from_str(&result).unwrap();
+    assert!(
+        !matches.is_empty(),
+        "Real data should have completed phases"
+    );
+
+    for m in &matches {
+        assert!(!m.phases.is_empty());
+        let detail = svc.get_track_detail(Parameters(GetTrackDetailParams {
+            track_id: m.track_id.clone(),
+            relative_paths: None,
+        }));
+        let detail: TrackDetailResponse = serde_json::from_str(&detail).unwrap();
+        for name in &m.phases {
+            let phase = detail.plan_phases.iter().find(|p| &p.name == name).unwrap();
+            assert_eq!(phase.status, "Complete");
+        }
+    }
+}
+
+#[test]
+fn test_tracks_by_phase_status_name_filter_and_errors() {
+    let svc = service();
+    let result = svc.get_tracks_by_phase_status(Parameters(GetTracksByPhaseStatusParams {
+        phase_status: "complete".into(),
+        phase_name: Some("PHASE 1".into()),
+    }));
+    let matches: Vec<TrackPhaseMatch> = serde_json::from_str(&result).unwrap();
+    for m in &matches {
+        assert!(m
+            .phases
+            .iter()
+            .all(|p| p.to_ascii_lowercase().contains("phase 1")));
+    }
+
+    let result = svc.get_tracks_by_phase_status(Parameters(GetTracksByPhaseStatusParams {
+        phase_status: "finished".into(),
+        phase_name: None,
+    }));
+    assert!(result.contains("Unknown phase status"));
+    assert!(result.contains("Expected one of: pending, active, complete, blocked."));
+}
+
 // ---------------------------------------------------------------------------
 // get_outstanding_tasks
 // ---------------------------------------------------------------------------
@@ -282,6 +563,322 @@ fn test_outstanding_tasks_are_incomplete() {
     }
 }
 
+// ---------------------------------------------------------------------------
+// get_all_tasks
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_all_tasks_have_stable_ids() {
+    let first: Vec<TaskEntry> = serde_json::from_str(&service().get_all_tasks()).unwrap();
+    let second: Vec<TaskEntry> = serde_json::from_str(&service().get_all_tasks()).unwrap();
+    assert!(!first.is_empty());
+
+    let ids = |tasks: &[TaskEntry]| tasks.iter().map(|t| t.id.clone()).collect::<Vec<_>>();
+    assert_eq!(ids(&first), ids(&second), "reload must not change task IDs");
+
+    let unique: std::collections::HashSet<&str> = first.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(unique.len(), first.len(), "task IDs must be unique");
+
+    let task = &first[0];
+    assert!(task.id.starts_with(&format!("{}#", task.track_id)));
+    assert!(first.iter().any(|t| t.id.ends_with("#0.0")));
+}
+
+// ---------------------------------------------------------------------------
+// set_task_done
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_set_task_done_round_trips_through_plan() {
+    use std::fs;
+
+    let tmp = fixture_copy("set_task_done");
+    let plan_path = tmp.path().join("tracks/alpha/plan.md");
+    let plan = fs::read_to_string(&plan_path).unwrap();
+
+    let set = |svc: &ConductorService, id: &str, done: bool| {
+        svc.set_task_done(Parameters(SetTaskDoneParams {
+            task_id: id.to_string(),
+            done,
+        }))
+    };
+
+    // Read-only by default: nothing is written
+    let read_only = ConductorService::new(tmp.path()).unwrap();
+    assert!(set(&read_only, "alpha#1.1", true).contains("read-only"));
+    assert_eq!(fs::read_to_string(&plan_path).unwrap(), plan);
+
+    let svc = ConductorService::new(tmp.path())
+        .unwrap()
+        .with_allow_write(true);
+    let entry: TaskEntry = serde_json::from_str(&set(&svc, "alpha#1.1", true)).unwrap();
+    assert_eq!(entry.text, "Third");
+    assert!(entry.done);
+    assert!(fs::read_to_string(&plan_path)
+        .unwrap()
+        .contains("- [x] Third"));
+    let detail: TrackDetailResponse =
+        serde_json::from_str(&svc.get_track_detail(Parameters(GetTrackDetailParams {
+            track_id: "alpha".into(),
+            relative_paths: None,
+        })))
+        .unwrap();
+    assert_eq!(detail.tasks_completed, 2);
+
+    // And back again restores the original file
+    let entry: TaskEntry = serde_json::from_str(&set(&svc, "alpha#1.1", false)).unwrap();
+    assert!(!entry.done);
+    assert_eq!(fs::read_to_string(&plan_path).unwrap(), plan);
+
+    assert!(set(&svc, "alpha#9.0", true).starts_with("No task"));
+    assert!(set(&svc, "alpha-1.0", true).starts_with("Invalid task ID"));
+
+    // A task inserted on disk after loading shifts the indexes: refuse
+    // rather than tick whichever task now sits at 1.1
+    let shifted = plan.replace("- [ ] Second", "- [ ] Inserted\n- [ ] Second");
+    fs::write(&plan_path, &shifted).unwrap();
+    assert!(set(&svc, "alpha#1.1", true).contains("changed on disk"));
+    assert_eq!(fs::read_to_string(&plan_path).unwrap(), shifted);
+}
+
+#[test]
+fn test_complete_track_round_trips_through_metadata_and_plan() {
+    use std::fs;
+
+    let copy = fixture_copy("complete_track");
+    let tmp = copy.path();
+    // Unsorted keys and odd spacing must survive the edit untouched
+    let json = fs::read_to_string(tmp.join("tracks/json_track/metadata.json")).unwrap();
+    let plan = fs::read_to_string(tmp.join("tracks/json_track/plan.md")).unwrap();
+
+    let complete = |svc: &ConductorService, id: &str, tick: bool| {
+        svc.complete_track(Parameters(CompleteTrackParams {
+            track_id: id.to_string(),
+            tick_all_tasks: Some(tick),
+        }))
+    };
+
+    let read_only = ConductorService::new(tmp).unwrap();
+    assert!(complete(&read_only, "json_track", true).contains("read-only"));
+
+    let svc = ConductorService::new(tmp).unwrap().with_allow_write(true);
+    let resp: CompleteTrackResponse =
+        serde_json::from_str(&complete(&svc, "json_track", true)).unwrap();
+    assert_eq!(resp.status, "Complete");
+    assert_eq!(resp.tasks_ticked, 1);
+    assert_eq!((resp.plan_tasks_completed, resp.plan_tasks_total), (2, 2));
+    // Only the status changes, plus an updated_at stamped with the edit
+    let written = fs::read_to_string(tmp.join("tracks/json_track/metadata.json")).unwrap();
+    let meta =
+        conductor_dashboard::parser::metadata::parse_json_metadata(&written, "json_track").unwrap();
+    let updated_at = meta.updated_at.expect("updated_at is set");
+    assert!((chrono::Utc::now() - updated_at).num_seconds() < 60);
+    let stamp = updated_at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    assert_eq!(
+        written,
+        json.replace(
+            "\"in_progress\",",
+            &format!("\"completed\",\n  \"updated_at\": \"{stamp}\",")
+        )
+    );
+    assert_eq!(
+        fs::read_to_string(tmp.join("tracks/json_track/plan.md")).unwrap(),
+        plan.replace("[ ]", "[x]")
+    );
+
+    // Without tick_all_tasks the plan is left alone
+    let resp: CompleteTrackResponse =
+        serde_json::from_str(&complete(&svc, "yaml_track", false)).unwrap();
+    assert_eq!(resp.tasks_ticked, 0);
+    let written = fs::read_to_string(tmp.join("tracks/yaml_track/meta.yaml")).unwrap();
+    assert!(written
+        .starts_with("name: Yaml\nstatus: completed # set by hand\ntags: [cli]\ncompleted: "));
+
+    // meta.toml gets the same edit, its updated_at added before any table
+    let resp: CompleteTrackResponse =
+        serde_json::from_str(&complete(&svc, "toml_track", false)).unwrap();
+    assert_eq!(resp.status, "Complete");
+    assert!(resp.metadata_file.ends_with("meta.toml"));
+    let written = fs::read_to_string(tmp.join("tracks/toml_track/meta.toml")).unwrap();
+    assert!(written.starts_with("status = \"completed\" # set by hand\n\nupdated_at = \""));
+    assert!(written.ends_with("\n[links]\nstatus = \"ok\"\n"));
+
+    // Two metadata files: ambiguous, so neither is touched
+    assert!(complete(&svc, "both_track", true).contains("metadata.json and meta.yaml exist"));
+    assert_eq!(
+        fs::read_to_string(tmp.join("tracks/both_track/meta.yaml")).unwrap(),
+        "status: new\n"
+    );
+    assert!(complete(&svc, "missing", true).starts_with("No track"));
+}
+
+#[test]
+fn test_write_tools_and_validation_use_configured_plan_file() {
+    use conductor_dashboard::parser::{ReadOptions, DEFAULT_TRACKS_SUBDIR};
+    use std::fs;
+
+    let tmp = fixture_copy("configured_plan_file");
+    let tasks = tmp.path().join("tracks/alpha/tasks.md");
+
+    let options = ReadOptions {
+        plan_files: vec!["plan.md".to_string(), "tasks.md".to_string()],
+        ..ReadOptions::default()
+    };
+    let svc = ConductorService::with_options(tmp.path(), DEFAULT_TRACKS_SUBDIR, options)
+        .unwrap()
+        .with_allow_write(true);
+
+    let paths: FilePathsResponse = serde_json::from_str(&svc.get_track_file_paths(Parameters(
+        GetTrackFilePathsParams {
+            track_id: "alpha".into(),
+            relative_paths: None,
+        },
+    )))
+    .unwrap();
+    assert!(paths.plan_md.unwrap().ends_with("tasks.md"));
+    let issues: Vec<ValidationIssue> = serde_json::from_str(&svc.validate_conductor()).unwrap();
+    assert!(
+        issues.iter().all(|i| i.kind != "missing_plan"),
+        "{issues:?}"
+    );
+
+    let entry: TaskEntry =
+        serde_json::from_str(&svc.set_task_done(Parameters(SetTaskDoneParams {
+            task_id: "alpha#0.0".into(),
+            done: true,
+        })))
+        .unwrap();
+    assert!(entry.done);
+    let resp: CompleteTrackResponse =
+        serde_json::from_str(&svc.complete_track(Parameters(CompleteTrackParams {
+            track_id: "alpha".into(),
+            tick_all_tasks: Some(true),
+        })))
+        .unwrap();
+    assert_eq!(resp.tasks_ticked, 1);
+    assert_eq!(
+        fs::read_to_string(&tasks).unwrap(),
+        "## Phase 1\n- [x] First\n- [x] Second\n"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// get_overdue_tasks
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_overdue_tasks_on_fixture() {
+    let svc = ConductorService::new(&fixture_dir("overdue")).unwrap();
+    let tasks: Vec<OverdueTask> = serde_json::from_str(&svc.get_overdue_tasks()).unwrap();
+
+    let texts: Vec<&str> = tasks.iter().map(|t| t.task.as_str()).collect();
+    assert_eq!(texts, vec!["Later task", "Late task"]);
+    assert_eq!(tasks[0].due, "2020-01-01");
+    assert_eq!(tasks[0].phase, "Phase 1: Ship");
+}
+
+// ---------------------------------------------------------------------------
+// get_estimated_remaining_effort
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_remaining_effort_on_fixture() {
+    let svc = ConductorService::new(&fixture_dir("effort")).unwrap();
+    let resp: RemainingEffortResponse =
+        serde_json::from_str(&svc.get_estimated_remaining_effort()).unwrap();
+
+    // Alpha: 8 points, 1 of 4 tasks done -> 6 remaining. Beta is complete,
+    // so excluded entirely; Gamma is incomplete with no estimate.
+    assert!((resp.total_remaining - 6.0).abs() < f32::EPSILON);
+    assert_eq!(resp.tracks_counted, 1);
+    assert_eq!(resp.tracks_missing_effort, 1);
+    assert_eq!(resp.by_priority.len(), 1);
+    assert!((resp.by_priority["HIGH"] - 6.0).abs() < f32::EPSILON);
+}
+
+// ---------------------------------------------------------------------------
+// get_changelog
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_changelog_only_includes_completed_tracks_in_window() {
+    let svc = ConductorService::new(&fixture_dir("changelog")).unwrap();
+    let changelog = |since: Option<&str>, group_by: Option<&str>| {
+        svc.get_changelog(Parameters(GetChangelogParams {
+            since: since.map(String::from),
+            group_by: group_by.map(String::from),
+        }))
+    };
+
+    let groups: Vec<ChangelogGroup> =
+        serde_json::from_str(&changelog(Some("2026-02-01"), None)).unwrap();
+    assert_eq!(groups.len(), 1);
+    let ids: Vec<&str> = groups[0]
+        .entries
+        .iter()
+        .map(|e| e.track_id.as_str())
+        .collect();
+    assert_eq!(
+        ids,
+        vec!["recent", "untagged"],
+        "newest first, in window only"
+    );
+    assert_eq!(
+        groups[0].entries[0].entry,
+        "- recent (`feat/recent`) [api, otel]"
+    );
+
+    // Without a date every complete track is listed
+    let groups: Vec<ChangelogGroup> = serde_json::from_str(&changelog(None, None)).unwrap();
+    assert_eq!(groups[0].entries.len(), 3);
+
+    let groups: Vec<ChangelogGroup> =
+        serde_json::from_str(&changelog(Some("2026-02-01"), Some("tag"))).unwrap();
+    let names: Vec<&str> = groups.iter().map(|g| g.group.as_str()).collect();
+    assert_eq!(names, vec!["api", "otel", "untagged"]);
+
+    assert!(changelog(Some("not a date"), None).starts_with("Invalid date"));
+    assert!(changelog(None, Some("owner")).starts_with("Invalid group_by"));
+}
+
+// ---------------------------------------------------------------------------
+// get_tracks_by_date
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_tracks_by_date_created_and_completed() {
+    let svc = ConductorService::new(&fixture_dir("by_date")).unwrap();
+    let by_date = |date: &str, field: &str, utc_offset: Option<&str>| {
+        svc.get_tracks_by_date(Parameters(GetTracksByDateParams {
+            date: date.to_string(),
+            field: field.to_string(),
+            utc_offset: utc_offset.map(String::from),
+        }))
+    };
+    let ids = |result: String| -> Vec<String> {
+        let parsed: Vec<TrackSummaryResponse> = serde_json::from_str(&result).unwrap();
+        parsed.into_iter().map(|t| t.id).collect()
+    };
+
+    assert_eq!(ids(by_date("2026-02-10", "created", None)), vec!["started"]);
+    assert_eq!(ids(by_date("2026-02-09", "created", None)), vec!["shipped"]);
+    // Only complete tracks count as completed, however recently updated
+    assert_eq!(
+        ids(by_date("2026-02-10", "completed", None)),
+        vec!["shipped"]
+    );
+    // 22:30 UTC is already the next day two hours east
+    assert!(ids(by_date("2026-02-10", "completed", Some("+02:00"))).is_empty());
+    assert_eq!(
+        ids(by_date("2026-02-11", "completed", Some("+02:00"))),
+        vec!["shipped"]
+    );
+
+    assert!(by_date("someday", "created", None).starts_with("Invalid date"));
+    assert!(by_date("2026-02-10", "updated", None).starts_with("Invalid field"));
+    assert!(by_date("2026-02-10", "created", Some("CET")).starts_with("Invalid utc_offset"));
+}
+
 // ---------------------------------------------------------------------------
 // get_track_file_paths
 // ---------------------------------------------------------------------------
@@ -298,9 +895,39 @@ fn test_file_paths_existing_track() {
 
     let result = svc.get_track_file_paths(Parameters(GetTrackFilePathsParams {
         track_id: first.id.clone(),
+        relative_paths: None,
     }));
     let paths: FilePathsResponse = serde_json::from_str(&result).unwrap();
     assert!(paths.track_dir.contains(&first.id));
+    assert!(paths.track_dir.starts_with(env!("CARGO_MANIFEST_DIR")));
+}
+
+#[test]
+fn test_file_paths_relative_to_repo_root() {
+    let svc = service();
+    let id = "auth_failure_ui_20260124";
+    let result = svc.get_track_file_paths(Parameters(GetTrackFilePathsParams {
+        track_id: id.into(),
+        relative_paths: Some(true),
+    }));
+    let paths: FilePathsResponse = serde_json::from_str(&result).unwrap();
+    assert_eq!(paths.track_dir, format!("conductor/tracks/{id}"));
+    assert_eq!(
+        paths.metadata_json.as_deref(),
+        Some(format!("conductor/tracks/{id}/metadata.json").as_str())
+    );
+
+    // The detail tool applies the same option to its file_paths
+    let detail: TrackDetailResponse =
+        serde_json::from_str(&svc.get_track_detail(Parameters(GetTrackDetailParams {
+            track_id: id.into(),
+            relative_paths: Some(true),
+        })))
+        .unwrap();
+    assert_eq!(
+        detail.file_paths.track_dir,
+        format!("conductor/tracks/{id}")
+    );
 }
 
 #[test]
@@ -308,6 +935,175 @@ fn test_file_paths_nonexistent() {
     let svc = service();
     let result = svc.get_track_file_paths(Parameters(GetTrackFilePathsParams {
         track_id: "nonexistent_xyz".into(),
+        relative_paths: None,
     }));
     assert!(result.contains("not found"));
 }
+
+// ---------------------------------------------------------------------------
+// validate_conductor
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_validate_conductor_reports_issues() {
+    let svc = ConductorService::new(&fixture_dir("validate")).unwrap();
+    let issues: Vec<ValidationIssue> = serde_json::from_str(&svc.validate_conductor()).unwrap();
+    let found: Vec<(&str, &str)> = issues
+        .iter()
+        .map(|i| (i.kind.as_str(), i.track_id.as_str()))
+        .collect();
+
+    assert!(found.contains(&("duplicate_id", "beta")));
+    assert!(found.contains(&("status_mismatch", "alpha")));
+    assert!(found.contains(&("missing_plan", "beta")));
+    assert!(found.contains(&("unresolved_dependency", "alpha")));
+    assert!(found.contains(&("orphan_directory", "stray")));
+    assert!(!found.contains(&("missing_plan", "alpha")));
+    let mismatch = issues
+        .iter()
+        .find(|i| i.kind == "task_count_mismatch")
+        .unwrap();
+    assert_eq!(mismatch.track_id, "alpha");
+    assert_eq!(mismatch.message, "plan has 1 of 3 declared tasks");
+    assert!(found.contains(&("invalid_metadata", "beta")));
+    let shadowed = issues
+        .iter()
+        .find(|i| i.kind == "shadowed_metadata")
+        .unwrap();
+    assert_eq!(shadowed.track_id, "alpha");
+    assert_eq!(shadowed.message, "meta.toml ignored; metadata.json is read");
+    assert_eq!(issues.len(), 8);
+}
+
+#[test]
+fn test_validate_flat_layout_skips_non_track_folders() {
+    // Track folders sit beside tracks.md, with the usual non-track folders
+    let svc = ConductorService::with_tracks_subdir(&fixture_dir("flat_conductor"), ".").unwrap();
+    let issues: Vec<ValidationIssue> = serde_json::from_str(&svc.validate_conductor()).unwrap();
+    let found: Vec<(&str, &str)> = issues
+        .iter()
+        .map(|i| (i.kind.as_str(), i.track_id.as_str()))
+        .collect();
+    assert_eq!(found, [("orphan_directory", "stray_track")]);
+}
+
+// ---------------------------------------------------------------------------
+// HTTP JSON API
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_http_serves_tool_json() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(conductor_dashboard::mcp::http::serve(service(), listener));
+
+    let get = |path: &str| {
+        let request = format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n");
+        async move {
+            let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            let (head, body) = response.split_once("\r\n\r\n").unwrap();
+            (head.lines().next().unwrap().to_string(), body.to_string())
+        }
+    };
+
+    let (status, body) = get("/summary").await;
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    let summary: SummaryResponse = serde_json::from_str(&body).unwrap();
+    assert_eq!(body, service().get_summary());
+
+    let (status, body) = get("/tracks?status=complete").await;
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    let tracks: Vec<TrackSummaryResponse> = serde_json::from_str(&body).unwrap();
+    assert_eq!(tracks.len(), summary.by_status.complete);
+    assert!(tracks.iter().all(|t| t.status == "Complete"));
+
+    let id = &tracks[0].id;
+    let (status, body) = get(&format!("/tracks/{id}")).await;
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    let detail: TrackDetailResponse = serde_json::from_str(&body).unwrap();
+    assert_eq!(&detail.id, id);
+
+    let (status, body) = get("/tracks/no_such_track_xyz").await;
+    assert_eq!(status, "HTTP/1.1 404 Not Found");
+    assert!(body.contains("\"error\""));
+    assert_eq!(get("/nope").await.0, "HTTP/1.1 404 Not Found");
+
+    let (status, body) = get("/metrics").await;
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert_eq!(body, service().get_metrics());
+}
+
+#[test]
+fn test_http_routes_set_their_content_type() {
+    use conductor_dashboard::mcp::http::respond;
+
+    let svc = service();
+    assert_eq!(
+        respond(&svc, "GET", "/metrics").1,
+        "text/plain; version=0.0.4"
+    );
+    for target in ["/summary", "/tracks", "/nope"] {
+        assert_eq!(respond(&svc, "GET", target).1, "application/json");
+    }
+    assert_eq!(respond(&svc, "POST", "/summary").1, "application/json");
+}
+
+#[tokio::test]
+async fn test_http_reloads_after_file_change() {
+    use std::fs;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let tmp = fixture_copy("http_reload");
+    let plan = tmp.path().join("tracks/alpha/plan.md");
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let service = ConductorService::new(tmp.path()).unwrap();
+    tokio::spawn(conductor_dashboard::mcp::http::serve(service, listener));
+
+    let summary = || async move {
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /summary HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(!response.contains("Access-Control-Allow-Origin"));
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        serde_json::from_str::<SummaryResponse>(body).unwrap()
+    };
+
+    assert_eq!(summary().await.total_tasks, 1);
+    fs::write(&plan, "## Phase 1\n- [x] First\n- [ ] Second\n").unwrap();
+    let after = summary().await;
+    assert_eq!(after.total_tasks, 2);
+    assert_eq!(after.total_tasks_completed, 1);
+}
+
+#[test]
+fn test_with_options_keeps_real_progress_without_normalize() {
+    use conductor_dashboard::parser::{ReadOptions, DEFAULT_TRACKS_SUBDIR};
+
+    let options = ReadOptions {
+        normalize_complete: false,
+        ..ReadOptions::default()
+    };
+    let raw =
+        ConductorService::with_options(&conductor_dir(), DEFAULT_TRACKS_SUBDIR, options).unwrap();
+    assert!(raw
+        .tracks()
+        .values()
+        .all(|t| t.tasks_completed == t.raw_tasks_completed));
+    // The default service shows every task of a Complete track as done
+    assert!(service()
+        .tracks()
+        .values()
+        .filter(|t| t.is_complete())
+        .all(|t| t.tasks_completed == t.tasks_total));
+}
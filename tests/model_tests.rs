@@ -9,16 +9,62 @@ use conductor_dashboard::model::*;
 #[test]
 fn test_filter_mode_cycles() {
     assert_eq!(FilterMode::All.next(), FilterMode::Active);
-    assert_eq!(FilterMode::Active.next(), FilterMode::Blocked);
+    assert_eq!(FilterMode::Active.next(), FilterMode::Review);
+    assert_eq!(FilterMode::Review.next(), FilterMode::Blocked);
     assert_eq!(FilterMode::Blocked.next(), FilterMode::Complete);
     assert_eq!(FilterMode::Complete.next(), FilterMode::New);
     assert_eq!(FilterMode::New.next(), FilterMode::All);
 }
 
+#[test]
+fn test_tag_match_any_and_all() {
+    let selected: Vec<String> = vec!["backend".into(), "Security".into()];
+    let tags = |t: &[&str]| t.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    assert!(TagMatch::Any.matches(&selected, &tags(&["backend", "ui"])));
+    assert!(!TagMatch::All.matches(&selected, &tags(&["backend", "ui"])));
+    assert!(TagMatch::All.matches(&selected, &tags(&["security", "BACKEND"])));
+    assert!(!TagMatch::Any.matches(&selected, &tags(&[])));
+    // No selection filters nothing
+    assert!(TagMatch::All.matches(&Vec::new(), &tags(&[])));
+    assert_eq!(TagMatch::Any.toggle(), TagMatch::All);
+}
+
+#[test]
+fn test_plan_filter_cycles_and_matches() {
+    assert_eq!(PlanFilter::Any.next(), PlanFilter::WithPlan);
+    assert_eq!(PlanFilter::WithPlan.next(), PlanFilter::WithoutPlan);
+    assert_eq!(PlanFilter::WithoutPlan.next(), PlanFilter::Any);
+
+    assert!(PlanFilter::Any.matches(true) && PlanFilter::Any.matches(false));
+    assert!(PlanFilter::WithPlan.matches(true) && !PlanFilter::WithPlan.matches(false));
+    assert!(!PlanFilter::WithoutPlan.matches(true) && PlanFilter::WithoutPlan.matches(false));
+}
+
+#[test]
+fn test_type_filter_cycles_and_matches() {
+    let mut filter = TypeFilter::All;
+    let mut labels = Vec::new();
+    for _ in 0..6 {
+        filter = filter.next();
+        labels.push(filter.label());
+    }
+    assert_eq!(
+        labels,
+        ["Feature", "Bug", "Migration", "Refactor", "Other", "All"]
+    );
+
+    assert!(TypeFilter::All.matches(&TrackType::Bug));
+    assert!(TypeFilter::Bug.matches(&TrackType::Bug));
+    assert!(!TypeFilter::Bug.matches(&TrackType::Feature));
+    assert!(TypeFilter::Other.matches(&TrackType::Other));
+}
+
 #[test]
 fn test_sort_mode_toggles() {
     assert_eq!(SortMode::Updated.next(), SortMode::Progress);
-    assert_eq!(SortMode::Progress.next(), SortMode::Updated);
+    assert_eq!(SortMode::Progress.next(), SortMode::Type);
+    assert_eq!(SortMode::Type.next(), SortMode::Updated);
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -36,6 +82,10 @@ fn test_status_from_str_loose() {
     assert_eq!(Status::from_str_loose("implementation"), Status::InProgress);
     assert_eq!(Status::from_str_loose("blocked"), Status::Blocked);
     assert_eq!(Status::from_str_loose("on_hold"), Status::Blocked);
+    assert_eq!(Status::from_str_loose("review"), Status::Review);
+    assert_eq!(Status::from_str_loose("In_Review"), Status::Review);
+    assert_eq!(Status::from_str_loose("testing"), Status::Review);
+    assert_eq!(Status::from_str_loose("QA"), Status::Review);
     assert_eq!(Status::from_str_loose("not_started"), Status::New);
     assert_eq!(Status::from_str_loose("new"), Status::New);
     assert_eq!(Status::from_str_loose("planning"), Status::New);
@@ -66,6 +116,13 @@ fn test_track_type_from_str_loose() {
     assert_eq!(TrackType::from_str_loose("other"), TrackType::Other);
 }
 
+#[test]
+fn test_checkbox_markers() {
+    assert_eq!(CheckboxStatus::Unchecked.marker(), "[ ]");
+    assert_eq!(CheckboxStatus::InProgress.marker(), "[~]");
+    assert_eq!(CheckboxStatus::Checked.marker(), "[x]");
+}
+
 #[test]
 fn test_checkbox_to_status() {
     assert_eq!(CheckboxStatus::Unchecked.to_status(), Status::New);
@@ -134,6 +191,125 @@ fn test_track_not_complete() {
     assert!(!track.is_complete());
 }
 
+#[test]
+fn test_track_in_review_not_complete() {
+    let track = Track {
+        status: Status::Review,
+        tasks_total: 5,
+        tasks_completed: 5,
+        ..Track::default()
+    };
+    assert!(!track.is_complete());
+}
+
+#[test]
+fn test_track_is_owned_by_branch_prefix_or_owner() {
+    let by_branch = Track {
+        branch: Some("LD/dashboard-fix".to_string()),
+        ..Track::default()
+    };
+    assert!(by_branch.is_owned_by("ld/"));
+    assert!(!by_branch.is_owned_by("dashboard"));
+
+    let by_owner = Track {
+        owner: Some("Luis Dee".to_string()),
+        ..Track::default()
+    };
+    assert!(by_owner.is_owned_by("luis"));
+    assert!(!by_owner.is_owned_by("ld/"));
+
+    assert!(!Track::default().is_owned_by("ld/"));
+    assert!(Track::default().is_owned_by(""));
+}
+
+#[test]
+fn test_track_branch_prefix() {
+    let prefix = |branch: &str| {
+        Track {
+            branch: Some(branch.to_string()),
+            ..Track::default()
+        }
+        .branch_prefix()
+        .map(str::to_string)
+    };
+    assert_eq!(prefix("feat/x").as_deref(), Some("feat"));
+    assert_eq!(prefix("DSS-1234").as_deref(), Some("DSS"));
+    assert_eq!(prefix("fix/login-form").as_deref(), Some("fix"));
+    assert_eq!(prefix("main").as_deref(), Some("main"));
+    assert_eq!(prefix(""), None);
+    assert_eq!(Track::default().branch_prefix(), None);
+}
+
+#[test]
+fn test_task_ids_follow_plan_positions() {
+    let task = |text: &str| PlanTask {
+        text: text.to_string(),
+        done: false,
+        due: None,
+        depth: 0,
+    };
+    let phase = |name: &str, tasks: Vec<PlanTask>| PlanPhase {
+        name: name.to_string(),
+        status: PhaseStatus::Pending,
+        tasks,
+        depth: 0,
+        completed_at: None,
+    };
+    let track = Track {
+        id: TrackId::new("alpha"),
+        plan_phases: vec![
+            phase("One", vec![task("a"), task("b")]),
+            phase("Two", vec![task("c")]),
+        ],
+        ..Track::default()
+    };
+
+    let ids: Vec<(String, &str)> = track
+        .tasks_with_ids()
+        .map(|(id, _, task)| (id, task.text.as_str()))
+        .collect();
+    assert_eq!(
+        ids,
+        vec![
+            ("alpha#0.0".to_string(), "a"),
+            ("alpha#0.1".to_string(), "b"),
+            ("alpha#1.0".to_string(), "c"),
+        ]
+    );
+}
+
+#[test]
+fn test_is_archived_uses_age_and_status() {
+    use chrono::{Duration, TimeZone, Utc};
+
+    let now = Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap();
+    let old_complete = Track {
+        status: Status::Complete,
+        updated_at: Some(now - Duration::days(40)),
+        ..Track::default()
+    };
+    assert!(old_complete.is_archived(now, Duration::days(30)));
+    assert!(!old_complete.is_archived(now, Duration::days(60)));
+
+    // Falls back to created_at; open tracks and undated tracks are never archived
+    let created_only = Track {
+        status: Status::Complete,
+        created_at: Some(now - Duration::days(40)),
+        ..Track::default()
+    };
+    assert!(created_only.is_archived(now, Duration::days(30)));
+    let old_open = Track {
+        status: Status::InProgress,
+        ..old_complete.clone()
+    };
+    assert!(!old_open.is_archived(now, Duration::days(30)));
+    let undated = Track {
+        status: Status::Complete,
+        ..Track::default()
+    };
+    assert!(!undated.is_archived(now, Duration::days(0)));
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Track merge
 // ═══════════════════════════════════════════════════════════════════════════
@@ -154,6 +330,7 @@ fn test_merge_metadata_overrides_non_defaults() {
         track_type: TrackType::Feature,
         branch: Some("feat/test".to_string()),
         tags: vec!["backend".to_string()],
+        external_blockers: vec!["SAP upgrade".to_string()],
         ..TrackMetadata::default()
     };
 
@@ -164,6 +341,7 @@ fn test_merge_metadata_overrides_non_defaults() {
     assert_eq!(track.track_type, TrackType::Feature);
     assert_eq!(track.branch.as_deref(), Some("feat/test"));
     assert_eq!(track.tags, vec!["backend"]);
+    assert_eq!(track.external_blockers, vec!["SAP upgrade"]);
 }
 
 #[test]
@@ -195,12 +373,18 @@ fn test_merge_plan_updates_task_counts() {
                 PlanTask {
                     text: "A".to_string(),
                     done: true,
+                    due: None,
+                    depth: 0,
                 },
                 PlanTask {
                     text: "B".to_string(),
                     done: true,
+                    due: None,
+                    depth: 0,
                 },
             ],
+            depth: 0,
+            completed_at: None,
         },
         PlanPhase {
             name: "Phase 2".to_string(),
@@ -209,16 +393,24 @@ fn test_merge_plan_updates_task_counts() {
                 PlanTask {
                     text: "C".to_string(),
                     done: true,
+                    due: None,
+                    depth: 0,
                 },
                 PlanTask {
                     text: "D".to_string(),
                     done: false,
+                    due: None,
+                    depth: 0,
                 },
                 PlanTask {
                     text: "E".to_string(),
                     done: false,
+                    due: None,
+                    depth: 0,
                 },
             ],
+            depth: 0,
+            completed_at: None,
         },
     ];
 
@@ -230,6 +422,182 @@ fn test_merge_plan_updates_task_counts() {
     assert_eq!(track.phase, "Phase 2");
 }
 
+#[test]
+fn test_metadata_completed_tasks_override_plan_checkboxes() {
+    let phases = vec![PlanPhase {
+        name: "Phase 1".to_string(),
+        status: PhaseStatus::Active,
+        tasks: vec![
+            PlanTask {
+                text: "Setup DB".to_string(),
+                done: false,
+                due: None,
+                depth: 0,
+            },
+            PlanTask {
+                text: "Build API".to_string(),
+                done: false,
+                due: None,
+                depth: 0,
+            },
+        ],
+        depth: 0,
+        completed_at: None,
+    }];
+    let meta = TrackMetadata {
+        completed_tasks: vec!["  setup db ".to_string(), "Not in plan".to_string()],
+        ..TrackMetadata::default()
+    };
+
+    // Metadata first (as the loader does), then the plan
+    let mut track = Track::default();
+    track.merge_metadata(meta.clone());
+    track.merge_plan(phases.clone());
+    assert!(track.plan_phases[0].tasks[0].done);
+    assert!(!track.plan_phases[0].tasks[1].done);
+    assert_eq!(track.tasks_completed, 1);
+    assert_eq!(track.tasks_total, 2);
+
+    // Plan first (incremental metadata reload) gives the same result
+    let mut track = Track::default();
+    track.merge_plan(phases);
+    track.merge_metadata(meta);
+    assert!(track.plan_phases[0].tasks[0].done);
+    assert_eq!(track.tasks_completed, 1);
+}
+
+#[test]
+fn test_metadata_completed_tasks_rederive_phases_and_reset() {
+    let phase = |name: &str, task: &str| PlanPhase {
+        name: name.to_string(),
+        status: PhaseStatus::Pending,
+        tasks: vec![PlanTask {
+            text: task.to_string(),
+            done: false,
+            due: None,
+            depth: 0,
+        }],
+        depth: 0,
+        completed_at: None,
+    };
+    let mut phases = vec![phase("Phase 1", "Setup DB"), phase("Phase 2", "Build API")];
+    phases[0].status = PhaseStatus::Active;
+    let mut track = Track::default();
+    track.merge_plan(phases);
+    assert_eq!(track.phase, "Phase 1");
+
+    track.merge_metadata(TrackMetadata {
+        completed_tasks: vec!["Setup DB".to_string()],
+        ..TrackMetadata::default()
+    });
+    let statuses: Vec<PhaseStatus> = track.plan_phases.iter().map(|p| p.status).collect();
+    assert_eq!(statuses, vec![PhaseStatus::Complete, PhaseStatus::Active]);
+    assert_eq!(track.phase, "Phase 2");
+    assert_eq!(track.tasks_completed, 1);
+
+    // A re-read without the list drops the override
+    track.merge_metadata(TrackMetadata::default());
+    assert!(track.completed_tasks.is_empty());
+    assert!(!track.plan_phases[0].tasks[0].done);
+    let statuses: Vec<PhaseStatus> = track.plan_phases.iter().map(|p| p.status).collect();
+    assert_eq!(statuses, vec![PhaseStatus::Active, PhaseStatus::Pending]);
+    assert_eq!(track.phase, "Phase 1");
+    assert_eq!(track.tasks_completed, 0);
+}
+
+#[test]
+fn test_declared_task_total_mismatch_warning() {
+    let task = |text: &str| PlanTask {
+        text: text.to_string(),
+        done: false,
+        due: None,
+        depth: 0,
+    };
+    let mut track = Track::default();
+    track.merge_plan(vec![PlanPhase {
+        name: "Phase 1".to_string(),
+        status: PhaseStatus::Pending,
+        tasks: vec![task("One"), task("Two")],
+        depth: 0,
+        completed_at: None,
+    }]);
+    assert_eq!(track.task_count_warning(), None);
+
+    let declare = |track: &mut Track, total| {
+        track.merge_metadata(TrackMetadata {
+            declared_tasks: Some(total),
+            ..TrackMetadata::default()
+        })
+    };
+    declare(&mut track, 5);
+    assert_eq!(
+        track.task_count_warning().as_deref(),
+        Some("plan has 2 of 5 declared tasks")
+    );
+    declare(&mut track, 1);
+    assert_eq!(
+        track.task_count_warning().as_deref(),
+        Some("plan has 2 tasks but metadata declares 1")
+    );
+    declare(&mut track, 2);
+    assert_eq!(track.task_count_warning(), None);
+}
+
+#[test]
+fn test_compare_tracks_fallbacks_and_tiebreaks() {
+    use chrono::TimeZone;
+
+    let day = |d| Some(chrono::Utc.with_ymd_and_hms(2026, 2, d, 0, 0, 0).unwrap());
+    let track = |id: &str, updated, created| Track {
+        id: TrackId::new(id),
+        updated_at: updated,
+        created_at: created,
+        ..Track::default()
+    };
+    let mut tracks = [
+        track("undated", None, None),
+        track("b_created_only", None, day(10)),
+        track("a_updated", day(5), day(1)),
+        track("a_created_only", None, day(10)),
+    ];
+
+    tracks.sort_by(|a, b| compare_tracks(a, b, SortMode::Updated));
+    let ids: Vec<&str> = tracks.iter().map(|t| t.id.as_str()).collect();
+    // created_at stands in for updated_at; equal dates fall back to ID; undated last
+    assert_eq!(
+        ids,
+        vec!["a_created_only", "b_created_only", "a_updated", "undated"]
+    );
+
+    // All at 0% progress, so the ID tiebreak decides
+    tracks.sort_by(|a, b| compare_tracks(a, b, SortMode::Progress));
+    let ids: Vec<&str> = tracks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec!["a_created_only", "a_updated", "b_created_only", "undated"]
+    );
+}
+
+#[test]
+fn test_compare_tracks_groups_by_type_then_title() {
+    let track = |id: &str, title: &str, track_type| Track {
+        id: TrackId::new(id),
+        title: title.to_string(),
+        track_type,
+        ..Track::default()
+    };
+    let mut tracks = [
+        track("other", "Alpha", TrackType::Other),
+        track("feat_b", "beta feature", TrackType::Feature),
+        track("bug", "Zed crash", TrackType::Bug),
+        track("feat_a", "Alpha feature", TrackType::Feature),
+        track("refactor", "Cleanup", TrackType::Refactor),
+    ];
+    tracks.sort_by(|a, b| compare_tracks(a, b, SortMode::Type));
+    let ids: Vec<&str> = tracks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, vec!["bug", "feat_a", "feat_b", "refactor", "other"]);
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // PlanPhase progress
 // ═══════════════════════════════════════════════════════════════════════════
@@ -240,6 +608,8 @@ fn test_plan_phase_progress_empty() {
         name: "Empty".to_string(),
         status: PhaseStatus::Pending,
         tasks: Vec::new(),
+        depth: 0,
+        completed_at: None,
     };
     assert!((phase.progress_percent() - 0.0).abs() < f32::EPSILON);
 }
@@ -253,20 +623,30 @@ fn test_plan_phase_progress_partial() {
             PlanTask {
                 text: "A".to_string(),
                 done: true,
+                due: None,
+                depth: 0,
             },
             PlanTask {
                 text: "B".to_string(),
                 done: false,
+                due: None,
+                depth: 0,
             },
             PlanTask {
                 text: "C".to_string(),
                 done: false,
+                due: None,
+                depth: 0,
             },
             PlanTask {
                 text: "D".to_string(),
                 done: false,
+                due: None,
+                depth: 0,
             },
         ],
+        depth: 0,
+        completed_at: None,
     };
     assert!((phase.progress_percent() - 25.0).abs() < f32::EPSILON);
     assert_eq!(phase.tasks_completed(), 1);
@@ -305,6 +685,7 @@ fn test_filter_mode_labels() {
 fn test_sort_mode_labels() {
     assert_eq!(SortMode::Updated.label(), "Recent");
     assert_eq!(SortMode::Progress.label(), "Progress");
+    assert_eq!(SortMode::Type.label(), "Type");
 }
 
 #[test]
@@ -333,3 +714,204 @@ fn test_priority_ordering() {
     assert!(Priority::High < Priority::Medium);
     assert!(Priority::Medium < Priority::Low);
 }
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TrackSetDiff
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_track_set_diff_summary() {
+    use std::collections::BTreeMap;
+
+    let set = |tracks: &[(&str, Status)]| -> BTreeMap<TrackId, Track> {
+        tracks
+            .iter()
+            .map(|(id, status)| {
+                let track = Track {
+                    id: TrackId::new(*id),
+                    status: *status,
+                    ..Track::default()
+                };
+                (track.id.clone(), track)
+            })
+            .collect()
+    };
+    let old = set(&[
+        ("a", Status::New),
+        ("b", Status::InProgress),
+        ("gone", Status::New),
+    ]);
+    let new = set(&[
+        ("a", Status::New),
+        ("b", Status::Complete),
+        ("c", Status::New),
+        ("d", Status::Blocked),
+    ]);
+
+    let diff = TrackSetDiff::between(&old, &new);
+    assert_eq!(diff.added, vec![TrackId::new("c"), TrackId::new("d")]);
+    assert_eq!(diff.removed, vec![TrackId::new("gone")]);
+    assert_eq!(
+        diff.status_changed,
+        vec![(TrackId::new("b"), Status::InProgress, Status::Complete)]
+    );
+    assert_eq!(diff.summary(), "+2 tracks, 1 removed, 1 status change");
+
+    assert!(TrackSetDiff::between(&new, &new).is_empty());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Track query language
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_track_query_parses_tokens() {
+    let query =
+        TrackQuery::parse("status:blocked,Review tag:Backend prio:high type:bug overdue Login");
+    assert_eq!(query.statuses, vec![vec![Status::Blocked, Status::Review]]);
+    assert_eq!(query.tags, vec![vec!["backend".to_string()]]);
+    assert_eq!(query.priorities, vec![vec![Priority::High]]);
+    assert_eq!(query.types, vec![vec![TrackType::Bug]]);
+    assert!(query.overdue);
+    assert_eq!(query.words, vec!["login"]);
+    assert!(query.ignored.is_empty());
+
+    assert_eq!(
+        TrackQuery::parse("is:new").statuses,
+        vec![vec![Status::New]]
+    );
+    assert_eq!(TrackQuery::parse("   "), TrackQuery::default());
+}
+
+#[test]
+fn test_track_query_ignores_unknown_tokens() {
+    let query = TrackQuery::parse("owner:me status:bogus prio:urgent type:epic status: tag:ui");
+    assert_eq!(
+        query.ignored,
+        vec![
+            "owner:me",
+            "status:bogus",
+            "prio:urgent",
+            "type:epic",
+            "status:"
+        ]
+    );
+    assert!(query.statuses.is_empty() && query.priorities.is_empty() && query.types.is_empty());
+    assert_eq!(query.tags, vec![vec!["ui".to_string()]]);
+}
+
+#[test]
+fn test_track_query_matches_all_tokens() {
+    use chrono::TimeZone;
+
+    let now = chrono::Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+    let mut track = Track {
+        id: TrackId::new("login_fix_20260201"),
+        title: "Fix SSO login".to_string(),
+        status: Status::Blocked,
+        priority: Priority::High,
+        track_type: TrackType::Bug,
+        tags: vec!["Backend".to_string(), "auth".to_string()],
+        ..Track::default()
+    };
+    let matches = |q: &str, t: &Track| TrackQuery::parse(q).matches(t, now);
+
+    assert!(matches("", &track));
+    assert!(matches(
+        "status:blocked tag:backend prio:high type:bug sso",
+        &track
+    ));
+    assert!(matches(
+        "status:review,blocked tag:ui,auth fix_20260201",
+        &track
+    ));
+    assert!(!matches("status:blocked tag:ui", &track));
+    assert!(!matches("prio:low", &track));
+    assert!(!matches("oauth", &track));
+    // Unknown tokens don't filter anything out
+    assert!(matches("owner:me sso", &track));
+
+    assert!(!matches("overdue", &track));
+    track.merge_plan(vec![PlanPhase {
+        name: "Phase 1".to_string(),
+        status: PhaseStatus::Active,
+        tasks: vec![PlanTask {
+            text: "Patch callback".to_string(),
+            done: false,
+            due: Some(chrono::Utc.with_ymd_and_hms(2026, 2, 20, 0, 0, 0).unwrap()),
+            depth: 0,
+        }],
+        depth: 0,
+        completed_at: None,
+    }]);
+    assert!(matches("overdue", &track));
+}
+
+#[test]
+fn test_blocked_by_incomplete_dependencies() {
+    use std::collections::BTreeMap;
+
+    let track = |id: &str, status, deps: &[&str]| Track {
+        id: TrackId::new(id),
+        status,
+        dependencies: deps.iter().map(|d| TrackId::new(*d)).collect(),
+        ..Track::default()
+    };
+    let tracks: BTreeMap<TrackId, Track> = [
+        track("done", Status::Complete, &[]),
+        track("wip", Status::InProgress, &[]),
+        track("app", Status::New, &["done", "wip", "ghost"]),
+        track("ready", Status::New, &["done"]),
+    ]
+    .into_iter()
+    .map(|t| (t.id.clone(), t))
+    .collect();
+
+    // An incomplete dependency and one missing from the map both hold it up
+    assert_eq!(
+        tracks[&TrackId::new("app")].is_blocked_by_incomplete(&tracks),
+        vec![TrackId::new("wip"), TrackId::new("ghost")]
+    );
+    assert!(tracks[&TrackId::new("ready")]
+        .is_blocked_by_incomplete(&tracks)
+        .is_empty());
+}
+
+#[test]
+fn test_blocked_by_incomplete_follows_unmet_dependencies_upstream() {
+    use std::collections::BTreeMap;
+
+    let track = |id: &str, status, deps: &[&str]| Track {
+        id: TrackId::new(id),
+        status,
+        dependencies: deps.iter().map(|d| TrackId::new(*d)).collect(),
+        ..Track::default()
+    };
+    let tracks: BTreeMap<TrackId, Track> = [
+        track("base", Status::InProgress, &[]),
+        track("api", Status::New, &["base", "ghost"]),
+        track("ui", Status::New, &["api", "base"]),
+        // Complete, so whatever it depends on no longer matters
+        track("shipped", Status::Complete, &["base"]),
+        track("docs", Status::New, &["shipped"]),
+        track("loop_a", Status::New, &["loop_b"]),
+        track("loop_b", Status::New, &["loop_a"]),
+    ]
+    .into_iter()
+    .map(|t| (t.id.clone(), t))
+    .collect();
+    let blocked_by = |id: &str| tracks[&TrackId::new(id)].is_blocked_by_incomplete(&tracks);
+
+    // Nearest first, each listed once
+    assert_eq!(
+        blocked_by("ui"),
+        vec![
+            TrackId::new("api"),
+            TrackId::new("base"),
+            TrackId::new("ghost")
+        ]
+    );
+    assert!(blocked_by("docs").is_empty());
+    // A cycle ends the walk instead of looping
+    assert_eq!(blocked_by("loop_a"), vec![TrackId::new("loop_b")]);
+}
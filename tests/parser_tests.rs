@@ -12,6 +12,14 @@ fn conductor_dir() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("conductor")
 }
 
+/// A small conductor directory under `tests/fixtures`, for layouts and
+/// formats the real one doesn't have.
+fn fixture_dir(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Index parser (tracks.md)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -75,7 +83,7 @@ fn test_status_field_overrides_checkbox() {
 #[test]
 fn test_all_tracks_have_ids() {
     let tracks = parser::index::parse_index(&conductor_dir()).unwrap();
-    for (id, _track) in &tracks {
+    for id in tracks.keys() {
         assert!(!id.as_str().is_empty(), "track should have non-empty ID");
     }
 }
@@ -482,8 +490,112 @@ fn test_plan_with_deeply_nested_tasks() {
 "#;
     let phases = parser::plan::parse_plan_content(md);
     assert_eq!(phases.len(), 1);
-    // Should capture at least the top-level checkbox tasks
-    assert!(phases[0].tasks.len() >= 2);
+    // Plain sub-bullets continue their task; nested checkboxes are tasks
+    let tasks = &phases[0].tasks;
+    assert_eq!(tasks.len(), 3);
+    assert_eq!(
+        tasks[0].text,
+        "Top-level task Sub-item description (not a checkbox) More nested text"
+    );
+    assert!(tasks[0].done);
+    assert_eq!(tasks[1].text, "Another top-level task");
+    assert_eq!(tasks[2].text, "Nested checked item");
+    assert!(tasks[2].done);
+    assert_eq!(
+        tasks.iter().map(|t| t.depth).collect::<Vec<_>>(),
+        vec![0, 0, 1]
+    );
+}
+
+#[test]
+fn test_plan_subtasks_two_levels_counted_with_depth() {
+    let md = r#"## Phase 1: Build
+
+- [ ] Parent
+  - [x] Child one
+  - [ ] Child two
+- [x] Sibling
+"#;
+    let phases = parser::plan::parse_plan_content(md);
+    let tasks = &phases[0].tasks;
+    let shape: Vec<(&str, u8, bool)> = tasks
+        .iter()
+        .map(|t| (t.text.as_str(), t.depth, t.done))
+        .collect();
+    assert_eq!(
+        shape,
+        vec![
+            ("Parent", 0, false),
+            ("Child one", 1, true),
+            ("Child two", 1, false),
+            ("Sibling", 0, true),
+        ]
+    );
+
+    let mut track = conductor_dashboard::model::Track::default();
+    track.merge_plan(phases);
+    assert_eq!(track.tasks_total, 4);
+    assert_eq!(track.tasks_completed, 2);
+}
+
+#[test]
+fn test_plan_subtasks_three_levels() {
+    let md = r#"## Phase 1: Deep
+
+- [ ] Level zero
+  - [ ] Level one
+    - [x] Level two
+      - plain note on level two
+  - [ ] Back to one
+- Plain group
+  - [ ] Under a plain bullet
+"#;
+    let phases = parser::plan::parse_plan_content(md);
+    let shape: Vec<(&str, u8)> = phases[0]
+        .tasks
+        .iter()
+        .map(|t| (t.text.as_str(), t.depth))
+        .collect();
+    assert_eq!(
+        shape,
+        vec![
+            ("Level zero", 0),
+            ("Level one", 1),
+            ("Level two plain note on level two", 2),
+            ("Back to one", 1),
+            // Only task ancestors count toward depth
+            ("Under a plain bullet", 0),
+        ]
+    );
+}
+
+#[test]
+fn test_plan_star_bullets_and_uppercase_markers() {
+    let md = r#"## Phase 1: Mixed Markers
+
+* [X] Star bullet, uppercase
+* [ ] Star bullet, open
+- [X] Dash bullet, uppercase
++ [x] Plus bullet
+1. [X] Ordered item
+"#;
+    let phases = parser::plan::parse_plan_content(md);
+    let tasks: Vec<(&str, bool)> = phases[0]
+        .tasks
+        .iter()
+        .map(|t| (t.text.as_str(), t.done))
+        .collect();
+    assert_eq!(
+        tasks,
+        vec![
+            ("Star bullet, uppercase", true),
+            ("Star bullet, open", false),
+            ("Dash bullet, uppercase", true),
+            ("Plus bullet", true),
+            ("Ordered item", true),
+        ]
+    );
+    assert_eq!(phases[0].status, PhaseStatus::Active);
 }
 
 #[test]
@@ -649,3 +761,384 @@ fn test_parse_synthetic_conductor_directory() {
     // Cleanup
     let _ = fs::remove_dir_all(&tmp);
 }
+
+#[test]
+fn test_load_tracks_from_custom_index_source() {
+    use parser::index::IndexSource;
+
+    let dir = fixture_dir("index_source");
+    let index_file = dir.join("proposed-tracks.md");
+
+    // File source with a non-standard name, track files from the sibling dir
+    let from_file = parser::load_tracks_from_source(
+        &IndexSource::File(index_file.clone()),
+        &dir.join("tracks"),
+    )
+    .unwrap();
+    let gamma = from_file.get(&TrackId::new("gamma_track")).unwrap();
+    assert_eq!(gamma.status, Status::InProgress);
+    assert_eq!(gamma.tasks_total, 2);
+    assert_eq!(gamma.tasks_completed, 1);
+
+    // In-memory content (as read from stdin)
+    let index = std::fs::read_to_string(&index_file).unwrap();
+    let from_content =
+        parser::load_tracks_from_source(&IndexSource::Content(index), &dir.join("tracks")).unwrap();
+    assert_eq!(from_content.len(), 1);
+    assert_eq!(
+        from_content
+            .get(&TrackId::new("gamma_track"))
+            .unwrap()
+            .tasks_total,
+        2
+    );
+
+    // Missing file surfaces IndexNotFound
+    let missing = parser::load_tracks_from_source(
+        &IndexSource::File(dir.join("nope.md")),
+        &dir.join("tracks"),
+    );
+    assert!(matches!(
+        missing,
+        Err(parser::error::ParseError::IndexNotFound(_))
+    ));
+}
+
+#[test]
+fn test_load_tracks_from_custom_tracks_subdir() {
+    let dir = fixture_dir("tracks_subdir");
+
+    let tracks = parser::load_all_tracks_with_subdir(&dir, "streams").unwrap();
+    let delta = tracks.get(&TrackId::new("delta_track")).unwrap();
+    assert_eq!(delta.tasks_total, 3);
+    assert_eq!(delta.tasks_completed, 2);
+
+    // The default subdir finds no plan for the same index
+    let tracks = parser::load_all_tracks(&dir).unwrap();
+    assert_eq!(
+        tracks
+            .get(&TrackId::new("delta_track"))
+            .unwrap()
+            .tasks_total,
+        0
+    );
+}
+
+#[test]
+fn test_raw_task_counts_survive_auto_complete() {
+    let dir = fixture_dir("raw_counts");
+
+    let tracks = parser::load_all_tracks(&dir).unwrap();
+    let echo = tracks.get(&TrackId::new("echo_track")).unwrap();
+    assert_eq!(echo.status, Status::Complete);
+    // Normalised for display...
+    assert_eq!(echo.tasks_completed, 3);
+    assert_eq!(echo.tasks_total, 3);
+    // ...but the actual plan ticks are kept
+    assert_eq!(echo.raw_tasks_completed, 1);
+    assert_eq!(echo.raw_tasks_total, 3);
+    assert!((echo.raw_progress_percent() - 100.0 / 3.0).abs() < 0.01);
+
+    // With normalization off the plan's own ticks are shown
+    let options = parser::ReadOptions {
+        normalize_complete: false,
+        ..parser::ReadOptions::default()
+    };
+    let tracks =
+        parser::load_all_tracks_with_options(&dir, parser::DEFAULT_TRACKS_SUBDIR, &options)
+            .unwrap();
+    let echo = tracks.get(&TrackId::new("echo_track")).unwrap();
+    assert_eq!(echo.status, Status::Complete);
+    assert_eq!(echo.tasks_completed, 1);
+    assert_eq!(echo.tasks_total, 3);
+    assert!(!echo.plan_phases[0].tasks[1].done);
+}
+
+#[test]
+fn test_plan_read_from_first_configured_plan_file() {
+    let dir = fixture_dir("plan_files");
+
+    // The default only looks for plan.md
+    let tracks = parser::load_all_tracks(&dir).unwrap();
+    assert_eq!(tracks[&TrackId::new("tasks_track")].tasks_total, 0);
+
+    let options = parser::ReadOptions {
+        plan_files: vec!["plan.md".to_string(), "tasks.md".to_string()],
+        ..parser::ReadOptions::default()
+    };
+    let tracks =
+        parser::load_all_tracks_with_options(&dir, parser::DEFAULT_TRACKS_SUBDIR, &options)
+            .unwrap();
+    let tasks_track = &tracks[&TrackId::new("tasks_track")];
+    assert_eq!(tasks_track.tasks_completed, 1);
+    assert_eq!(tasks_track.tasks_total, 2);
+    // The earlier name wins when both exist
+    let both = &tracks[&TrackId::new("both_track")];
+    assert_eq!(both.plan_phases[0].tasks[0].text, "From plan");
+
+    // Loading from an explicit index source honours the same options
+    let tracks = parser::load_tracks_with_status_source(
+        &parser::index::IndexSource::File(dir.join("tracks.md")),
+        &dir.join("tracks"),
+        Default::default(),
+        &options,
+    )
+    .unwrap();
+    assert_eq!(tracks[&TrackId::new("tasks_track")].tasks_total, 2);
+}
+
+#[test]
+fn test_lazy_plans_are_read_on_request() {
+    let dir = fixture_dir("lazy_plans");
+    let track_dir = dir.join("tracks").join("lazy_track");
+
+    let options = parser::ReadOptions {
+        lazy_plans: true,
+        ..parser::ReadOptions::default()
+    };
+    let mut tracks =
+        parser::load_all_tracks_with_options(&dir, parser::DEFAULT_TRACKS_SUBDIR, &options)
+            .unwrap();
+    let track = tracks.get_mut(&TrackId::new("lazy_track")).unwrap();
+    // Metadata is read up front, the plan is not
+    assert_eq!(track.priority, Priority::High);
+    assert!(track.plan_pending);
+    assert!(track.plan_phases.is_empty());
+    assert_eq!(track.tasks_total, 0);
+
+    assert!(parser::load_pending_plan(track, &track_dir, &options));
+    assert!(!track.plan_pending);
+    assert_eq!(track.plan_phases.len(), 1);
+    assert_eq!((track.tasks_completed, track.tasks_total), (1, 2));
+    // Once read, the plan is kept rather than parsed again
+    assert!(!parser::load_pending_plan(track, &track_dir, &options));
+}
+
+#[test]
+fn test_spec_title_and_acceptance_criteria_load_into_track() {
+    let tracks = parser::load_all_tracks(&fixture_dir("spec")).unwrap();
+    let with_spec = &tracks[&TrackId::new("spec_track")];
+    assert_eq!(with_spec.spec_summary.as_deref(), Some("Billing Export"));
+    assert_eq!(
+        with_spec.acceptance_criteria,
+        vec!["Export includes refunds", "Dates are ISO 8601"]
+    );
+
+    // A missing spec.md leaves the fields empty without affecting the rest
+    let bare = &tracks[&TrackId::new("bare_track")];
+    assert_eq!(bare.spec_summary, None);
+    assert!(bare.acceptance_criteria.is_empty());
+    assert_eq!(bare.tasks_total, 1);
+}
+
+#[test]
+fn test_dependencies_written_as_titles_resolve_to_ids() {
+    let tracks = parser::load_all_tracks(&fixture_dir("title_deps")).unwrap();
+    let billing = tracks.get(&TrackId::new("billing_20260102")).unwrap();
+    assert_eq!(
+        billing.dependencies,
+        vec![
+            // By title and by ID: the same track, listed once
+            TrackId::new("auth_refactor_20260101"),
+            // No track has this title or ID: kept, and still unresolved
+            TrackId::new("Ghost Track"),
+        ]
+    );
+    assert_eq!(
+        unmet_dependencies(&tracks, billing),
+        vec![&TrackId::new("Ghost Track")]
+    );
+}
+
+#[test]
+fn test_load_tracks_from_multiple_conductor_dirs() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/multi_dirs");
+    let api = root.join("api").join("conductor");
+    let web = root.join("web").join("conductor");
+
+    let tracks = parser::load_tracks_with_extra_dirs(
+        &parser::index::IndexSource::File(parser::index::index_path(&api)),
+        &api,
+        std::slice::from_ref(&web),
+        "tracks",
+        Default::default(),
+        &parser::ReadOptions::default(),
+    )
+    .unwrap();
+    let ids: Vec<&str> = tracks.keys().map(|id| id.as_str()).collect();
+    assert_eq!(ids, vec!["api_only", "shared", "web/shared", "web_only"]);
+
+    assert_eq!(
+        tracks[&TrackId::new("api_only")].source_dir.as_ref(),
+        Some(&api)
+    );
+    let web_shared = &tracks[&TrackId::new("web/shared")];
+    assert_eq!(web_shared.source_dir.as_ref(), Some(&web));
+    assert_eq!(web_shared.dir_name(), "shared");
+    // Plan came from the web dir's own folder, not the api one
+    assert_eq!(web_shared.tasks_total, 2);
+    assert_eq!(tracks[&TrackId::new("shared")].tasks_total, 0);
+    // A dependency on the re-keyed track follows it; others are untouched
+    assert_eq!(
+        tracks[&TrackId::new("web_only")].dependencies,
+        vec![TrackId::new("web/shared"), TrackId::new("api_only")]
+    );
+}
+
+#[test]
+fn test_bom_prefixed_files_parse() {
+    let dir = fixture_dir("bom");
+    // Guard against an editor dropping the marks from the fixture
+    for file in [
+        "tracks.md",
+        "tracks/bom_track/metadata.json",
+        "tracks/bom_track/plan.md",
+    ] {
+        let content = std::fs::read_to_string(dir.join(file)).unwrap();
+        assert!(content.starts_with('\u{feff}'), "{file} lost its BOM");
+    }
+
+    let tracks = parser::load_all_tracks(&dir).unwrap();
+    let track = tracks.get(&TrackId::new("bom_track")).unwrap();
+    assert_eq!(track.title, "BOM Track");
+    assert_eq!(track.checkbox_status, CheckboxStatus::Checked);
+    assert_eq!(track.priority, Priority::High);
+    assert_eq!(track.plan_phases.len(), 1);
+    assert_eq!(track.plan_phases[0].name, "Phase 1: Only");
+}
+
+#[test]
+fn test_retry_read_recovers_from_transient_failures() {
+    let flaky = |failures: u32| {
+        let mut calls = 0;
+        move || {
+            calls += 1;
+            if calls <= failures {
+                Err(parser::error::ParseError::Io {
+                    path: PathBuf::from("plan.md"),
+                    source: std::io::Error::other("mid-write"),
+                })
+            } else {
+                Ok(calls)
+            }
+        }
+    };
+
+    assert_eq!(parser::retry_read(2, flaky(2)).unwrap(), 3);
+    assert!(parser::retry_read(1, flaky(2)).is_err());
+    // Off by default: a single failure is surfaced immediately
+    assert!(parser::retry_read(0, flaky(1)).is_err());
+    assert_eq!(parser::retry_read(0, flaky(0)).unwrap(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_retry_read_inside_runtime() {
+    let mut calls = 0;
+    let result = parser::retry_read(1, || {
+        calls += 1;
+        if calls == 1 {
+            Err(parser::error::ParseError::Io {
+                path: PathBuf::from("plan.md"),
+                source: std::io::Error::other("mid-write"),
+            })
+        } else {
+            Ok(calls)
+        }
+    });
+    assert_eq!(result.unwrap(), 2);
+}
+
+#[test]
+fn test_read_overview_first_section() {
+    use conductor_dashboard::parser::overview::{parse_overview, read_overview};
+
+    let dir = fixture_dir("overview");
+    assert!(
+        read_overview(&dir).is_none(),
+        "missing file means no overview"
+    );
+
+    let overview = read_overview(&dir.join("overview_only")).unwrap();
+    assert_eq!(overview.title, "Q1 2026 Roadmap");
+    assert_eq!(
+        overview.description,
+        "Ship observability and the billing rewrite.\nFreeze on Mar 20"
+    );
+    assert_eq!(overview.headline(), "Q1 2026 Roadmap");
+
+    // README.md wins over overview.md
+    let overview = read_overview(&dir.join("with_readme")).unwrap();
+    assert!(overview.title.is_empty());
+    assert_eq!(overview.headline(), "Board notes without a heading.");
+
+    assert!(parse_overview("  \n").is_none());
+}
+
+#[test]
+fn test_git_dates_fill_missing_updated_at() {
+    use std::process::Command;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path();
+    let committed = dir.join("tracks").join("committed_20260301");
+    let untracked = dir.join("tracks").join("untracked_20260302");
+    std::fs::create_dir_all(&committed).unwrap();
+    std::fs::create_dir_all(&untracked).unwrap();
+    std::fs::write(committed.join("plan.md"), "- [ ] Task\n").unwrap();
+
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .env("GIT_COMMITTER_DATE", "2026-03-04T05:06:07+02:00")
+            .status()
+            .is_ok_and(|s| s.success())
+    };
+    // Only meaningful where git is installed
+    if !git(&["init", "-q"]) {
+        return;
+    }
+    assert!(git(&["add", "."]));
+    assert!(git(&["commit", "-q", "-m", "plan"]));
+
+    let track = |id: &str| Track {
+        id: TrackId::new(id),
+        ..Track::default()
+    };
+    let mut tracks: std::collections::BTreeMap<TrackId, Track> = [
+        track("committed_20260301"),
+        track("untracked_20260302"),
+        track("missing_20260303"),
+    ]
+    .into_iter()
+    .map(|t| (t.id.clone(), t))
+    .collect();
+    let mut git_dates = parser::git::GitDates::default();
+    git_dates.fill_updated_at(&mut tracks, dir, "tracks");
+
+    let updated = |id: &str| tracks[&TrackId::new(id)].updated_at;
+    assert_eq!(
+        updated("committed_20260301").map(|dt| dt.to_rfc3339()),
+        Some("2026-03-04T03:06:07+00:00".to_string())
+    );
+    assert!(
+        updated("untracked_20260302").is_some(),
+        "falls back to the folder mtime"
+    );
+    assert_eq!(updated("missing_20260303"), None);
+
+    // Filling from the cache alone runs no git: only looked-up folders fill
+    let mut tracks: std::collections::BTreeMap<TrackId, Track> =
+        [track("committed_20260301"), track("untracked_20260302")]
+            .into_iter()
+            .map(|t| (t.id.clone(), t))
+            .collect();
+    let mut cached = parser::git::GitDates::default();
+    let committed_date = git_dates.last_commit(&committed);
+    cached.insert(committed.clone(), committed_date);
+    let uncached = cached.fill_from_cache(&mut tracks, dir, "tracks");
+    assert_eq!(uncached, vec![untracked.clone()]);
+    assert_eq!(tracks[&TrackId::new("committed_20260301")].updated_at, committed_date);
+    assert_eq!(tracks[&TrackId::new("untracked_20260302")].updated_at, None);
+}